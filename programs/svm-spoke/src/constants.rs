@@ -9,3 +9,37 @@ pub const MESSAGE_TRANSMITTER_PROGRAM_ID: Pubkey = pubkey!("CCTPmbSD7gX1bxKPAmg7
 pub const MAX_EXCLUSIVITY_PERIOD_SECONDS: u32 = 31_536_000;
 
 pub const ZERO_DEPOSIT_ID: [u8; 32] = [0u8; 32];
+
+// Domain separators prefixed to origin refund and payout leaves so that they can never collide with other SVM leaf types.
+pub const ORIGIN_REFUND_LEAF_DOMAIN: &[u8] = b"ACROSS-ORIGIN-REFUND-LEAF";
+pub const PAYOUT_LEAF_DOMAIN: &[u8] = b"ACROSS-PAYOUT-LEAF";
+
+// Typed roots in a root bundle. Origin refunds have a dedicated root type, while any other root type is a generic
+// payout tree (e.g. LP rewards or periphery rebates) that can be added without program or CCTP message changes.
+pub const ORIGIN_REFUND_ROOT_TYPE: u8 = 0;
+pub const MAX_TYPED_ROOTS: usize = 8;
+
+// Maximum number of solidity selectors that can be permitted for an additional remote admin.
+pub const MAX_REMOTE_ADMIN_SELECTORS: usize = 16;
+
+// Roles that the owner can grant to additional accounts. Each role only permits the narrow set of admin instructions
+// listed below, while the owner (local or remote) can still call all of them.
+pub const PAUSER_ROLE: u8 = 0; // Pause deposits and fills (unpausing is reserved for the owner).
+pub const BUNDLE_RELAYER_ROLE: u8 = 1; // Relay root bundles and typed roots.
+pub const EMERGENCY_ADMIN_ROLE: u8 = 2; // Emergency delete root bundles.
+pub const GUARDIAN_ROLE: u8 = 3; // Pause refunds, claims and bridging (unpausing is reserved for the owner).
+
+// Upper bounds for governable deposit time buffers. One day for quote times and one week for fill deadlines.
+pub const MAX_DEPOSIT_QUOTE_TIME_BUFFER: u32 = 86_400;
+pub const MAX_FILL_DEADLINE_BUFFER: u32 = 604_800;
+
+// Default period after which a superseded root bundle can be closed by anyone to return its rent. 30 days.
+pub const DEFAULT_ROOT_BUNDLE_CLOSE_GRACE_PERIOD: u32 = 2_592_000;
+
+// Upper bound for the timelock of locally relayed root bundles. One week.
+pub const MAX_ROOT_BUNDLE_TIMELOCK: u32 = 604_800;
+
+// Compressed fill statuses are grouped per origin chain into buckets of fill deadlines spanning one hour, each split into
+// shards by the first byte of the relay hash to bound write lock contention between relayers.
+pub const FILL_STATUS_BUCKET_DURATION: u32 = 3_600;
+pub const FILL_STATUS_BUCKET_SHARDS: u8 = 16;
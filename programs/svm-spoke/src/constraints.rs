@@ -5,13 +5,37 @@ use anchor_spl::{
 };
 
 use crate::{
-    state::State,
-    utils::{get_relay_hash, get_self_authority_pda},
+    state::{RoleMember, State},
+    utils::{get_relay_hash, get_remote_admin_authority_pda, get_self_authority_pda},
     RelayData,
 };
 
 pub fn is_local_or_remote_owner(signer: &Signer, state: &Account<State>) -> bool {
-    signer.key() == state.owner || signer.key() == get_self_authority_pda()
+    signer.key() == state.owner || is_remote_owner(signer)
+}
+
+// Self-invoked CPIs from messages of additional remote admins are signed by remote_admin_authority instead of
+// self_authority, so that only the cross-domain admin passes is_cross_domain_admin.
+pub fn is_remote_owner(signer: &Signer) -> bool {
+    is_cross_domain_admin(signer) || signer.key() == get_remote_admin_authority_pda()
+}
+
+pub fn is_cross_domain_admin(signer: &Signer) -> bool {
+    signer.key() == get_self_authority_pda()
+}
+
+// Role members must pass their role_member PDA as the first remaining account. This is checked in instruction handlers
+// as Anchor account constraints cannot access remaining accounts.
+pub fn is_local_or_remote_owner_or_role<'info>(
+    signer: &Signer,
+    state: &Account<State>,
+    role: u8,
+    role_member: Option<&'info AccountInfo<'info>>,
+) -> bool {
+    is_local_or_remote_owner(signer, state)
+        || role_member
+            .and_then(|account_info| RoleMember::try_from(account_info, state.seed, role, &signer.key()))
+            .is_some()
 }
 
 pub fn is_relay_hash_valid(relay_hash: &[u8; 32], relay_data: &RelayData, state: &Account<State>) -> bool {
@@ -35,6 +35,10 @@ pub enum CommonError {
     InvalidExclusiveRelayer,
     #[msg("Invalid output token!")]
     InvalidOutputToken,
+    #[msg("Invalid depositor signature!")]
+    InvalidDepositorSignature,
+    #[msg("Deposit route is not enabled!")]
+    DisabledRoute,
 }
 
 // SVM specific errors.
@@ -76,6 +80,100 @@ pub enum SvmError {
     InvalidATACreationAccounts,
     #[msg("Invalid delegate PDA!")]
     InvalidDelegatePda,
+    #[msg("Invalid Ed25519 signature verification instruction!")]
+    InvalidEd25519Instruction,
+    #[msg("Invalid batch fill parameters!")]
+    InvalidBatchFill,
+    #[msg("Invalid remaining accounts for batch fill!")]
+    InvalidBatchFillAccounts,
+    #[msg("Batch fill does not support messages!")]
+    BatchFillMessageNotSupported,
+    #[msg("Selector is not permitted for the remote admin!")]
+    RemoteSelectorNotPermitted,
+    #[msg("Too many remote admin selectors!")]
+    TooManyRemoteAdminSelectors,
+    #[msg("Invalid deposit quote time buffer!")]
+    InvalidDepositQuoteTimeBuffer,
+    #[msg("Invalid fill deadline buffer!")]
+    InvalidFillDeadlineBuffer,
+    #[msg("Invalid token limits!")]
+    InvalidTokenLimits,
+    #[msg("Deposit amount exceeds the max deposit amount for the token!")]
+    DepositAmountLimitExceeded,
+    #[msg("Deposit exceeds the deposit volume limit for the token in the current window!")]
+    DepositVolumeLimitExceeded,
+    #[msg("Refund or slow fill exceeds the outflow volume limit for the token in the current window!")]
+    OutflowVolumeLimitExceeded,
+    #[msg("Native deposits must be signed by the depositor!")]
+    InvalidNativeDepositor,
+    #[msg("Recipient has not opted in to native fills!")]
+    NativeRecipientNotEnabled,
+    #[msg("Native fills require wrapped SOL output token!")]
+    InvalidNativeOutputToken,
+    #[msg("Native fills do not support messages!")]
+    NativeFillMessageNotSupported,
+    #[msg("Mints with transfer fee extension are not supported!")]
+    UnsupportedTransferFeeMint,
+    #[msg("Mints with transfer hook extension are not supported!")]
+    UnsupportedTransferHookMint,
+    #[msg("Cannot refund deposit on origin before its fill deadline has passed!")]
+    OriginRefundBeforeFillDeadline,
+    #[msg("Too many typed roots in the root bundle!")]
+    TooManyTypedRoots,
+    #[msg("Typed root is not set in the root bundle!")]
+    TypedRootNotSet,
+    #[msg("Invalid root type for payout leaf!")]
+    InvalidPayoutRootType,
+    #[msg("Invalid root bundle close grace period!")]
+    InvalidRootBundleCloseGracePeriod,
+    #[msg("Root bundle can only be closed once superseded past the grace period or fully executed!")]
+    RootBundleNotClosable,
+    #[msg("Leaf ID exceeds the leaf count of the root bundle!")]
+    LeafIdExceedsLeafCount,
+    #[msg("Invalid compressed fill status cutover!")]
+    InvalidCompressedFillStatusCutover,
+    #[msg("Relay fill status is tracked in a compressed fill status bucket that must be provided!")]
+    RelayUsesCompressedFillStatus,
+    #[msg("Relay fill status must be tracked in a fill status PDA!")]
+    RelayUsesLegacyFillStatus,
+    #[msg("Fill status PDA must be migrated to the compressed fill status bucket first!")]
+    LegacyFillStatusExists,
+    #[msg("Can only close fill status bucket once fill deadlines of all its relays have passed!")]
+    FillStatusBucketNotExpired,
+    #[msg("Invalid fill PDAs and relayer accounts to close!")]
+    InvalidCloseFillPdasAccounts,
+    #[msg("Signer is neither the relayer nor the delegate in the relayer profile!")]
+    InvalidRelayerProfileSigner,
+    #[msg("Only the pending owner can accept the ownership transfer!")]
+    NotPendingOwner,
+    #[msg("There is no pending ownership transfer!")]
+    NoPendingOwnershipTransfer,
+    #[msg("Invalid role!")]
+    InvalidRole,
+    #[msg("Only the remote cross-domain admin can call this function!")]
+    NotCrossDomainAdmin,
+    #[msg("Invalid root bundle timelock!")]
+    InvalidRootBundleTimelock,
+    #[msg("Root bundle is still pending!")]
+    RootBundlePending,
+    #[msg("Root bundle is not pending!")]
+    RootBundleNotPending,
+    #[msg("Root bundle timelock has not passed!")]
+    RootBundleTimelockNotPassed,
+    #[msg("Refunds are currently paused!")]
+    RefundsArePaused,
+    #[msg("Claims are currently paused!")]
+    ClaimsArePaused,
+    #[msg("Bridging to the HubPool is currently paused!")]
+    BridgeIsPaused,
+    #[msg("Missing instruction params!")]
+    InvalidInstructionParams,
+    #[msg("Account is already migrated!")]
+    AccountAlreadyMigrated,
+    #[msg("Fill exceeds the fill volume limit for the token in the current window!")]
+    FillVolumeLimitExceeded,
+    #[msg("Typed root claims can only be closed once their root bundle is closed!")]
+    TypedRootClaimsNotClosable,
 }
 
 // CCTP specific errors.
@@ -95,6 +193,12 @@ pub enum CallDataError {
     InvalidUint64,
     #[msg("Unsupported solidity selector")]
     UnsupportedSelector,
+    #[msg("Invalid solidity bytes4 argument")]
+    InvalidBytes4,
+    #[msg("Invalid solidity int64 argument")]
+    InvalidInt64,
+    #[msg("Invalid solidity uint8 argument")]
+    InvalidUint8,
 }
 
 // Across+ specific errors.
@@ -6,11 +6,104 @@ pub struct SetXDomainAdmin {
     pub new_admin: Pubkey,
 }
 
+#[event]
+pub struct SetRemoteAdminSelectors {
+    pub remote_domain: u32,
+    pub sender: Pubkey,
+    pub selectors: Vec<[u8; 4]>,
+}
+
+#[event]
+pub struct RemovedRemoteAdmin {
+    pub remote_domain: u32,
+    pub sender: Pubkey,
+}
+
+#[event]
+pub struct AppliedRemoteMessage {
+    pub remote_domain: u32,
+    pub sender: Pubkey,
+    pub selector: [u8; 4],
+    pub nonce: Option<u64>,
+}
+
+#[event]
+pub struct IgnoredRemoteMessage {
+    pub remote_domain: u32,
+    pub sender: Pubkey,
+    pub selector: [u8; 4],
+    pub nonce: u64,
+}
+
+#[event]
+pub struct GrantedRole {
+    pub role: u8,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct RevokedRole {
+    pub role: u8,
+    pub member: Pubkey,
+}
+
 #[event]
 pub struct TransferredOwnership {
     pub new_owner: Pubkey,
 }
 
+#[event]
+pub struct StartedOwnershipTransfer {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct CancelledOwnershipTransfer {
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct SetDepositQuoteTimeBuffer {
+    pub new_deposit_quote_time_buffer: u32,
+}
+
+#[event]
+pub struct SetFillDeadlineBuffer {
+    pub new_fill_deadline_buffer: u32,
+}
+
+#[event]
+pub struct SetRootBundleCloseGracePeriod {
+    pub new_root_bundle_close_grace_period: u32,
+}
+
+#[event]
+pub struct SetCompressedFillStatusCutover {
+    pub new_compressed_fill_status_cutover: u32,
+}
+
+#[event]
+pub struct SetRootBundleTimelock {
+    pub new_root_bundle_timelock: u32,
+}
+
+#[event]
+pub struct EnabledDepositRoute {
+    pub origin_token: Pubkey,
+    pub destination_chain_id: u64,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct SetTokenLimits {
+    pub mint: Pubkey,
+    pub max_deposit_amount: u64,
+    pub max_deposit_volume: u64,
+    pub max_outflow_volume: u64,
+    pub window_length: u32,
+}
+
 #[event]
 pub struct PausedDeposits {
     pub is_paused: bool,
@@ -21,6 +114,21 @@ pub struct PausedFills {
     pub is_paused: bool,
 }
 
+#[event]
+pub struct PausedRefunds {
+    pub is_paused: bool,
+}
+
+#[event]
+pub struct PausedClaims {
+    pub is_paused: bool,
+}
+
+#[event]
+pub struct PausedBridge {
+    pub is_paused: bool,
+}
+
 #[event]
 pub struct RelayedRootBundle {
     pub root_bundle_id: u32,
@@ -28,11 +136,47 @@ pub struct RelayedRootBundle {
     pub slow_relay_root: [u8; 32],
 }
 
+#[event]
+pub struct RelayedTypedRoot {
+    pub root_bundle_id: u32,
+    pub root_type: u8,
+    pub root: [u8; 32],
+}
+
+#[event]
+pub struct PendingRootBundle {
+    pub root_bundle_id: u32,
+    pub pending_until: u32,
+}
+
+#[event]
+pub struct ActivatedRootBundle {
+    pub root_bundle_id: u32,
+}
+
+#[event]
+pub struct VetoedRootBundle {
+    pub root_bundle_id: u32,
+}
+
 #[event]
 pub struct EmergencyDeletedRootBundle {
     pub root_bundle_id: u32,
 }
 
+#[event]
+pub struct ClosedRootBundle {
+    pub root_bundle_id: u32,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct ClosedTypedRootClaims {
+    pub root_bundle_id: u32,
+    pub root_type: u8,
+    pub payer: Pubkey,
+}
+
 // Deposit events
 #[event]
 pub struct FundsDeposited {
@@ -51,6 +195,16 @@ pub struct FundsDeposited {
     pub message: Vec<u8>,
 }
 
+#[event]
+pub struct RequestedSpeedUpDeposit {
+    pub updated_output_amount: [u8; 32],
+    pub deposit_id: [u8; 32],
+    pub depositor: Pubkey,
+    pub updated_recipient: Pubkey,
+    pub updated_message: Vec<u8>,
+    pub depositor_signature: Vec<u8>,
+}
+
 // Fill events
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum FillType {
@@ -103,6 +257,12 @@ pub struct RequestedSlowFill {
     pub message_hash: [u8; 32],
 }
 
+#[event]
+pub struct MigratedFillStatus {
+    pub relay_hash: [u8; 32],
+    pub relayer: Pubkey,
+}
+
 // Bundle refund events
 #[event]
 pub struct ExecutedRelayerRefundRoot {
@@ -115,6 +275,7 @@ pub struct ExecutedRelayerRefundRoot {
     pub refund_addresses: Vec<Pubkey>,
     pub deferred_refunds: bool,
     pub caller: Pubkey,
+    pub refund_deferrals: Vec<bool>,
 }
 
 #[event]
@@ -138,3 +299,52 @@ pub struct TokensBridged {
     pub l2_token_address: Pubkey,
     pub caller: Pubkey,
 }
+
+#[event]
+pub struct SetNativeRecipient {
+    pub recipient: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct FundedRelayerInventory {
+    pub relayer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WithdrewRelayerInventory {
+    pub relayer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SetRelayerProfile {
+    pub relayer: Pubkey,
+    pub repayment_chain_id: u64,
+    pub repayment_address: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct ExecutedOriginRefund {
+    pub root_bundle_id: u32,
+    pub deposit_id: [u8; 32],
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub caller: Pubkey,
+}
+
+#[event]
+pub struct ExecutedPayout {
+    pub root_bundle_id: u32,
+    pub root_type: u8,
+    pub leaf_id: u32,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub caller: Pubkey,
+}
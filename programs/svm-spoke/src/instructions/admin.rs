@@ -1,19 +1,26 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::DISCRIMINATOR_SIZE,
-    constraints::is_local_or_remote_owner,
+    constants::{
+        BUNDLE_RELAYER_ROLE, DEFAULT_ROOT_BUNDLE_CLOSE_GRACE_PERIOD, DISCRIMINATOR_SIZE, EMERGENCY_ADMIN_ROLE,
+        GUARDIAN_ROLE, MAX_DEPOSIT_QUOTE_TIME_BUFFER, MAX_FILL_DEADLINE_BUFFER, MAX_REMOTE_ADMIN_SELECTORS,
+        MAX_ROOT_BUNDLE_TIMELOCK, MAX_TYPED_ROOTS, PAUSER_ROLE,
+    },
+    constraints::{is_cross_domain_admin, is_local_or_remote_owner, is_local_or_remote_owner_or_role},
     error::SvmError,
     event::{
-        EmergencyDeletedRootBundle, PausedDeposits, PausedFills, RelayedRootBundle, SetXDomainAdmin,
-        TransferredOwnership,
+        ActivatedRootBundle, CancelledOwnershipTransfer, EmergencyDeletedRootBundle, EnabledDepositRoute, GrantedRole,
+        PausedBridge, PausedClaims, PausedDeposits, PausedFills, PausedRefunds, PendingRootBundle, RelayedRootBundle,
+        RelayedTypedRoot, RemovedRemoteAdmin, RevokedRole, SetCompressedFillStatusCutover, SetDepositQuoteTimeBuffer,
+        SetFillDeadlineBuffer, SetRemoteAdminSelectors, SetRootBundleCloseGracePeriod, SetRootBundleTimelock,
+        SetTokenLimits, SetXDomainAdmin, StartedOwnershipTransfer, TransferredOwnership, VetoedRootBundle,
     },
-    state::{RootBundle, State},
-    utils::{initialize_current_time, set_seed},
+    state::{RemoteAdmin, RemoteNonce, RoleMember, RootBundle, Route, State, TokenLimits, TypedRoot, TypedRootClaims},
+    utils::{get_bitmap_len, get_current_time, initialize_current_time, set_seed},
 };
 
 #[derive(Accounts)]
-#[instruction(seed: u64)]
+#[instruction(seed: u64, initial_number_of_deposits: u32, chain_id: u64, remote_domain: u32)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -27,6 +34,15 @@ pub struct Initialize<'info> {
     )]
     pub state: Account<'info, State>,
 
+    #[account(
+        init,
+        payer = signer,
+        space = DISCRIMINATOR_SIZE + RemoteNonce::INIT_SPACE,
+        seeds = [b"remote_nonce", seed.to_le_bytes().as_ref(), remote_domain.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub remote_nonce: Account<'info, RemoteNonce>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,6 +56,9 @@ pub fn initialize(
     deposit_quote_time_buffer: u32,  // Deposit quote times can't be set more than this amount into the past/future.
     fill_deadline_buffer: u32,       // Fill deadlines can't be set more than this amount into the future.
 ) -> Result<()> {
+    validate_deposit_quote_time_buffer(deposit_quote_time_buffer)?;
+    validate_fill_deadline_buffer(fill_deadline_buffer)?;
+
     let state = &mut ctx.accounts.state;
     state.owner = *ctx.accounts.signer.key;
     state.number_of_deposits = initial_number_of_deposits;
@@ -48,6 +67,9 @@ pub fn initialize(
     state.cross_domain_admin = cross_domain_admin;
     state.deposit_quote_time_buffer = deposit_quote_time_buffer;
     state.fill_deadline_buffer = fill_deadline_buffer;
+    state.root_bundle_close_grace_period = DEFAULT_ROOT_BUNDLE_CLOSE_GRACE_PERIOD;
+
+    ctx.accounts.remote_nonce.remote_domain = remote_domain;
 
     // Set seed and initialize current time. Both enable testing functionality and are no-ops in production.
     set_seed(state, seed)?;
@@ -56,17 +78,39 @@ pub fn initialize(
     Ok(())
 }
 
+// Buffers must be non-zero and bounded so that deposits can't be blocked or accept unreasonable timestamps.
+fn validate_deposit_quote_time_buffer(deposit_quote_time_buffer: u32) -> Result<()> {
+    if deposit_quote_time_buffer == 0 || deposit_quote_time_buffer > MAX_DEPOSIT_QUOTE_TIME_BUFFER {
+        return err!(SvmError::InvalidDepositQuoteTimeBuffer);
+    }
+
+    Ok(())
+}
+
+fn validate_fill_deadline_buffer(fill_deadline_buffer: u32) -> Result<()> {
+    if fill_deadline_buffer == 0 || fill_deadline_buffer > MAX_FILL_DEADLINE_BUFFER {
+        return err!(SvmError::InvalidFillDeadlineBuffer);
+    }
+
+    Ok(())
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 pub struct PauseDeposits<'info> {
-    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
     pub signer: Signer<'info>,
 
     #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
     pub state: Account<'info, State>,
 }
 
-pub fn pause_deposits(ctx: Context<PauseDeposits>, pause: bool) -> Result<()> {
+pub fn pause_deposits<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseDeposits<'info>>, pause: bool) -> Result<()>
+where
+    'c: 'info,
+{
+    check_pauser(&ctx.accounts.signer, &ctx.accounts.state, PAUSER_ROLE, pause, ctx.remaining_accounts.first())?;
+
     let state = &mut ctx.accounts.state;
     state.paused_deposits = pause;
 
@@ -78,14 +122,19 @@ pub fn pause_deposits(ctx: Context<PauseDeposits>, pause: bool) -> Result<()> {
 #[event_cpi]
 #[derive(Accounts)]
 pub struct PauseFills<'info> {
-    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
     pub signer: Signer<'info>,
 
     #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
     pub state: Account<'info, State>,
 }
 
-pub fn pause_fills(ctx: Context<PauseFills>, pause: bool) -> Result<()> {
+pub fn pause_fills<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseFills<'info>>, pause: bool) -> Result<()>
+where
+    'c: 'info,
+{
+    check_pauser(&ctx.accounts.signer, &ctx.accounts.state, PAUSER_ROLE, pause, ctx.remaining_accounts.first())?;
+
     let state = &mut ctx.accounts.state;
     state.paused_fills = pause;
 
@@ -94,10 +143,102 @@ pub fn pause_fills(ctx: Context<PauseFills>, pause: bool) -> Result<()> {
     Ok(())
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseRefunds<'info> {
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn pause_refunds<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseRefunds<'info>>, pause: bool) -> Result<()>
+where
+    'c: 'info,
+{
+    check_pauser(&ctx.accounts.signer, &ctx.accounts.state, GUARDIAN_ROLE, pause, ctx.remaining_accounts.first())?;
+
+    let state = &mut ctx.accounts.state;
+    state.paused_refunds = pause;
+
+    emit_cpi!(PausedRefunds { is_paused: pause });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseClaims<'info> {
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn pause_claims<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseClaims<'info>>, pause: bool) -> Result<()>
+where
+    'c: 'info,
+{
+    check_pauser(&ctx.accounts.signer, &ctx.accounts.state, GUARDIAN_ROLE, pause, ctx.remaining_accounts.first())?;
+
+    let state = &mut ctx.accounts.state;
+    state.paused_claims = pause;
+
+    emit_cpi!(PausedClaims { is_paused: pause });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseBridge<'info> {
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn pause_bridge<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseBridge<'info>>, pause: bool) -> Result<()>
+where
+    'c: 'info,
+{
+    check_pauser(&ctx.accounts.signer, &ctx.accounts.state, GUARDIAN_ROLE, pause, ctx.remaining_accounts.first())?;
+
+    let state = &mut ctx.accounts.state;
+    state.paused_bridge = pause;
+
+    emit_cpi!(PausedBridge { is_paused: pause });
+
+    Ok(())
+}
+
+// Role members can only pause, so that a compromised pauser or guardian key cannot lift a pause set by the owner.
+fn check_pauser<'info>(
+    signer: &Signer,
+    state: &Account<State>,
+    role: u8,
+    pause: bool,
+    role_member: Option<&'info AccountInfo<'info>>,
+) -> Result<()> {
+    let is_authorized = if pause {
+        is_local_or_remote_owner_or_role(signer, state, role, role_member)
+    } else {
+        is_local_or_remote_owner(signer, state)
+    };
+    if !is_authorized {
+        return err!(SvmError::NotOwner);
+    }
+
+    Ok(())
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 pub struct TransferOwnership<'info> {
-    #[account(address = state.owner @ SvmError::NotOwner)]
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
     pub signer: Signer<'info>,
 
     #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
@@ -106,9 +247,67 @@ pub struct TransferOwnership<'info> {
 
 pub fn transfer_ownership(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()> {
     let state = &mut ctx.accounts.state;
-    state.owner = new_owner;
 
-    emit_cpi!(TransferredOwnership { new_owner });
+    // Renouncing ownership takes effect immediately as no one could accept the transfer to the zero address.
+    if new_owner == Pubkey::default() {
+        state.owner = new_owner;
+        state.pending_owner = Pubkey::default();
+
+        emit_cpi!(TransferredOwnership { new_owner });
+
+        return Ok(());
+    }
+
+    state.pending_owner = new_owner;
+
+    emit_cpi!(StartedOwnershipTransfer { owner: state.owner, pending_owner: new_owner });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    #[account(
+        constraint = signer.key() == state.pending_owner
+            && state.pending_owner != Pubkey::default() @ SvmError::NotPendingOwner
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    state.owner = state.pending_owner;
+    state.pending_owner = Pubkey::default();
+
+    emit_cpi!(TransferredOwnership { new_owner: state.owner });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    if state.pending_owner == Pubkey::default() {
+        return err!(SvmError::NoPendingOwnershipTransfer);
+    }
+
+    let pending_owner = state.pending_owner;
+    state.pending_owner = Pubkey::default();
+
+    emit_cpi!(CancelledOwnershipTransfer { pending_owner });
 
     Ok(())
 }
@@ -134,20 +333,408 @@ pub fn set_cross_domain_admin(ctx: Context<SetCrossDomainAdmin>, cross_domain_ad
 
 #[event_cpi]
 #[derive(Accounts)]
-pub struct RelayRootBundle<'info> {
+#[instruction(origin_token: Pubkey, destination_chain_id: u64)]
+pub struct SetEnableRoute<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init_if_needed, // Allows toggling an already configured route.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + Route::INIT_SPACE,
+        seeds = [
+            b"route",
+            origin_token.as_ref(),
+            state.seed.to_le_bytes().as_ref(),
+            destination_chain_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub route: Account<'info, Route>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_enable_route(
+    ctx: Context<SetEnableRoute>,
+    origin_token: Pubkey,
+    destination_chain_id: u64,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.route.enabled = enabled;
+
+    emit_cpi!(EnabledDepositRoute { origin_token, destination_chain_id, enabled });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct SetTokenLimitsState<'info> {
     #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
     pub signer: Signer<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init_if_needed, // Allows updating limits of an already configured mint.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + TokenLimits::INIT_SPACE,
+        seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.as_ref()],
+        bump
+    )]
+    pub token_limits: Account<'info, TokenLimits>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_token_limits(
+    ctx: Context<SetTokenLimitsState>,
+    mint: Pubkey,
+    max_deposit_amount: u64,
+    max_deposit_volume: u64,
+    max_outflow_volume: u64,
+    window_length: u32,
+) -> Result<()> {
+    if window_length == 0 {
+        return err!(SvmError::InvalidTokenLimits);
+    }
+
+    // Volumes tracked in the current window are kept so that updating limits does not reset them.
+    let token_limits = &mut ctx.accounts.token_limits;
+    token_limits.max_deposit_amount = max_deposit_amount;
+    token_limits.max_deposit_volume = max_deposit_volume;
+    token_limits.max_outflow_volume = max_outflow_volume;
+    token_limits.window_length = window_length;
+
+    emit_cpi!(SetTokenLimits { mint, max_deposit_amount, max_deposit_volume, max_outflow_volume, window_length });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDepositQuoteTimeBufferState<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn set_deposit_quote_time_buffer(
+    ctx: Context<SetDepositQuoteTimeBufferState>,
+    deposit_quote_time_buffer: u32,
+) -> Result<()> {
+    validate_deposit_quote_time_buffer(deposit_quote_time_buffer)?;
+
+    let state = &mut ctx.accounts.state;
+    state.deposit_quote_time_buffer = deposit_quote_time_buffer;
+
+    emit_cpi!(SetDepositQuoteTimeBuffer { new_deposit_quote_time_buffer: deposit_quote_time_buffer });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFillDeadlineBufferState<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn set_fill_deadline_buffer(ctx: Context<SetFillDeadlineBufferState>, fill_deadline_buffer: u32) -> Result<()> {
+    validate_fill_deadline_buffer(fill_deadline_buffer)?;
+
+    let state = &mut ctx.accounts.state;
+    state.fill_deadline_buffer = fill_deadline_buffer;
+
+    emit_cpi!(SetFillDeadlineBuffer { new_fill_deadline_buffer: fill_deadline_buffer });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRootBundleCloseGracePeriodState<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn set_root_bundle_close_grace_period(
+    ctx: Context<SetRootBundleCloseGracePeriodState>,
+    root_bundle_close_grace_period: u32,
+) -> Result<()> {
+    // Zero grace period would let anyone close the previous root bundle as soon as a new one is relayed.
+    if root_bundle_close_grace_period == 0 {
+        return err!(SvmError::InvalidRootBundleCloseGracePeriod);
+    }
+
+    let state = &mut ctx.accounts.state;
+    state.root_bundle_close_grace_period = root_bundle_close_grace_period;
+
+    emit_cpi!(SetRootBundleCloseGracePeriod { new_root_bundle_close_grace_period: root_bundle_close_grace_period });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCompressedFillStatusCutoverState<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn set_compressed_fill_status_cutover(
+    ctx: Context<SetCompressedFillStatusCutoverState>,
+    compressed_fill_status_cutover: u32,
+) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+
+    // The cutover cannot be disabled or moved later as relays that were already tracked in compressed fill status
+    // buckets could then be filled again through FillStatusAccount PDAs. Moving it earlier is safe as compressed fills
+    // are blocked while a relay still has its FillStatusAccount PDA.
+    if compressed_fill_status_cutover == 0
+        || (state.compressed_fill_status_cutover != 0
+            && compressed_fill_status_cutover > state.compressed_fill_status_cutover)
+    {
+        return err!(SvmError::InvalidCompressedFillStatusCutover);
+    }
+
+    state.compressed_fill_status_cutover = compressed_fill_status_cutover;
+
+    emit_cpi!(SetCompressedFillStatusCutover { new_compressed_fill_status_cutover: compressed_fill_status_cutover });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRootBundleTimelockState<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn set_root_bundle_timelock(ctx: Context<SetRootBundleTimelockState>, root_bundle_timelock: u32) -> Result<()> {
+    let is_cross_domain_admin = is_cross_domain_admin(&ctx.accounts.signer);
+    let state = &mut ctx.accounts.state;
+
+    // The local owner and additional remote admins can only extend the timelock, as otherwise they could lift the veto
+    // window for root bundles relayed by them.
+    if root_bundle_timelock > MAX_ROOT_BUNDLE_TIMELOCK
+        || (!is_cross_domain_admin && root_bundle_timelock < state.root_bundle_timelock)
+    {
+        return err!(SvmError::InvalidRootBundleTimelock);
+    }
+
+    state.root_bundle_timelock = root_bundle_timelock;
+
+    emit_cpi!(SetRootBundleTimelock { new_root_bundle_timelock: root_bundle_timelock });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(remote_domain: u32, sender: Pubkey)]
+pub struct SetRemoteAdmin<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init_if_needed, // Allows updating selectors of already registered remote admin.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + RemoteAdmin::INIT_SPACE,
+        seeds = [
+            b"remote_admin",
+            state.seed.to_le_bytes().as_ref(),
+            remote_domain.to_le_bytes().as_ref(),
+            sender.as_ref()
+        ],
+        bump
+    )]
+    pub remote_admin: Account<'info, RemoteAdmin>,
+
+    #[account(
+        init_if_needed, // Remote domain can already be used by other remote admins.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + RemoteNonce::INIT_SPACE,
+        seeds = [b"remote_nonce", state.seed.to_le_bytes().as_ref(), remote_domain.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub remote_nonce: Account<'info, RemoteNonce>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_remote_admin(
+    ctx: Context<SetRemoteAdmin>,
+    remote_domain: u32,
+    sender: Pubkey,
+    selectors: Vec<[u8; 4]>,
+) -> Result<()> {
+    if selectors.len() > MAX_REMOTE_ADMIN_SELECTORS {
+        return err!(SvmError::TooManyRemoteAdminSelectors);
+    }
+
+    let remote_admin = &mut ctx.accounts.remote_admin;
+    remote_admin.remote_domain = remote_domain;
+    remote_admin.sender = sender;
+    remote_admin.selectors = selectors.clone();
+
+    ctx.accounts.remote_nonce.remote_domain = remote_domain;
+
+    emit_cpi!(SetRemoteAdminSelectors { remote_domain, sender, selectors });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(remote_domain: u32, sender: Pubkey)]
+pub struct RemoveRemoteAdmin<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub closer: SystemAccount<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"remote_admin",
+            state.seed.to_le_bytes().as_ref(),
+            remote_domain.to_le_bytes().as_ref(),
+            sender.as_ref()
+        ],
+        bump,
+        close = closer
+    )]
+    pub remote_admin: Account<'info, RemoteAdmin>,
+}
+
+pub fn remove_remote_admin(ctx: Context<RemoveRemoteAdmin>, remote_domain: u32, sender: Pubkey) -> Result<()> {
+    emit_cpi!(RemovedRemoteAdmin { remote_domain, sender });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: u8, member: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        init_if_needed, // Granting an already granted role is a no-op besides emitting the event.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + RoleMember::INIT_SPACE,
+        seeds = [b"role_member", state.seed.to_le_bytes().as_ref(), &[role], member.as_ref()],
+        bump
+    )]
+    pub role_member: Account<'info, RoleMember>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: u8, member: Pubkey) -> Result<()> {
+    if !RoleMember::is_valid_role(role) {
+        return err!(SvmError::InvalidRole);
+    }
+
+    let role_member = &mut ctx.accounts.role_member;
+    role_member.role = role;
+    role_member.member = member;
+
+    emit_cpi!(GrantedRole { role, member });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: u8, member: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub closer: SystemAccount<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"role_member", state.seed.to_le_bytes().as_ref(), &[role], member.as_ref()],
+        bump,
+        close = closer
+    )]
+    pub role_member: Account<'info, RoleMember>,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, role: u8, member: Pubkey) -> Result<()> {
+    emit_cpi!(RevokedRole { role, member });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relayer_refund_root: [u8; 32], slow_relay_root: [u8; 32], leaf_count: Option<u32>)]
+pub struct RelayRootBundle<'info> {
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(mut, seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
     pub state: Account<'info, State>,
 
     #[account(
         init, // Init to create root bundle account. Prevents re-initialization for a given root..
         payer = payer,
-        space = DISCRIMINATOR_SIZE + RootBundle::INIT_SPACE,
+        space = RootBundle::space(leaf_count), // Preallocate the claimed bitmap when the leaf count is known.
         seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), state.root_bundle_id.to_le_bytes().as_ref()],
         bump
     )]
@@ -156,18 +743,42 @@ pub struct RelayRootBundle<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn relay_root_bundle(
-    ctx: Context<RelayRootBundle>,
+pub fn relay_root_bundle<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RelayRootBundle<'info>>,
     relayer_refund_root: [u8; 32],
     slow_relay_root: [u8; 32],
-) -> Result<()> {
+    leaf_count: Option<u32>,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let role_member = ctx.remaining_accounts.first();
+    if !is_local_or_remote_owner_or_role(&ctx.accounts.signer, &ctx.accounts.state, BUNDLE_RELAYER_ROLE, role_member) {
+        return err!(SvmError::NotOwner);
+    }
+
+    let is_timelocked = !is_cross_domain_admin(&ctx.accounts.signer) && ctx.accounts.state.root_bundle_timelock != 0;
+
     let state = &mut ctx.accounts.state;
     let root_bundle = &mut ctx.accounts.root_bundle;
     root_bundle.relayer_refund_root = relayer_refund_root;
     root_bundle.slow_relay_root = slow_relay_root;
+    root_bundle.payer = ctx.accounts.payer.key();
+    root_bundle.created_at = get_current_time(state)?;
+    root_bundle.leaf_count = leaf_count;
+    root_bundle.claimed_bitmap = vec![0; get_bitmap_len(leaf_count.unwrap_or_default())];
 
     emit_cpi!(RelayedRootBundle { root_bundle_id: state.root_bundle_id, relayer_refund_root, slow_relay_root });
 
+    // Root bundles relayed by the cross-domain admin are already protected by the HubPool challenge period, while root
+    // bundles relayed by other signers stay pending until the timelock passes so that the cross-domain admin can veto
+    // them.
+    if is_timelocked {
+        root_bundle.pending_until = root_bundle.created_at.saturating_add(state.root_bundle_timelock);
+
+        emit_cpi!(PendingRootBundle { root_bundle_id: state.root_bundle_id, pending_until: root_bundle.pending_until });
+    }
+
     state.root_bundle_id += 1;
 
     Ok(())
@@ -177,12 +788,12 @@ pub fn relay_root_bundle(
 #[derive(Accounts)]
 #[instruction(root_bundle_id: u32)]
 pub struct EmergencyDeleteRootBundleState<'info> {
-    #[account(constraint = is_local_or_remote_owner(&signer, &state) @ SvmError::NotOwner)]
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
     pub signer: Signer<'info>,
 
     #[account(mut)]
-    // We do not restrict who can receive lamports from closing root_bundle account as that would require storing the
-    // original payer when root bundle was relayed and unnecessarily make it more expensive to relay in the happy path.
+    // We do not restrict who can receive lamports from closing root_bundle account in emergencies. Regular closing of
+    // executed or superseded root bundles returns lamports to the original payer via close_root_bundle.
     pub closer: SystemAccount<'info>,
 
     #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
@@ -195,7 +806,163 @@ pub struct EmergencyDeleteRootBundleState<'info> {
     pub root_bundle: Account<'info, RootBundle>,
 }
 
-pub fn emergency_delete_root_bundle(ctx: Context<EmergencyDeleteRootBundleState>, root_bundle_id: u32) -> Result<()> {
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32, root_type: u8)]
+pub struct RelayTypedRoot<'info> {
+    // Signer is checked in the handler as role members pass their role_member PDA in remaining accounts.
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+
+    #[account(
+        init, // Init prevents relaying the same root type twice in a root bundle.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + TypedRootClaims::INIT_SPACE,
+        seeds = [
+            b"typed_root_claims",
+            state.seed.to_le_bytes().as_ref(),
+            root_bundle_id.to_le_bytes().as_ref(),
+            &[root_type]
+        ],
+        bump
+    )]
+    pub typed_root_claims: Account<'info, TypedRootClaims>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn relay_typed_root<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RelayTypedRoot<'info>>,
+    root_bundle_id: u32,
+    root_type: u8,
+    root: [u8; 32],
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let role_member = ctx.remaining_accounts.first();
+    if !is_local_or_remote_owner_or_role(&ctx.accounts.signer, &ctx.accounts.state, BUNDLE_RELAYER_ROLE, role_member) {
+        return err!(SvmError::NotOwner);
+    }
+
+    let is_timelocked = !is_cross_domain_admin(&ctx.accounts.signer) && ctx.accounts.state.root_bundle_timelock != 0;
+
+    let state = &ctx.accounts.state;
+    let root_bundle = &mut ctx.accounts.root_bundle;
+    // Timelocked typed roots cannot be added to active root bundles, as they would either take effect immediately or
+    // block leaf execution of the whole root bundle by putting it back to pending.
+    if is_timelocked && root_bundle.is_active() {
+        return err!(SvmError::RootBundleNotPending);
+    }
+    if root_bundle.typed_roots.len() >= MAX_TYPED_ROOTS {
+        return err!(SvmError::TooManyTypedRoots);
+    }
+    root_bundle.typed_roots.push(TypedRoot { root_type, root });
+    ctx.accounts.typed_root_claims.payer = ctx.accounts.payer.key();
+
+    emit_cpi!(RelayedTypedRoot { root_bundle_id, root_type, root });
+
+    // Timelocked typed roots extend the pending period of the root bundle so that the cross-domain admin can veto them.
+    if is_timelocked {
+        let pending_until = get_current_time(state)?.saturating_add(state.root_bundle_timelock);
+        root_bundle.pending_until = root_bundle.pending_until.max(pending_until);
+
+        emit_cpi!(PendingRootBundle { root_bundle_id, pending_until: root_bundle.pending_until });
+    }
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32)]
+pub struct ActivateRootBundle<'info> {
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+}
+
+pub fn activate_root_bundle(ctx: Context<ActivateRootBundle>, root_bundle_id: u32) -> Result<()> {
+    let current_time = get_current_time(&ctx.accounts.state)?;
+    let root_bundle = &mut ctx.accounts.root_bundle;
+    if root_bundle.is_active() {
+        return err!(SvmError::RootBundleNotPending);
+    }
+    if current_time < root_bundle.pending_until {
+        return err!(SvmError::RootBundleTimelockNotPassed);
+    }
+
+    root_bundle.pending_until = 0;
+
+    emit_cpi!(ActivatedRootBundle { root_bundle_id });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32)]
+pub struct VetoRootBundle<'info> {
+    #[account(constraint = is_cross_domain_admin(&signer) @ SvmError::NotCrossDomainAdmin)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, address = root_bundle.payer)]
+    pub payer: SystemAccount<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump,
+        close = payer
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+}
+
+pub fn veto_root_bundle(ctx: Context<VetoRootBundle>, root_bundle_id: u32) -> Result<()> {
+    // Vetoing is only possible during the timelock, while active root bundles can still be deleted via
+    // emergency_delete_root_bundle.
+    if ctx.accounts.root_bundle.is_active() {
+        return err!(SvmError::RootBundleNotPending);
+    }
+
+    emit_cpi!(VetoedRootBundle { root_bundle_id });
+
+    Ok(())
+}
+
+pub fn emergency_delete_root_bundle<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, EmergencyDeleteRootBundleState<'info>>,
+    root_bundle_id: u32,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let role_member = ctx.remaining_accounts.first();
+    if !is_local_or_remote_owner_or_role(&ctx.accounts.signer, &ctx.accounts.state, EMERGENCY_ADMIN_ROLE, role_member) {
+        return err!(SvmError::NotOwner);
+    }
+
     emit_cpi!(EmergencyDeletedRootBundle { root_bundle_id });
 
     Ok(())
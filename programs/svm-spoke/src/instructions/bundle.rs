@@ -7,9 +7,15 @@ use anchor_spl::{
 use crate::{
     constants::DISCRIMINATOR_SIZE,
     error::{CommonError, SvmError},
-    event::{ExecutedRelayerRefundRoot, TokensBridged},
-    state::{ClaimAccount, ExecuteRelayerRefundLeafParams, RootBundle, State, TransferLiability},
-    utils::{is_claimed, set_claimed, verify_merkle_proof},
+    event::{ClosedRootBundle, ClosedTypedRootClaims, ExecutedRelayerRefundRoot, TokensBridged},
+    state::{
+        ClaimAccount, ExecuteRelayerRefundLeafParams, ExecuteRelayerRefundLeavesParams, RootBundle, RootBundleLeaves,
+        State, TokenLimits, TransferLiability, TypedRootClaims,
+    },
+    utils::{
+        get_current_time, has_restricted_incoming_transfers, is_claimed, set_claimed, validate_mint_extensions,
+        verify_merkle_multi_proof, verify_merkle_proof,
+    },
 };
 
 #[event_cpi]
@@ -21,19 +27,25 @@ pub struct ExecuteRelayerRefundLeaf<'info> {
     #[account(mut, seeds = [b"instruction_params", signer.key().as_ref()], bump, close = signer)]
     pub instruction_params: Account<'info, ExecuteRelayerRefundLeafParams>, // Contains all leaf & proof information.
 
-    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
     pub state: Account<'info, State>,
 
     #[account(
         mut,
         seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), instruction_params.root_bundle_id.to_le_bytes().as_ref()], bump,
-        // Realloc to let the size of the dynamic array within root_bundle to grow as leafs are executed.
+        // Realloc to let the size of the dynamic array within root_bundle to grow as leafs are executed. This is a no-op
+        // when the claimed bitmap was preallocated for the leaf count when relaying the root bundle.
         realloc = std::cmp::max(
             DISCRIMINATOR_SIZE + RootBundle::INIT_SPACE + instruction_params.relayer_refund_leaf.leaf_id as usize / 8,
             root_bundle.to_account_info().data_len()
         ),
         realloc::payer = signer,
-        realloc::zero = false
+        realloc::zero = false,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
     )]
     pub root_bundle: Account<'info, RootBundle>,
 
@@ -51,6 +63,74 @@ pub struct ExecuteRelayerRefundLeaf<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed, // If first time creating, initialize the liability tracker, else re-use.
+        payer = signer,
+        space = DISCRIMINATOR_SIZE + TransferLiability::INIT_SPACE,
+        seeds = [b"transfer_liability", mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_liability: Account<'info, TransferLiability>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteRelayerRefundLeaves<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"instruction_params", signer.key().as_ref()], bump, close = signer)]
+    pub instruction_params: Account<'info, ExecuteRelayerRefundLeavesParams>, // Contains all leaves & multiproof.
+
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), instruction_params.root_bundle_id.to_le_bytes().as_ref()], bump,
+        // Realloc to let the claimed bitmap grow up to the highest leaf ID, same as in execute_relayer_refund_leaf.
+        realloc = std::cmp::max(
+            DISCRIMINATOR_SIZE
+                + RootBundle::INIT_SPACE
+                + instruction_params.relayer_refund_leaves.iter().map(|leaf| leaf.leaf_id).max().unwrap_or_default()
+                    as usize
+                    / 8,
+            root_bundle.to_account_info().data_len()
+        ),
+        realloc::payer = signer,
+        realloc::zero = false,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = state, // Ensure owner is the state.
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Mint of all relayer refund leaves is checked against this mint in the handler.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed, // If first time creating, initialize the liability tracker, else re-use.
         payer = signer,
@@ -98,9 +178,17 @@ impl RelayerRefundLeaf {
     }
 }
 
+// Controls how relayer refunds are allocated when executing the relayer refund leaf.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RefundMode {
+    Direct,   // All refunds are transferred to relayer token accounts.
+    Deferred, // All refunds are accrued to claim_account PDAs.
+    Hybrid,   // Refunds are transferred when possible and fall back to claim_account PDAs otherwise.
+}
+
 pub fn execute_relayer_refund_leaf<'c, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaf<'info>>,
-    deferred_refunds: bool,
+    refund_mode: RefundMode,
 ) -> Result<()>
 where
     'c: 'info, // The lifetime constraint 'c: 'info ensures that the lifetime 'c is at least as long as 'info.
@@ -111,133 +199,487 @@ where
     let relayer_refund_leaf = instruction_params.relayer_refund_leaf.to_owned();
     let proof = instruction_params.proof.to_owned();
 
-    let state = &ctx.accounts.state;
-
     let root = ctx.accounts.root_bundle.relayer_refund_root;
     let leaf = relayer_refund_leaf.to_keccak_hash()?;
     verify_merkle_proof(root, leaf, proof)?;
 
+    let refund_accounts = RefundAccounts {
+        state: &ctx.accounts.state,
+        state_bump: ctx.bumps.state,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    let refund_deferrals = execute_verified_relayer_refund_leaf(
+        &refund_accounts,
+        &mut ctx.accounts.root_bundle,
+        &ctx.accounts.token_limits,
+        &mut ctx.accounts.transfer_liability,
+        &relayer_refund_leaf,
+        refund_mode,
+        0,
+    )?;
+
+    if relayer_refund_leaf.amount_to_return > 0 {
+        emit_cpi!(TokensBridged {
+            amount_to_return: relayer_refund_leaf.amount_to_return,
+            chain_id: relayer_refund_leaf.chain_id,
+            leaf_id: relayer_refund_leaf.leaf_id,
+            l2_token_address: ctx.accounts.mint.key(),
+            caller: ctx.accounts.signer.key(),
+        });
+    }
+
+    emit_cpi!(ExecutedRelayerRefundRoot {
+        amount_to_return: relayer_refund_leaf.amount_to_return,
+        chain_id: relayer_refund_leaf.chain_id,
+        refund_amounts: relayer_refund_leaf.refund_amounts,
+        root_bundle_id,
+        leaf_id: relayer_refund_leaf.leaf_id,
+        l2_token_address: ctx.accounts.mint.key(),
+        refund_addresses: relayer_refund_leaf.refund_addresses,
+        deferred_refunds: refund_deferrals.iter().any(|&deferred| deferred),
+        caller: ctx.accounts.signer.key(),
+        refund_deferrals,
+    });
+
+    Ok(())
+}
+
+pub fn execute_relayer_refund_leaves<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaves<'info>>,
+    refund_mode: RefundMode,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    // Get pre-loaded instruction parameters.
+    let instruction_params = &ctx.accounts.instruction_params;
+    let root_bundle_id = instruction_params.root_bundle_id;
+    let relayer_refund_leaves = instruction_params.relayer_refund_leaves.to_owned();
+    let proof = instruction_params.proof.to_owned();
+    let proof_flags = instruction_params.proof_flags.to_owned();
+
+    let root = ctx.accounts.root_bundle.relayer_refund_root;
+    let leaves = relayer_refund_leaves
+        .iter()
+        .map(|relayer_refund_leaf| relayer_refund_leaf.to_keccak_hash())
+        .collect::<Result<Vec<_>>>()?;
+    verify_merkle_multi_proof(root, &leaves, &proof, &proof_flags)?;
+
+    // All leaves are paid out from the same vault, so they must refund the same mint.
+    if relayer_refund_leaves
+        .iter()
+        .any(|relayer_refund_leaf| relayer_refund_leaf.mint_public_key != ctx.accounts.mint.key())
+    {
+        return err!(SvmError::InvalidMint);
+    }
+
+    // Check if vault has sufficient balance for the refunds of all leaves, as its balance is not reloaded in between.
+    let total_refund_amount: u64 = relayer_refund_leaves
+        .iter()
+        .flat_map(|relayer_refund_leaf| relayer_refund_leaf.refund_amounts.iter())
+        .sum();
+    if ctx.accounts.vault.amount < total_refund_amount {
+        return err!(CommonError::InsufficientSpokePoolBalanceToExecuteLeaf);
+    }
+
+    let refund_accounts = RefundAccounts {
+        state: &ctx.accounts.state,
+        state_bump: ctx.bumps.state,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    // Refund accounts of each leaf follow the refund accounts of the previous leaf in the remaining accounts.
+    let mut account_offset = 0;
+    for relayer_refund_leaf in relayer_refund_leaves {
+        let refund_deferrals = execute_verified_relayer_refund_leaf(
+            &refund_accounts,
+            &mut ctx.accounts.root_bundle,
+            &ctx.accounts.token_limits,
+            &mut ctx.accounts.transfer_liability,
+            &relayer_refund_leaf,
+            refund_mode,
+            account_offset,
+        )?;
+        account_offset += get_required_refund_accounts(&relayer_refund_leaf, refund_mode);
+
+        if relayer_refund_leaf.amount_to_return > 0 {
+            emit_cpi!(TokensBridged {
+                amount_to_return: relayer_refund_leaf.amount_to_return,
+                chain_id: relayer_refund_leaf.chain_id,
+                leaf_id: relayer_refund_leaf.leaf_id,
+                l2_token_address: ctx.accounts.mint.key(),
+                caller: ctx.accounts.signer.key(),
+            });
+        }
+
+        emit_cpi!(ExecutedRelayerRefundRoot {
+            amount_to_return: relayer_refund_leaf.amount_to_return,
+            chain_id: relayer_refund_leaf.chain_id,
+            refund_amounts: relayer_refund_leaf.refund_amounts,
+            root_bundle_id,
+            leaf_id: relayer_refund_leaf.leaf_id,
+            l2_token_address: ctx.accounts.mint.key(),
+            refund_addresses: relayer_refund_leaf.refund_addresses,
+            deferred_refunds: refund_deferrals.iter().any(|&deferred| deferred),
+            caller: ctx.accounts.signer.key(),
+            refund_deferrals,
+        });
+    }
+
+    Ok(())
+}
+
+// Accounts used to pay out relayer refunds, shared by single and multiproof leaf execution.
+struct RefundAccounts<'a, 'info> {
+    state: &'a Account<'info, State>,
+    state_bump: u8,
+    vault: &'a InterfaceAccount<'info, TokenAccount>,
+    mint: &'a InterfaceAccount<'info, Mint>,
+    token_program: &'a Interface<'info, TokenInterface>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+}
+
+// Hybrid mode requires both token accounts and claim accounts for all refund addresses.
+fn get_required_refund_accounts(relayer_refund_leaf: &RelayerRefundLeaf, refund_mode: RefundMode) -> usize {
+    match refund_mode {
+        RefundMode::Hybrid => relayer_refund_leaf.refund_addresses.len() * 2,
+        _ => relayer_refund_leaf.refund_addresses.len(),
+    }
+}
+
+// Executes the relayer refund leaf that has been verified against the relayer refund root. Refund accounts of the leaf
+// start at account_offset in the remaining accounts. Returns whether each of the refunds got deferred.
+fn execute_verified_relayer_refund_leaf<'info>(
+    refund_accounts: &RefundAccounts<'_, 'info>,
+    root_bundle: &mut RootBundle,
+    token_limits: &AccountInfo<'info>,
+    transfer_liability: &mut TransferLiability,
+    relayer_refund_leaf: &RelayerRefundLeaf,
+    refund_mode: RefundMode,
+    account_offset: usize,
+) -> Result<Vec<bool>> {
+    let state = refund_accounts.state;
+
     if relayer_refund_leaf.chain_id != state.chain_id {
         return err!(CommonError::InvalidChainId);
     }
 
-    if is_claimed(&ctx.accounts.root_bundle.claimed_bitmap, relayer_refund_leaf.leaf_id) {
+    if !root_bundle.is_leaf_id_valid(relayer_refund_leaf.leaf_id) {
+        return err!(SvmError::LeafIdExceedsLeafCount);
+    }
+
+    if is_claimed(&root_bundle.claimed_bitmap, relayer_refund_leaf.leaf_id) {
         return err!(CommonError::ClaimedMerkleLeaf);
     }
 
-    set_claimed(&mut ctx.accounts.root_bundle.claimed_bitmap, relayer_refund_leaf.leaf_id);
+    set_claimed(&mut root_bundle.claimed_bitmap, relayer_refund_leaf.leaf_id);
+    root_bundle.claimed_leaf_count += 1;
 
     if relayer_refund_leaf.refund_addresses.len() != relayer_refund_leaf.refund_amounts.len() {
         return err!(CommonError::InvalidMerkleLeaf);
     }
 
-    if ctx.remaining_accounts.len() < relayer_refund_leaf.refund_addresses.len() {
+    let required_accounts = get_required_refund_accounts(relayer_refund_leaf, refund_mode);
+    if refund_accounts.remaining_accounts.len() < account_offset + required_accounts {
         return err!(ErrorCode::AccountNotEnoughKeys);
     }
 
     // Check if vault has sufficient balance for all the refunds.
     let total_refund_amount: u64 = relayer_refund_leaf.refund_amounts.iter().sum();
-    if ctx.accounts.vault.amount < total_refund_amount {
+    if refund_accounts.vault.amount < total_refund_amount {
         return err!(CommonError::InsufficientSpokePoolBalanceToExecuteLeaf);
     }
 
-    // Depending on the called instruction flavor, we either accrue the refunds to claim accounts or transfer them.
-    match deferred_refunds {
-        true => accrue_relayer_refunds(&ctx, &relayer_refund_leaf)?,
-        false => distribute_relayer_refunds(&ctx, &relayer_refund_leaf)?,
-    }
+    let current_time = get_current_time(state)?;
+    TokenLimits::update(token_limits, |limits| limits.record_outflow(current_time, total_refund_amount))?;
 
-    if relayer_refund_leaf.amount_to_return > 0 {
-        ctx.accounts.transfer_liability.pending_to_hub_pool += relayer_refund_leaf.amount_to_return;
+    // Depending on the called instruction flavor, we either accrue the refunds to claim accounts, transfer them or try
+    // transferring with fallback to claim accounts. Track which of the refunds got deferred for the event.
+    let refund_deferrals = match refund_mode {
+        RefundMode::Direct => {
+            distribute_relayer_refunds(refund_accounts, relayer_refund_leaf, account_offset)?;
+            vec![false; relayer_refund_leaf.refund_addresses.len()]
+        }
+        RefundMode::Deferred => {
+            accrue_relayer_refunds(refund_accounts, relayer_refund_leaf, account_offset)?;
+            vec![true; relayer_refund_leaf.refund_addresses.len()]
+        }
+        RefundMode::Hybrid => {
+            distribute_or_accrue_relayer_refunds(refund_accounts, relayer_refund_leaf, account_offset)?
+        }
+    };
 
-        emit_cpi!(TokensBridged {
-            amount_to_return: relayer_refund_leaf.amount_to_return,
-            chain_id: relayer_refund_leaf.chain_id,
-            leaf_id: relayer_refund_leaf.leaf_id,
-            l2_token_address: ctx.accounts.mint.key(),
-            caller: ctx.accounts.signer.key(),
-        });
+    if relayer_refund_leaf.amount_to_return > 0 {
+        transfer_liability.pending_to_hub_pool += relayer_refund_leaf.amount_to_return;
     }
 
-    emit_cpi!(ExecutedRelayerRefundRoot {
-        amount_to_return: relayer_refund_leaf.amount_to_return,
-        chain_id: relayer_refund_leaf.chain_id,
-        refund_amounts: relayer_refund_leaf.refund_amounts,
-        root_bundle_id,
-        leaf_id: relayer_refund_leaf.leaf_id,
-        l2_token_address: ctx.accounts.mint.key(),
-        refund_addresses: relayer_refund_leaf.refund_addresses,
-        deferred_refunds,
-        caller: ctx.accounts.signer.key(),
-    });
-
-    Ok(())
+    Ok(refund_deferrals)
 }
 
-fn distribute_relayer_refunds<'info>(
-    ctx: &Context<'_, '_, '_, 'info, ExecuteRelayerRefundLeaf<'info>>,
+fn distribute_relayer_refunds(
+    refund_accounts: &RefundAccounts,
     relayer_refund_leaf: &RelayerRefundLeaf,
+    account_offset: usize,
 ) -> Result<()> {
-    // Derive the signer seeds for the state. The vault owns the state PDA so we need to derive this to create the
-    // signer seeds to execute the CPI transfer from the vault to the refund recipient's token account.
-    let state_seed_bytes = ctx.accounts.state.seed.to_le_bytes();
-    let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
-    let signer_seeds = &[&seeds[..]];
-
     for (i, amount) in relayer_refund_leaf.refund_amounts.iter().enumerate() {
         // We only need to check the refund account matches the associated token address for the relayer.
         // All other required checks are performed within the transfer CPI. We do not check the token account authority
         // as the relayer might have transferred it to a multisig or any other wallet.
         // It should be safe to access elements of refund_addresses and remaining_accounts as their lengths are checked
         // before calling this internal function.
-        let refund_token_account = &ctx.remaining_accounts[i];
-        let associated_token_address = associated_token::get_associated_token_address_with_program_id(
-            &relayer_refund_leaf.refund_addresses[i],
-            &ctx.accounts.mint.key(),
-            &ctx.accounts.token_program.key(),
-        );
-        if refund_token_account.key() != associated_token_address {
-            return Err(Error::from(SvmError::InvalidRefund).with_account_name(&format!("remaining_accounts[{}]", i)));
+        let account_index = account_offset + i;
+        let refund_token_account = &refund_accounts.remaining_accounts[account_index];
+        if refund_token_account.key()
+            != get_refund_token_address(refund_accounts, &relayer_refund_leaf.refund_addresses[i])
+        {
+            return Err(Error::from(SvmError::InvalidRefund)
+                .with_account_name(&format!("remaining_accounts[{}]", account_index)));
         }
 
-        let transfer_accounts = TransferChecked {
-            from: ctx.accounts.vault.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            to: refund_token_account.to_account_info(),
-            authority: ctx.accounts.state.to_account_info(),
-        };
-        let cpi_context =
-            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
-        transfer_checked(cpi_context, amount.to_owned(), ctx.accounts.mint.decimals)?;
+        transfer_relayer_refund(refund_accounts, refund_token_account, amount.to_owned())?;
     }
 
     Ok(())
 }
 
-fn accrue_relayer_refunds<'c, 'info>(
-    ctx: &Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaf<'info>>,
+fn accrue_relayer_refunds(
+    refund_accounts: &RefundAccounts,
     relayer_refund_leaf: &RelayerRefundLeaf,
-) -> Result<()>
-where
-    'c: 'info,
-{
+    account_offset: usize,
+) -> Result<()> {
     for (i, amount) in relayer_refund_leaf.refund_amounts.iter().enumerate() {
         // It should be safe to access elements of refund_addresses and remaining_accounts as their lengths are checked
         // before calling this internal function.
-        let mut claim_account = ClaimAccount::try_from(
-            &ctx.remaining_accounts[i],
-            &relayer_refund_leaf.mint_public_key,
+        accrue_relayer_refund(
+            refund_accounts,
+            account_offset + i,
             &relayer_refund_leaf.refund_addresses[i],
-        )
-        .map_err(|e| e.with_account_name(&format!("remaining_accounts[{}]", i)))?;
+            amount.to_owned(),
+        )?;
+    }
+
+    Ok(())
+}
+
+// Transfers refunds to relayer token accounts that can receive them and accrues the rest to claim_account PDAs. Token
+// accounts are expected in the first half of the leaf's refund accounts and claim accounts in the second half. Returns
+// whether each of the refunds got deferred.
+fn distribute_or_accrue_relayer_refunds(
+    refund_accounts: &RefundAccounts,
+    relayer_refund_leaf: &RelayerRefundLeaf,
+    account_offset: usize,
+) -> Result<Vec<bool>> {
+    let refund_count = relayer_refund_leaf.refund_addresses.len();
+    let mut refund_deferrals = Vec::with_capacity(refund_count);
+
+    for (i, amount) in relayer_refund_leaf.refund_amounts.iter().enumerate() {
+        // It should be safe to access elements of refund_addresses and remaining_accounts as their lengths are checked
+        // before calling this internal function.
+        let refund_token_account = &refund_accounts.remaining_accounts[account_offset + i];
+        if can_receive_relayer_refund(refund_accounts, refund_token_account, &relayer_refund_leaf.refund_addresses[i]) {
+            transfer_relayer_refund(refund_accounts, refund_token_account, amount.to_owned())?;
+            refund_deferrals.push(false);
+        } else {
+            accrue_relayer_refund(
+                refund_accounts,
+                account_offset + refund_count + i,
+                &relayer_refund_leaf.refund_addresses[i],
+                amount.to_owned(),
+            )?;
+            refund_deferrals.push(true);
+        }
+    }
 
-        claim_account.amount += amount;
+    Ok(refund_deferrals)
+}
 
-        // Persist the updated claim account (Anchor handles this only for static accounts).
-        claim_account
-            .exit(ctx.program_id)
-            .map_err(|e| e.with_account_name(&format!("remaining_accounts[{}]", i)))?;
+fn get_refund_token_address(refund_accounts: &RefundAccounts, refund_address: &Pubkey) -> Pubkey {
+    associated_token::get_associated_token_address_with_program_id(
+        refund_address,
+        &refund_accounts.mint.key(),
+        &refund_accounts.token_program.key(),
+    )
+}
+
+// Checks that the transfer CPI to the refund token account would not fail: it must be the relayer's existing ATA that
+// is writable, not frozen and, for Token-2022, neither requiring incoming transfer memos nor having CPI guard enabled.
+// Otherwise the refund is accrued to the claim account so that a single recipient cannot abort the whole leaf.
+fn can_receive_relayer_refund(
+    refund_accounts: &RefundAccounts,
+    refund_token_account: &AccountInfo,
+    refund_address: &Pubkey,
+) -> bool {
+    if refund_token_account.key() != get_refund_token_address(refund_accounts, refund_address)
+        || !refund_token_account.is_writable
+        || refund_token_account.owner != &refund_accounts.token_program.key()
+    {
+        return false;
     }
 
+    let is_frozen = refund_token_account
+        .try_borrow_data()
+        .map_err(Into::into)
+        .and_then(|data| TokenAccount::try_deserialize(&mut &data[..]))
+        .map(|token_account| token_account.is_frozen());
+
+    matches!(is_frozen, Ok(false)) && matches!(has_restricted_incoming_transfers(refund_token_account), Ok(false))
+}
+
+fn transfer_relayer_refund<'info>(
+    refund_accounts: &RefundAccounts<'_, 'info>,
+    refund_token_account: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    validate_mint_extensions(refund_accounts.mint)?;
+
+    // Derive the signer seeds for the state. The vault owns the state PDA so we need to derive this to create the
+    // signer seeds to execute the CPI transfer from the vault to the refund recipient's token account.
+    let state_seed_bytes = refund_accounts.state.seed.to_le_bytes();
+    let seeds = &[b"state", state_seed_bytes.as_ref(), &[refund_accounts.state_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_accounts = TransferChecked {
+        from: refund_accounts.vault.to_account_info(),
+        mint: refund_accounts.mint.to_account_info(),
+        to: refund_token_account.to_account_info(),
+        authority: refund_accounts.state.to_account_info(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(refund_accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+    transfer_checked(cpi_context, amount, refund_accounts.mint.decimals)
+}
+
+fn accrue_relayer_refund(
+    refund_accounts: &RefundAccounts,
+    account_index: usize,
+    refund_address: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let mut claim_account = ClaimAccount::try_from(
+        &refund_accounts.remaining_accounts[account_index],
+        &refund_accounts.mint.key(),
+        refund_address,
+    )
+    .map_err(|e| e.with_account_name(&format!("remaining_accounts[{}]", account_index)))?;
+
+    claim_account.amount += amount;
+
+    // Persist the updated claim account (Anchor handles this only for static accounts).
+    claim_account
+        .exit(&crate::ID)
+        .map_err(|e| e.with_account_name(&format!("remaining_accounts[{}]", account_index)))
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32)]
+pub struct CloseRootBundle<'info> {
+    #[account(mut, address = root_bundle.payer)]
+    pub payer: SystemAccount<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(
+        mut,
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump,
+        close = payer
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+}
+
+pub fn close_root_bundle(ctx: Context<CloseRootBundle>, root_bundle_id: u32) -> Result<()> {
+    let state = &ctx.accounts.state;
+    let root_bundle = &ctx.accounts.root_bundle;
+    let current_time = get_current_time(state)?;
+
+    // Root bundle is superseded when a newer one has been relayed (state.root_bundle_id is the next root bundle id).
+    let is_expired = root_bundle_id.saturating_add(1) < state.root_bundle_id
+        && current_time
+            >= root_bundle
+                .created_at
+                .saturating_add(state.root_bundle_close_grace_period);
+
+    // Executed leaves can only be counted for relayer refunds, so root bundles with any slow fill or typed roots are
+    // closable only after expiry.
+    let is_fully_executed = root_bundle.leaf_count == Some(root_bundle.claimed_leaf_count)
+        && root_bundle.slow_relay_root == [0u8; 32]
+        && root_bundle.typed_roots.is_empty();
+
+    if !is_expired && !is_fully_executed {
+        return err!(SvmError::RootBundleNotClosable);
+    }
+
+    emit_cpi!(ClosedRootBundle { root_bundle_id, payer: ctx.accounts.payer.key() });
+
     Ok(())
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32, root_type: u8)]
+pub struct CloseTypedRootClaims<'info> {
+    #[account(mut, address = typed_root_claims.payer)]
+    pub payer: SystemAccount<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: Root bundle PDA of the typed root, checked in the handler to be closed.
+    #[account(seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()], bump)]
+    pub root_bundle: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"typed_root_claims",
+            state.seed.to_le_bytes().as_ref(),
+            root_bundle_id.to_le_bytes().as_ref(),
+            &[root_type]
+        ],
+        bump,
+        close = payer
+    )]
+    pub typed_root_claims: Account<'info, TypedRootClaims>,
+}
+
+pub fn close_typed_root_claims(ctx: Context<CloseTypedRootClaims>, root_bundle_id: u32, root_type: u8) -> Result<()> {
+    // Typed root leaves can no longer be executed once their root bundle is closed, vetoed or deleted. Root bundle IDs
+    // are never reused, so the closed root bundle PDA cannot be recreated.
+    if !ctx.accounts.root_bundle.data_is_empty() {
+        return err!(SvmError::TypedRootClaimsNotClosable);
+    }
+
+    emit_cpi!(ClosedTypedRootClaims { root_bundle_id, root_type, payer: ctx.accounts.payer.key() });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32)]
+pub struct GetRootBundleLeaves<'info> {
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    #[account(seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()], bump)]
+    pub root_bundle: Account<'info, RootBundle>,
+}
+
+pub fn get_root_bundle_leaves(ctx: Context<GetRootBundleLeaves>) -> Result<RootBundleLeaves> {
+    let root_bundle = &ctx.accounts.root_bundle;
+
+    // Return the raw bitmap rather than lists of leaf IDs so that it fits in the 1024 byte return data for up to 8000
+    // leaves. Unclaimed leaves can only be derived off-chain when the leaf count was provided.
+    Ok(RootBundleLeaves {
+        leaf_count: root_bundle.leaf_count,
+        claimed_leaf_count: root_bundle.claimed_leaf_count,
+        claimed_bitmap: root_bundle.claimed_bitmap.clone(),
+    })
+}
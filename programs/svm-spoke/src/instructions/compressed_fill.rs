@@ -0,0 +1,510 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    common::RelayData,
+    constraints::is_relay_hash_valid,
+    error::{CommonError, SvmError},
+    event::{FillType, FilledRelay, MigratedFillStatus, RelayExecutionEventInfo, RequestedSlowFill},
+    instructions::{get_fill_type, validate_fill_deadlines, SlowFill},
+    state::{FillStatus, FillStatusAccount, FillStatusBucket, RelayFillStatus, RootBundle, State, TokenLimits},
+    utils::{
+        derive_seed_hash, get_current_time, hash_non_empty_message, invoke_handler, transfer_from,
+        validate_mint_extensions, verify_merkle_proof, FillSeedData,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], relay_data: RelayData)]
+pub struct FillRelayCompressed<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["delegate", seed_hash]; used as a CPI signer.
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = relay_data.output_token @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+        token::token_program = token_program
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = relay_data.recipient, // Ensures tokens go to ATA owned by the recipient.
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; must not exist unless migrated via migrate_fill_status.
+    #[account(
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = legacy_fill_status.owner != &crate::ID @ SvmError::LegacyFillStatusExists,
+        constraint = is_relay_hash_valid(&relay_hash, &relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub legacy_fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler as its entries are accessed in place.
+    #[account(mut)]
+    pub fill_status_bucket: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fill_relay_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRelayCompressed<'info>>,
+    relay_hash: [u8; 32],
+    relay_data: RelayData,
+    repayment_chain_id: u64,
+    repayment_address: Pubkey,
+) -> Result<()> {
+    require!(!ctx.accounts.state.paused_fills, CommonError::FillsArePaused);
+    require!(
+        ctx.accounts.state.uses_compressed_fill_status(relay_data.fill_deadline),
+        SvmError::RelayUsesLegacyFillStatus
+    );
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+    validate_fill_deadlines(&relay_data, &ctx.accounts.signer.key(), current_time)?;
+
+    // Check the fill status and set the fill type
+    let mut fill_status = RelayFillStatus::load(
+        &ctx.accounts.state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.legacy_fill_status,
+        Some(&ctx.accounts.fill_status_bucket),
+    )?;
+    let fill_type = get_fill_type(&fill_status.status)?;
+
+    // Delegation is derived the same as in fill_relay, so relayers can approve both fill methods the same way.
+    let seed_hash = derive_seed_hash(&(FillSeedData { relay_hash, repayment_chain_id, repayment_address }));
+
+    let output_amount = relay_data.output_amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_fill(current_time, output_amount))?;
+
+    // Relayer must have delegated output_amount to the delegate PDA
+    transfer_from(
+        &ctx.accounts.relayer_token_account,
+        &ctx.accounts.recipient_token_account,
+        relay_data.output_amount,
+        &ctx.accounts.delegate,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        seed_hash,
+    )?;
+
+    fill_status.set(FillStatus::Filled, None, &ctx.accounts.signer.to_account_info(), &ctx.accounts.system_program)?;
+
+    if !relay_data.message.is_empty() {
+        invoke_handler(ctx.accounts.signer.as_ref(), ctx.remaining_accounts, &relay_data.message)?;
+    }
+
+    // Empty message is not hashed and emits zeroed bytes32 for easier human observability.
+    let message_hash = hash_non_empty_message(&relay_data.message);
+
+    emit_cpi!(FilledRelay {
+        input_token: relay_data.input_token,
+        output_token: relay_data.output_token,
+        input_amount: relay_data.input_amount,
+        output_amount: relay_data.output_amount,
+        repayment_chain_id,
+        origin_chain_id: relay_data.origin_chain_id,
+        deposit_id: relay_data.deposit_id,
+        fill_deadline: relay_data.fill_deadline,
+        exclusivity_deadline: relay_data.exclusivity_deadline,
+        exclusive_relayer: relay_data.exclusive_relayer,
+        relayer: repayment_address,
+        depositor: relay_data.depositor,
+        recipient: relay_data.recipient,
+        message_hash,
+        relay_execution_info: RelayExecutionEventInfo {
+            updated_recipient: relay_data.recipient,
+            updated_message_hash: message_hash,
+            updated_output_amount: relay_data.output_amount,
+            fill_type,
+        },
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], relay_data: RelayData)]
+pub struct RequestSlowFillCompressed<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_fills @ CommonError::FillsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; must not exist unless migrated via migrate_fill_status.
+    #[account(
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = legacy_fill_status.owner != &crate::ID @ SvmError::LegacyFillStatusExists,
+        constraint = is_relay_hash_valid(&relay_hash, &relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub legacy_fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler as its entries are accessed in place.
+    #[account(mut)]
+    pub fill_status_bucket: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_slow_fill_compressed(
+    ctx: Context<RequestSlowFillCompressed>,
+    relay_hash: [u8; 32],
+    relay_data: RelayData,
+) -> Result<()> {
+    require!(
+        ctx.accounts.state.uses_compressed_fill_status(relay_data.fill_deadline),
+        SvmError::RelayUsesLegacyFillStatus
+    );
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+
+    // Check if the fill is past the exclusivity window & within the fill deadline.
+    if relay_data.exclusivity_deadline >= current_time {
+        return err!(CommonError::NoSlowFillsInExclusivityWindow);
+    }
+    if relay_data.fill_deadline < current_time {
+        return err!(CommonError::ExpiredFillDeadline);
+    }
+
+    // Check the fill status is unfilled.
+    let mut fill_status = RelayFillStatus::load(
+        &ctx.accounts.state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.legacy_fill_status,
+        Some(&ctx.accounts.fill_status_bucket),
+    )?;
+    if fill_status.status != FillStatus::Unfilled {
+        return err!(CommonError::InvalidSlowFillRequest);
+    }
+
+    fill_status.set(
+        FillStatus::RequestedSlowFill,
+        None,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
+
+    // Emit the RequestedSlowFill event. Empty message is not hashed and emits zeroed bytes32 for easier observability
+    let message_hash = hash_non_empty_message(&relay_data.message);
+
+    emit_cpi!(RequestedSlowFill {
+        input_token: relay_data.input_token,
+        output_token: relay_data.output_token,
+        input_amount: relay_data.input_amount,
+        output_amount: relay_data.output_amount,
+        origin_chain_id: relay_data.origin_chain_id,
+        deposit_id: relay_data.deposit_id,
+        fill_deadline: relay_data.fill_deadline,
+        exclusivity_deadline: relay_data.exclusivity_deadline,
+        exclusive_relayer: relay_data.exclusive_relayer,
+        depositor: relay_data.depositor,
+        recipient: relay_data.recipient,
+        message_hash,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], slow_fill_leaf: SlowFill, root_bundle_id: u32)]
+pub struct ExecuteSlowRelayLeafCompressed<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; must not exist unless migrated via migrate_fill_status.
+    #[account(
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = legacy_fill_status.owner != &crate::ID @ SvmError::LegacyFillStatusExists,
+        constraint = is_relay_hash_valid(&relay_hash, &slow_fill_leaf.relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub legacy_fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler as its entries are accessed in place.
+    #[account(mut)]
+    pub fill_status_bucket: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = slow_fill_leaf.relay_data.output_token @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = slow_fill_leaf.relay_data.recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = state,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_slow_relay_leaf_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteSlowRelayLeafCompressed<'info>>,
+    relay_hash: [u8; 32],
+    slow_fill_leaf: SlowFill,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .state
+            .uses_compressed_fill_status(slow_fill_leaf.relay_data.fill_deadline),
+        SvmError::RelayUsesLegacyFillStatus
+    );
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+
+    let relay_data = slow_fill_leaf.relay_data;
+
+    let slow_fill = SlowFill {
+        relay_data: relay_data.clone(),        // Clone relay_data to avoid move
+        chain_id: ctx.accounts.state.chain_id, // This overrides caller provided chain_id, same as in EVM SpokePool.
+        updated_output_amount: slow_fill_leaf.updated_output_amount,
+    };
+
+    let root = ctx.accounts.root_bundle.slow_relay_root;
+    let leaf = slow_fill.to_keccak_hash()?;
+    verify_merkle_proof(root, leaf, proof)?;
+
+    // Check if the fill deadline has passed
+    if relay_data.fill_deadline < current_time {
+        return err!(CommonError::ExpiredFillDeadline);
+    }
+
+    // Check if the fill status is not filled
+    let mut fill_status = RelayFillStatus::load(
+        &ctx.accounts.state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.legacy_fill_status,
+        Some(&ctx.accounts.fill_status_bucket),
+    )?;
+    if fill_status.status == FillStatus::Filled {
+        return err!(CommonError::RelayFilled);
+    }
+
+    let updated_output_amount = slow_fill_leaf.updated_output_amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| {
+        limits.record_outflow(current_time, updated_output_amount)
+    })?;
+
+    // Derive the signer seeds for the state
+    let state_seed_bytes = ctx.accounts.state.seed.to_le_bytes();
+    let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
+    let signer_seeds = &[&seeds[..]];
+
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
+    // Invoke the transfer_checked instruction on the token program
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.vault.to_account_info(), // Pull from the vault
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(), // Send to the recipient
+        authority: ctx.accounts.state.to_account_info(),            // Authority is the state (owner of the vault)
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+    transfer_checked(cpi_context, updated_output_amount, ctx.accounts.mint.decimals)?;
+
+    fill_status.set(FillStatus::Filled, None, &ctx.accounts.signer.to_account_info(), &ctx.accounts.system_program)?;
+
+    if !relay_data.message.is_empty() {
+        invoke_handler(ctx.accounts.signer.as_ref(), ctx.remaining_accounts, &relay_data.message)?;
+    }
+
+    // Empty message is not hashed and emits zeroed bytes32 for easier human observability.
+    let message_hash = hash_non_empty_message(&relay_data.message);
+
+    emit_cpi!(FilledRelay {
+        input_token: relay_data.input_token,
+        output_token: relay_data.output_token,
+        input_amount: relay_data.input_amount,
+        output_amount: relay_data.output_amount,
+        repayment_chain_id: 0, // There is no repayment chain id for slow fills.
+        origin_chain_id: relay_data.origin_chain_id,
+        deposit_id: relay_data.deposit_id,
+        fill_deadline: relay_data.fill_deadline,
+        exclusivity_deadline: relay_data.exclusivity_deadline,
+        exclusive_relayer: relay_data.exclusive_relayer,
+        relayer: Pubkey::default(), // There is no repayment address for slow
+        depositor: relay_data.depositor,
+        recipient: relay_data.recipient,
+        message_hash,
+        relay_execution_info: RelayExecutionEventInfo {
+            updated_recipient: relay_data.recipient,
+            updated_message_hash: message_hash,
+            updated_output_amount,
+            fill_type: FillType::SlowFill,
+        },
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], relay_data: RelayData)]
+pub struct MigrateFillStatus<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: Relayer recorded in the fill status PDA that receives its rent back.
+    #[account(mut, address = fill_status.relayer @ SvmError::NotRelayer)]
+    pub relayer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        close = relayer,
+        constraint = is_relay_hash_valid(&relay_hash, &relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub fill_status: Account<'info, FillStatusAccount>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler as its entries are accessed in place.
+    #[account(mut)]
+    pub fill_status_bucket: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_fill_status(ctx: Context<MigrateFillStatus>, relay_hash: [u8; 32], relay_data: RelayData) -> Result<()> {
+    // Relays before the cutover keep using their PDAs, so closing it here would allow filling the relay again.
+    require!(
+        ctx.accounts.state.uses_compressed_fill_status(relay_data.fill_deadline),
+        SvmError::RelayUsesLegacyFillStatus
+    );
+
+    let status = ctx.accounts.fill_status.status.clone();
+    if status != FillStatus::Unfilled {
+        RelayFillStatus::load_bucket(&relay_hash, &relay_data, &ctx.accounts.fill_status_bucket)?.set(
+            status,
+            None,
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    emit_cpi!(MigratedFillStatus { relay_hash, relayer: ctx.accounts.fill_status.relayer });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseFillStatusBucket<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: Payer recorded in the bucket that receives the rent of the empty bucket back, checked in the handler.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: FillStatusBucket with owner and discriminator checked in the handler as its entries are accessed in
+    /// place. No need to check seed derivation as this method only evaluates the bucket ID recorded in this account.
+    #[account(mut)]
+    pub fill_status_bucket: UncheckedAccount<'info>,
+}
+
+pub fn close_fill_status_bucket<'info>(ctx: Context<'_, '_, '_, 'info, CloseFillStatusBucket<'info>>) -> Result<()> {
+    let current_time = get_current_time(&ctx.accounts.state)?;
+
+    let fill_status_bucket = &ctx.accounts.fill_status_bucket;
+    let bucket = FillStatusBucket::try_load_header(fill_status_bucket)?;
+    if !bucket.is_expired(current_time) {
+        return err!(SvmError::FillStatusBucketNotExpired);
+    }
+
+    // Each funder in the remaining accounts gets back the rent of all entries it funded.
+    for (i, funder) in ctx.remaining_accounts.iter().enumerate() {
+        FillStatusBucket::close_funder_entries(fill_status_bucket, funder)
+            .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", i)))?;
+    }
+
+    // Bucket can only be closed once all its entries have been refunded to their funders.
+    if FillStatusBucket::get_entry_count(fill_status_bucket)? == 0 {
+        if ctx.accounts.payer.key() != bucket.payer {
+            return Err(Error::from(ErrorCode::ConstraintAddress)
+                .with_account_name("payer")
+                .with_pubkeys((ctx.accounts.payer.key(), bucket.payer)));
+        }
+        FillStatusBucket::close(fill_status_bucket, &ctx.accounts.payer)?;
+    }
+
+    Ok(())
+}
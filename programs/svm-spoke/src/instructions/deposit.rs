@@ -1,27 +1,40 @@
-// Note: The `svm-spoke` does not support `speedUpDeposit` and `fillRelayWithUpdatedDeposit` due to cryptographic
-// incompatibilities between Solana (Ed25519) and Ethereum (ECDSA secp256k1). Specifically, Solana wallets cannot
-// generate ECDSA signatures required for Ethereum verification. As a result, speed-up functionality on Solana is not
-// implemented. For more details, refer to the documentation: https://docs.across.to
+// Note: Solana wallets cannot generate ECDSA secp256k1 signatures required by EVM `speedUpDeposit`, so the
+// `speed_up_deposit` instruction instead expects the depositor's Ed25519 signature verified by the Ed25519 precompile
+// instruction within the same transaction. For more details, refer to the documentation: https://docs.across.to
 
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar,
+    system_program::{transfer, Transfer},
+};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{Mint, TokenAccount, TokenInterface},
+    token::spl_token::native_mint,
+    token_interface::{sync_native, Mint, SyncNative, TokenAccount, TokenInterface},
 };
 
 use crate::{
     constants::{MAX_EXCLUSIVITY_PERIOD_SECONDS, ZERO_DEPOSIT_ID},
     error::{CommonError, SvmError},
-    event::FundsDeposited,
-    state::State,
+    event::{FundsDeposited, RequestedSpeedUpDeposit},
+    state::{Route, State, TokenLimits},
     utils::{
-        derive_seed_hash, get_current_time, get_unsafe_deposit_id, transfer_from, DepositNowSeedData, DepositSeedData,
+        derive_seed_hash, get_current_time, get_unsafe_deposit_id, get_update_deposit_details_hash, transfer_from,
+        verify_ed25519_signature, DepositNowSeedData, DepositSeedData, UpdateDepositDetails,
     },
 };
 
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(depositor: Pubkey, recipient: Pubkey, input_token: Pubkey)]
+#[instruction(
+    depositor: Pubkey,
+    recipient: Pubkey,
+    input_token: Pubkey,
+    output_token: Pubkey,
+    input_amount: u64,
+    output_amount: [u8; 32],
+    destination_chain_id: u64
+)]
 pub struct Deposit<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -34,6 +47,18 @@ pub struct Deposit<'info> {
     )]
     pub state: Account<'info, State>,
 
+    #[account(
+        seeds = [
+            b"route",
+            input_token.as_ref(),
+            state.seed.to_le_bytes().as_ref(),
+            destination_chain_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = route.enabled @ CommonError::DisabledRoute
+    )]
+    pub route: Account<'info, Route>,
+
     /// CHECK: PDA derived with seeds ["delegate", seed_hash]; used as a CPI signer.
     pub delegate: UncheckedAccount<'info>,
 
@@ -60,6 +85,10 @@ pub struct Deposit<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -87,26 +116,17 @@ pub fn _deposit(
     let state = &mut ctx.accounts.state;
     let current_time = get_current_time(state)?;
 
-    if output_token == Pubkey::default() {
-        return err!(CommonError::InvalidOutputToken);
-    }
-
-    if current_time.checked_sub(quote_timestamp).unwrap_or(u32::MAX) > state.deposit_quote_time_buffer {
-        return err!(CommonError::InvalidQuoteTimestamp);
-    }
-    if fill_deadline > current_time + state.fill_deadline_buffer {
-        return err!(CommonError::InvalidFillDeadline);
-    }
+    let exclusivity_deadline = validate_deposit(
+        state,
+        current_time,
+        output_token,
+        exclusive_relayer,
+        quote_timestamp,
+        fill_deadline,
+        exclusivity_parameter,
+    )?;
 
-    let mut exclusivity_deadline = exclusivity_parameter;
-    if exclusivity_deadline > 0 {
-        if exclusivity_deadline <= MAX_EXCLUSIVITY_PERIOD_SECONDS {
-            exclusivity_deadline += current_time;
-        }
-        if exclusive_relayer == Pubkey::default() {
-            return err!(CommonError::InvalidExclusiveRelayer);
-        }
-    }
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_deposit(current_time, input_amount))?;
 
     // Depositor must have delegated input_amount to the delegate PDA
     transfer_from(
@@ -119,12 +139,7 @@ pub fn _deposit(
         delegate_seed_hash,
     )?;
 
-    let mut applied_deposit_id = deposit_id;
-    // If the passed in deposit_id is all zeros, then we use the state's number of deposits as deposit_id.
-    if deposit_id == ZERO_DEPOSIT_ID {
-        state.number_of_deposits += 1;
-        applied_deposit_id[28..].copy_from_slice(&state.number_of_deposits.to_be_bytes());
-    }
+    let applied_deposit_id = apply_deposit_id(state, deposit_id);
 
     emit_cpi!(FundsDeposited {
         input_token,
@@ -145,6 +160,51 @@ pub fn _deposit(
     Ok(())
 }
 
+// Validates deposit parameters shared by all deposit flavors and returns the resolved exclusivity deadline.
+fn validate_deposit(
+    state: &State,
+    current_time: u32,
+    output_token: Pubkey,
+    exclusive_relayer: Pubkey,
+    quote_timestamp: u32,
+    fill_deadline: u32,
+    exclusivity_parameter: u32,
+) -> Result<u32> {
+    if output_token == Pubkey::default() {
+        return err!(CommonError::InvalidOutputToken);
+    }
+
+    if current_time.checked_sub(quote_timestamp).unwrap_or(u32::MAX) > state.deposit_quote_time_buffer {
+        return err!(CommonError::InvalidQuoteTimestamp);
+    }
+    if fill_deadline > current_time + state.fill_deadline_buffer {
+        return err!(CommonError::InvalidFillDeadline);
+    }
+
+    let mut exclusivity_deadline = exclusivity_parameter;
+    if exclusivity_deadline > 0 {
+        if exclusivity_deadline <= MAX_EXCLUSIVITY_PERIOD_SECONDS {
+            exclusivity_deadline += current_time;
+        }
+        if exclusive_relayer == Pubkey::default() {
+            return err!(CommonError::InvalidExclusiveRelayer);
+        }
+    }
+
+    Ok(exclusivity_deadline)
+}
+
+fn apply_deposit_id(state: &mut State, deposit_id: [u8; 32]) -> [u8; 32] {
+    let mut applied_deposit_id = deposit_id;
+    // If the passed in deposit_id is all zeros, then we use the state's number of deposits as deposit_id.
+    if deposit_id == ZERO_DEPOSIT_ID {
+        state.number_of_deposits += 1;
+        applied_deposit_id[28..].copy_from_slice(&state.number_of_deposits.to_be_bytes());
+    }
+
+    applied_deposit_id
+}
+
 pub fn deposit(
     ctx: Context<Deposit>,
     depositor: Pubkey,
@@ -303,3 +363,166 @@ pub fn unsafe_deposit(
 
     Ok(())
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(
+    depositor: Pubkey,
+    recipient: Pubkey,
+    input_token: Pubkey,
+    output_token: Pubkey,
+    input_amount: u64,
+    output_amount: [u8; 32],
+    destination_chain_id: u64
+)]
+pub struct DepositNative<'info> {
+    #[account(mut, address = depositor @ SvmError::InvalidNativeDepositor)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_deposits @ CommonError::DepositsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [
+            b"route",
+            input_token.as_ref(),
+            state.seed.to_le_bytes().as_ref(),
+            destination_chain_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = route.enabled @ CommonError::DisabledRoute
+    )]
+    pub route: Account<'info, Route>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = state, // Ensure owner is the state as wrapped lamports are sent here on deposit.
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = native_mint::ID @ SvmError::InvalidMint,
+        constraint = mint.key() == input_token @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_native(
+    ctx: Context<DepositNative>,
+    depositor: Pubkey,
+    recipient: Pubkey,
+    input_token: Pubkey,
+    output_token: Pubkey,
+    input_amount: u64,
+    output_amount: [u8; 32],
+    destination_chain_id: u64,
+    exclusive_relayer: Pubkey,
+    quote_timestamp: u32,
+    fill_deadline: u32,
+    exclusivity_parameter: u32,
+    message: Vec<u8>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    let current_time = get_current_time(state)?;
+
+    let exclusivity_deadline = validate_deposit(
+        state,
+        current_time,
+        output_token,
+        exclusive_relayer,
+        quote_timestamp,
+        fill_deadline,
+        exclusivity_parameter,
+    )?;
+
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_deposit(current_time, input_amount))?;
+
+    // Wrap the signer's lamports into the vault and sync its wSOL balance.
+    let transfer_accounts =
+        Transfer { from: ctx.accounts.signer.to_account_info(), to: ctx.accounts.vault.to_account_info() };
+    transfer(CpiContext::new(ctx.accounts.system_program.to_account_info(), transfer_accounts), input_amount)?;
+    let sync_native_accounts = SyncNative { account: ctx.accounts.vault.to_account_info() };
+    sync_native(CpiContext::new(ctx.accounts.token_program.to_account_info(), sync_native_accounts))?;
+
+    let applied_deposit_id = apply_deposit_id(state, ZERO_DEPOSIT_ID);
+
+    emit_cpi!(FundsDeposited {
+        input_token,
+        output_token,
+        input_amount,
+        output_amount,
+        destination_chain_id,
+        deposit_id: applied_deposit_id,
+        quote_timestamp,
+        fill_deadline,
+        exclusivity_deadline,
+        depositor,
+        recipient,
+        exclusive_relayer,
+        message,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SpeedUpDeposit<'info> {
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: Instructions sysvar used to load the Ed25519 signature verification instruction.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn speed_up_deposit(
+    ctx: Context<SpeedUpDeposit>,
+    depositor: Pubkey,
+    deposit_id: [u8; 32],
+    updated_output_amount: [u8; 32],
+    updated_recipient: Pubkey,
+    updated_message: Vec<u8>,
+    depositor_signature: Vec<u8>,
+) -> Result<()> {
+    // This is the origin chain of the deposit, so we sign over this spoke's chain_id.
+    let update_hash = get_update_deposit_details_hash(&UpdateDepositDetails {
+        deposit_id,
+        origin_chain_id: ctx.accounts.state.chain_id,
+        updated_output_amount,
+        updated_recipient,
+        updated_message: &updated_message,
+    });
+    verify_ed25519_signature(&ctx.accounts.instructions, &depositor, &depositor_signature, &update_hash)?;
+
+    emit_cpi!(RequestedSpeedUpDeposit {
+        updated_output_amount,
+        deposit_id,
+        depositor,
+        updated_recipient,
+        updated_message,
+        depositor_signature,
+    });
+
+    Ok(())
+}
@@ -1,17 +1,28 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar,
+    system_program::{transfer, Transfer},
+};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{Mint, TokenAccount, TokenInterface},
+    token::spl_token::native_mint,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 use crate::{
     common::RelayData,
-    constants::DISCRIMINATOR_SIZE,
-    constraints::is_relay_hash_valid,
+    constraints::{is_relay_hash_valid, is_valid_associated_token_account},
     error::{CommonError, SvmError},
     event::{FillType, FilledRelay, RelayExecutionEventInfo},
-    state::{FillRelayParams, FillStatus, FillStatusAccount, State},
-    utils::{derive_seed_hash, get_current_time, hash_non_empty_message, invoke_handler, transfer_from, FillSeedData},
+    state::{
+        FillRelayParams, FillRelayWithUpdatedDepositParams, FillRelaysBatchParams, FillStatus, FillStatusAccount,
+        NativeRecipient, RelayFillStatus, RelayerProfile, State, TokenLimits,
+    },
+    utils::{
+        derive_seed_hash, get_current_time, get_evm_update_deposit_details_hash, get_update_deposit_details_hash,
+        hash_non_empty_message, invoke_handler, is_evm_address, transfer_from, validate_mint_extensions,
+        verify_ed25519_signature, verify_evm_signature, FillBatchSeedData, FillSeedData, UpdateDepositDetails,
+    },
 };
 
 #[event_cpi]
@@ -40,6 +51,10 @@ pub struct FillRelay<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
     #[account(
         mut,
         token::mint = mint,
@@ -60,10 +75,10 @@ pub struct FillRelay<'info> {
     )]
     pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and is initialized in the handler when needed.
     #[account(
-        init_if_needed,
-        payer = signer,
-        space = DISCRIMINATOR_SIZE + FillStatusAccount::INIT_SPACE,
+        mut,
         seeds = [b"fills", relay_hash.as_ref()],
         bump,
         constraint = is_relay_hash_valid(
@@ -71,7 +86,12 @@ pub struct FillRelay<'info> {
             &relay_data.clone().unwrap_or_else(|| instruction_params.as_ref().unwrap().relay_data.clone()),
             &state) @ SvmError::InvalidRelayHash
     )]
-    pub fill_status: Account<'info, FillStatusAccount>,
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -85,63 +105,694 @@ pub fn fill_relay<'info>(
     repayment_chain_id: Option<u64>,
     repayment_address: Option<Pubkey>,
 ) -> Result<()> {
+    let FillRelayParams { relay_data, repayment_chain_id, repayment_address } =
+        unwrap_fill_relay_params(relay_data, repayment_chain_id, repayment_address, &ctx.accounts.instruction_params);
+
+    // Relayer must have delegated output_amount to the delegate PDA
+    let seed_hash = derive_seed_hash(&(FillSeedData { relay_hash, repayment_chain_id, repayment_address }));
+    let funding_source = FillFundingSource::Delegate {
+        token_account: &ctx.accounts.relayer_token_account,
+        delegate: &ctx.accounts.delegate,
+        seed_hash,
+    };
+
+    let filled_relay = fill_relay_from_source(
+        FillAccounts {
+            state: &ctx.accounts.state,
+            signer: &ctx.accounts.signer,
+            mint: &ctx.accounts.mint,
+            token_limits: &ctx.accounts.token_limits,
+            recipient_token_account: &ctx.accounts.recipient_token_account,
+            fill_status: &ctx.accounts.fill_status,
+            fill_status_bucket: ctx.accounts.fill_status_bucket.as_deref(),
+            token_program: &ctx.accounts.token_program,
+            system_program: &ctx.accounts.system_program,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        funding_source,
+        ctx.accounts.signer.key(),
+        relay_hash,
+        &relay_data,
+        repayment_chain_id,
+        repayment_address,
+    )?;
+    emit_cpi!(filled_relay);
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], relay_data: RelayData)]
+pub struct FillRelayNative<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), native_mint::ID.as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    // Lamports are sent directly to the recipient's system account instead of its wSOL ATA.
+    #[account(mut, address = relay_data.recipient)]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"native_recipient", relay_data.recipient.as_ref()],
+        bump,
+        constraint = native_recipient.enabled @ SvmError::NativeRecipientNotEnabled
+    )]
+    pub native_recipient: Account<'info, NativeRecipient>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and is initialized in the handler when needed.
+    #[account(
+        mut,
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = is_relay_hash_valid(&relay_hash, &relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fill_relay_native(
+    ctx: Context<FillRelayNative>,
+    relay_hash: [u8; 32],
+    relay_data: RelayData,
+    repayment_chain_id: u64,
+    repayment_address: Pubkey,
+) -> Result<()> {
+    require!(!ctx.accounts.state.paused_fills, CommonError::FillsArePaused);
+    require!(relay_data.output_token == native_mint::ID, SvmError::InvalidNativeOutputToken);
+    require!(relay_data.message.is_empty(), SvmError::NativeFillMessageNotSupported);
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+    validate_fill_deadlines(&relay_data, &ctx.accounts.signer.key(), current_time)?;
+
+    // Check the fill status and set the fill type
+    let mut fill_status = RelayFillStatus::load(
+        &ctx.accounts.state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.fill_status,
+        ctx.accounts.fill_status_bucket.as_deref(),
+    )?;
+    let fill_type = get_fill_type(&fill_status.status)?;
+
+    let output_amount = relay_data.output_amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_fill(current_time, output_amount))?;
+
+    // Relayer sends output_amount of native lamports, equivalent to unwrapped wSOL, directly to the recipient.
+    let transfer_accounts =
+        Transfer { from: ctx.accounts.signer.to_account_info(), to: ctx.accounts.recipient.to_account_info() };
+    transfer(CpiContext::new(ctx.accounts.system_program.to_account_info(), transfer_accounts), output_amount)?;
+
+    // Update the fill status to Filled, set the relayer and fill deadline
+    fill_status.set(
+        FillStatus::Filled,
+        Some(ctx.accounts.signer.key()),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
+
+    // Native fills have no message, so this emits zeroed bytes32 same as other fills with empty messages.
+    let message_hash = hash_non_empty_message(&relay_data.message);
+
+    emit_cpi!(FilledRelay {
+        input_token: relay_data.input_token,
+        output_token: relay_data.output_token,
+        input_amount: relay_data.input_amount,
+        output_amount: relay_data.output_amount,
+        repayment_chain_id,
+        origin_chain_id: relay_data.origin_chain_id,
+        deposit_id: relay_data.deposit_id,
+        fill_deadline: relay_data.fill_deadline,
+        exclusivity_deadline: relay_data.exclusivity_deadline,
+        exclusive_relayer: relay_data.exclusive_relayer,
+        relayer: repayment_address,
+        depositor: relay_data.depositor,
+        recipient: relay_data.recipient,
+        message_hash,
+        relay_execution_info: RelayExecutionEventInfo {
+            updated_recipient: relay_data.recipient,
+            updated_message_hash: message_hash,
+            updated_output_amount: relay_data.output_amount,
+            fill_type,
+        },
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], relay_data: RelayData)]
+pub struct FillRelayWithProfile<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    // The profile PDA is the standing delegate that the relayer approved on its token account.
+    #[account(
+        seeds = [b"relayer_profile", relayer_profile.relayer.as_ref()],
+        bump,
+        constraint = relayer_profile.is_allowed_signer(&signer.key()) @ SvmError::InvalidRelayerProfileSigner
+    )]
+    pub relayer_profile: Account<'info, RelayerProfile>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = relay_data.output_token @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = relayer_profile.relayer,
+        token::token_program = token_program
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = relay_data.recipient, // Ensures tokens go to ATA owned by the recipient.
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and is initialized in the handler when needed.
+    #[account(
+        mut,
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = is_relay_hash_valid(&relay_hash, &relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fill_relay_with_profile<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRelayWithProfile<'info>>,
+    relay_hash: [u8; 32],
+    relay_data: RelayData,
+) -> Result<()> {
+    let relayer_profile = &ctx.accounts.relayer_profile;
+
+    // Relayer must have delegated at least output_amount to its relayer profile PDA
+    let seeds: &[&[u8]] = &[
+        b"relayer_profile",
+        relayer_profile.relayer.as_ref(),
+        &[ctx.bumps.relayer_profile],
+    ];
+    let relayer_profile_info = relayer_profile.to_account_info();
+    let funding_source = FillFundingSource::Pda {
+        token_account: &ctx.accounts.relayer_token_account,
+        authority: &relayer_profile_info,
+        signer_seeds: seeds,
+    };
+
+    // The profile delegate fills on behalf of the relayer, so exclusivity is checked against the profile relayer that
+    // is also recorded in the fill status instead of its delegate signer.
+    let filled_relay = fill_relay_from_source(
+        FillAccounts {
+            state: &ctx.accounts.state,
+            signer: &ctx.accounts.signer,
+            mint: &ctx.accounts.mint,
+            token_limits: &ctx.accounts.token_limits,
+            recipient_token_account: &ctx.accounts.recipient_token_account,
+            fill_status: &ctx.accounts.fill_status,
+            fill_status_bucket: ctx.accounts.fill_status_bucket.as_deref(),
+            token_program: &ctx.accounts.token_program,
+            system_program: &ctx.accounts.system_program,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        funding_source,
+        relayer_profile.relayer,
+        relay_hash,
+        &relay_data,
+        relayer_profile.repayment_chain_id,
+        relayer_profile.repayment_address,
+    )?;
+    emit_cpi!(filled_relay);
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relay_hash: [u8; 32], relay_data: RelayData)]
+pub struct FillRelayFromInventory<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["relayer_inventory", signer]; used as the inventory token account authority.
+    #[account(seeds = [b"relayer_inventory", signer.key().as_ref()], bump)]
+    pub relayer_inventory: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = relay_data.output_token @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = relayer_inventory,
+        associated_token::token_program = token_program
+    )]
+    pub inventory_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = relay_data.recipient, // Ensures tokens go to ATA owned by the recipient.
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and is initialized in the handler when needed.
+    #[account(
+        mut,
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = is_relay_hash_valid(&relay_hash, &relay_data, &state) @ SvmError::InvalidRelayHash
+    )]
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fill_relay_from_inventory<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRelayFromInventory<'info>>,
+    relay_hash: [u8; 32],
+    relay_data: RelayData,
+    repayment_chain_id: u64,
+    repayment_address: Pubkey,
+) -> Result<()> {
+    // Only the relayer can sign for its inventory, so no per-fill delegation is needed.
+    let signer_key = ctx.accounts.signer.key();
+    let seeds: &[&[u8]] = &[
+        b"relayer_inventory",
+        signer_key.as_ref(),
+        &[ctx.bumps.relayer_inventory],
+    ];
+    let funding_source = FillFundingSource::Pda {
+        token_account: &ctx.accounts.inventory_token_account,
+        authority: &ctx.accounts.relayer_inventory,
+        signer_seeds: seeds,
+    };
+
+    let filled_relay = fill_relay_from_source(
+        FillAccounts {
+            state: &ctx.accounts.state,
+            signer: &ctx.accounts.signer,
+            mint: &ctx.accounts.mint,
+            token_limits: &ctx.accounts.token_limits,
+            recipient_token_account: &ctx.accounts.recipient_token_account,
+            fill_status: &ctx.accounts.fill_status,
+            fill_status_bucket: ctx.accounts.fill_status_bucket.as_deref(),
+            token_program: &ctx.accounts.token_program,
+            system_program: &ctx.accounts.system_program,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        funding_source,
+        signer_key,
+        relay_hash,
+        &relay_data,
+        repayment_chain_id,
+        repayment_address,
+    )?;
+    emit_cpi!(filled_relay);
+
+    Ok(())
+}
+
+// Accounts used by fills that send the output tokens to the recipient's token account.
+pub(crate) struct FillAccounts<'a, 'info> {
+    pub state: &'a Account<'info, State>,
+    pub signer: &'a Signer<'info>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_limits: &'a UncheckedAccount<'info>,
+    pub recipient_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub fill_status: &'a UncheckedAccount<'info>,
+    pub fill_status_bucket: Option<&'a AccountInfo<'info>>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub system_program: &'a Program<'info, System>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+// Token account funding the fill and the program PDA authorized to transfer from it.
+pub(crate) enum FillFundingSource<'a, 'info> {
+    // Relayer's token account that approved output_amount to the delegate PDA derived from the seed hash.
+    Delegate {
+        token_account: &'a InterfaceAccount<'info, TokenAccount>,
+        delegate: &'a UncheckedAccount<'info>,
+        seed_hash: [u8; 32],
+    },
+    // Token account owned by or standing approved to the PDA that signs with the provided seeds.
+    Pda {
+        token_account: &'a InterfaceAccount<'info, TokenAccount>,
+        authority: &'a AccountInfo<'info>,
+        signer_seeds: &'a [&'a [u8]],
+    },
+}
+
+// Fills the relay from the funding source and returns the FilledRelay event for the caller to emit. The relayer is
+// checked against the exclusive relayer and recorded in the fill status, while the signer pays for the fill status
+// and is passed to the message handler.
+pub(crate) fn fill_relay_from_source<'a, 'info>(
+    accounts: FillAccounts<'a, 'info>,
+    funding_source: FillFundingSource<'a, 'info>,
+    relayer: Pubkey,
+    relay_hash: [u8; 32],
+    relay_data: &'a RelayData,
+    repayment_chain_id: u64,
+    repayment_address: Pubkey,
+) -> Result<FilledRelay> {
     // This type of constraint normally would be checked in the context, but had to move it here in the handler to avoid
     // exceeding maximum stack offset.
-    require!(!ctx.accounts.state.paused_fills, CommonError::FillsArePaused);
+    require!(!accounts.state.paused_fills, CommonError::FillsArePaused);
 
-    let FillRelayParams { relay_data, repayment_chain_id, repayment_address } =
-        unwrap_fill_relay_params(relay_data, repayment_chain_id, repayment_address, &ctx.accounts.instruction_params);
+    let current_time = get_current_time(accounts.state)?;
+    validate_fill_deadlines(relay_data, &relayer, current_time)?;
 
-    let state = &ctx.accounts.state;
-    let current_time = get_current_time(state)?;
+    // Check the fill status and set the fill type
+    let mut fill_status = RelayFillStatus::load(
+        accounts.state,
+        &relay_hash,
+        relay_data,
+        accounts.fill_status,
+        accounts.fill_status_bucket,
+    )?;
+    let fill_type = get_fill_type(&fill_status.status)?;
+
+    let output_amount = relay_data.output_amount;
+    TokenLimits::update(accounts.token_limits, |limits| limits.record_fill(current_time, output_amount))?;
+
+    match funding_source {
+        FillFundingSource::Delegate { token_account, delegate, seed_hash } => transfer_from(
+            token_account,
+            accounts.recipient_token_account,
+            output_amount,
+            delegate,
+            accounts.mint,
+            accounts.token_program,
+            seed_hash,
+        )?,
+        FillFundingSource::Pda { token_account, authority, signer_seeds } => {
+            validate_mint_extensions(accounts.mint)?;
+            let transfer_accounts = TransferChecked {
+                from: token_account.to_account_info(),
+                mint: accounts.mint.to_account_info(),
+                to: accounts.recipient_token_account.to_account_info(),
+                authority: authority.clone(),
+            };
+            let signer_seeds = &[signer_seeds];
+            let cpi_context =
+                CpiContext::new_with_signer(accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+            transfer_checked(cpi_context, output_amount, accounts.mint.decimals)?;
+        }
+    }
+
+    // Update the fill status to Filled, set the relayer and fill deadline
+    fill_status.set(FillStatus::Filled, Some(relayer), &accounts.signer.to_account_info(), accounts.system_program)?;
+
+    if !relay_data.message.is_empty() {
+        invoke_handler(accounts.signer.as_ref(), accounts.remaining_accounts, &relay_data.message)?;
+    }
+
+    // Empty message is not hashed and emits zeroed bytes32 for easier human observability.
+    let message_hash = hash_non_empty_message(&relay_data.message);
+
+    Ok(FilledRelay {
+        input_token: relay_data.input_token,
+        output_token: relay_data.output_token,
+        input_amount: relay_data.input_amount,
+        output_amount: relay_data.output_amount,
+        repayment_chain_id,
+        origin_chain_id: relay_data.origin_chain_id,
+        deposit_id: relay_data.deposit_id,
+        fill_deadline: relay_data.fill_deadline,
+        exclusivity_deadline: relay_data.exclusivity_deadline,
+        exclusive_relayer: relay_data.exclusive_relayer,
+        relayer: repayment_address,
+        depositor: relay_data.depositor,
+        recipient: relay_data.recipient,
+        message_hash,
+        relay_execution_info: RelayExecutionEventInfo {
+            updated_recipient: relay_data.recipient,
+            updated_message_hash: message_hash,
+            updated_output_amount: relay_data.output_amount,
+            fill_type,
+        },
+    })
+}
 
-    // Check if the exclusivity deadline has passed or if the caller is the exclusive relayer
-    if relay_data.exclusive_relayer != ctx.accounts.signer.key()
+// Checks that the caller can fill the relay at the current time: either the exclusivity deadline has passed or the
+// caller is the exclusive relayer, and the fill deadline has not passed yet.
+pub(crate) fn validate_fill_deadlines(relay_data: &RelayData, relayer: &Pubkey, current_time: u32) -> Result<()> {
+    if relay_data.exclusive_relayer != *relayer
         && relay_data.exclusivity_deadline >= current_time
         && relay_data.exclusive_relayer != Pubkey::default()
     {
         return err!(CommonError::NotExclusiveRelayer);
     }
 
-    // Check if the fill deadline has passed
     if relay_data.fill_deadline < current_time {
         return err!(CommonError::ExpiredFillDeadline);
     }
 
-    // Check the fill status and set the fill type
-    let fill_status_account = &mut ctx.accounts.fill_status;
-    let fill_type = match fill_status_account.status {
-        FillStatus::Filled => {
-            return err!(CommonError::RelayFilled);
+    Ok(())
+}
+
+// Resolves the fill type from the current fill status, rejecting relays that have already been filled.
+pub(crate) fn get_fill_type(fill_status: &FillStatus) -> Result<FillType> {
+    match fill_status {
+        FillStatus::Filled => err!(CommonError::RelayFilled),
+        FillStatus::RequestedSlowFill => Ok(FillType::ReplacedSlowFill),
+        _ => Ok(FillType::FastFill),
+    }
+}
+
+// Helper to unwrap optional instruction params with fallback loading from buffer account.
+fn unwrap_fill_relay_params(
+    relay_data: Option<RelayData>,
+    repayment_chain_id: Option<u64>,
+    repayment_address: Option<Pubkey>,
+    account: &Option<Account<FillRelayParams>>,
+) -> FillRelayParams {
+    match (relay_data, repayment_chain_id, repayment_address) {
+        (Some(relay_data), Some(repayment_chain_id), Some(repayment_address)) => {
+            FillRelayParams { relay_data, repayment_chain_id, repayment_address }
         }
-        FillStatus::RequestedSlowFill => FillType::ReplacedSlowFill,
-        _ => FillType::FastFill,
-    };
+        _ => account
+            .as_ref()
+            .map(|account| FillRelayParams {
+                relay_data: account.relay_data.clone(),
+                repayment_chain_id: account.repayment_chain_id,
+                repayment_address: account.repayment_address,
+            })
+            .unwrap(), // We do not expect this to panic here as missing instruction_params is unwrapped in context.
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdatedDepositData {
+    pub updated_output_amount: u64,
+    pub updated_recipient: Pubkey,
+    pub updated_message: Vec<u8>,
+    pub depositor_signature: Vec<u8>,
+}
 
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(
+    relay_hash: [u8; 32],
+    relay_data: Option<RelayData>,
+    repayment_chain_id: Option<u64>,
+    repayment_address: Option<Pubkey>,
+    updated_deposit_data: Option<UpdatedDepositData>
+)]
+pub struct FillRelayWithUpdatedDeposit<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // This is required as fallback when None instruction params are passed in arguments.
+    #[account(mut, seeds = [b"instruction_params", signer.key().as_ref()], bump, close = signer)]
+    pub instruction_params: Option<Account<'info, FillRelayWithUpdatedDepositParams>>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["delegate", seed_hash]; used as a CPI signer.
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = relay_data
+            .clone()
+            .unwrap_or_else(|| instruction_params.as_ref().unwrap().relay_data.clone())
+            .output_token @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+        token::token_program = token_program
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        // Ensures tokens go to ATA owned by the updated recipient signed by the depositor.
+        associated_token::authority = updated_deposit_data
+            .clone()
+            .unwrap_or_else(|| instruction_params.as_ref().unwrap().updated_deposit_data.clone())
+            .updated_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and is initialized in the handler when needed.
+    #[account(
+        mut,
+        seeds = [b"fills", relay_hash.as_ref()],
+        bump,
+        constraint = is_relay_hash_valid(
+            &relay_hash,
+            &relay_data.clone().unwrap_or_else(|| instruction_params.as_ref().unwrap().relay_data.clone()),
+            &state) @ SvmError::InvalidRelayHash
+    )]
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Instructions sysvar used to load the Ed25519 signature verification instruction.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fill_relay_with_updated_deposit<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRelayWithUpdatedDeposit<'info>>,
+    relay_hash: [u8; 32],
+    relay_data: Option<RelayData>,
+    repayment_chain_id: Option<u64>,
+    repayment_address: Option<Pubkey>,
+    updated_deposit_data: Option<UpdatedDepositData>,
+) -> Result<()> {
+    require!(!ctx.accounts.state.paused_fills, CommonError::FillsArePaused);
+
+    let FillRelayWithUpdatedDepositParams { relay_data, repayment_chain_id, repayment_address, updated_deposit_data } =
+        unwrap_fill_relay_with_updated_deposit_params(
+            relay_data,
+            repayment_chain_id,
+            repayment_address,
+            updated_deposit_data,
+            &ctx.accounts.instruction_params,
+        );
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+    validate_fill_deadlines(&relay_data, &ctx.accounts.signer.key(), current_time)?;
+
+    verify_updated_deposit_signature(&relay_data, &updated_deposit_data, &ctx.accounts.instructions)?;
+
+    let mut fill_status = RelayFillStatus::load(
+        &ctx.accounts.state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.fill_status,
+        ctx.accounts.fill_status_bucket.as_deref(),
+    )?;
+    let fill_type = get_fill_type(&fill_status.status)?;
+
+    // Delegation is bound to the original relay hash, same as in fill_relay.
     let seed_hash = derive_seed_hash(&(FillSeedData { relay_hash, repayment_chain_id, repayment_address }));
 
-    // Relayer must have delegated output_amount to the delegate PDA
+    let updated_output_amount = updated_deposit_data.updated_output_amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_fill(current_time, updated_output_amount))?;
+
+    // Relayer must have delegated updated_output_amount to the delegate PDA
     transfer_from(
         &ctx.accounts.relayer_token_account,
         &ctx.accounts.recipient_token_account,
-        relay_data.output_amount,
+        updated_deposit_data.updated_output_amount,
         &ctx.accounts.delegate,
         &ctx.accounts.mint,
         &ctx.accounts.token_program,
         seed_hash,
     )?;
 
-    // Update the fill status to Filled, set the relayer and fill deadline
-    fill_status_account.status = FillStatus::Filled;
-    fill_status_account.relayer = *ctx.accounts.signer.key;
-    fill_status_account.fill_deadline = relay_data.fill_deadline;
+    fill_status.set(
+        FillStatus::Filled,
+        Some(ctx.accounts.signer.key()),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
 
-    if !relay_data.message.is_empty() {
-        invoke_handler(ctx.accounts.signer.as_ref(), ctx.remaining_accounts, &relay_data.message)?;
+    if !updated_deposit_data.updated_message.is_empty() {
+        invoke_handler(ctx.accounts.signer.as_ref(), ctx.remaining_accounts, &updated_deposit_data.updated_message)?;
     }
 
-    // Empty message is not hashed and emits zeroed bytes32 for easier human observability.
+    // Original message hash identifies the deposit while the updated one reflects what was actually executed.
     let message_hash = hash_non_empty_message(&relay_data.message);
+    let updated_message_hash = hash_non_empty_message(&updated_deposit_data.updated_message);
 
     emit_cpi!(FilledRelay {
         input_token: relay_data.input_token,
@@ -159,9 +810,9 @@ pub fn fill_relay<'info>(
         recipient: relay_data.recipient,
         message_hash,
         relay_execution_info: RelayExecutionEventInfo {
-            updated_recipient: relay_data.recipient,
-            updated_message_hash: message_hash,
-            updated_output_amount: relay_data.output_amount,
+            updated_recipient: updated_deposit_data.updated_recipient,
+            updated_message_hash,
+            updated_output_amount: updated_deposit_data.updated_output_amount,
             fill_type,
         },
     });
@@ -169,28 +820,277 @@ pub fn fill_relay<'info>(
     Ok(())
 }
 
+// Verifies the depositor's Ed25519 signature over the updated deposit details. The depositor signs over the origin
+// chain id of the deposit, so the same signature can be used on the origin chain speed up and on the destination fill.
+fn verify_updated_deposit_signature(
+    relay_data: &RelayData,
+    updated_deposit_data: &UpdatedDepositData,
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    let mut updated_output_amount = [0u8; 32];
+    updated_output_amount[24..].copy_from_slice(&updated_deposit_data.updated_output_amount.to_be_bytes());
+
+    let update = UpdateDepositDetails {
+        deposit_id: relay_data.deposit_id,
+        origin_chain_id: relay_data.origin_chain_id,
+        updated_output_amount,
+        updated_recipient: updated_deposit_data.updated_recipient,
+        updated_message: &updated_deposit_data.updated_message,
+    };
+
+    // Deposits from EVM chains are sped up with EIP-712 signatures from the 20-byte depositor address.
+    if is_evm_address(&relay_data.depositor) {
+        return verify_evm_signature(
+            &relay_data.depositor,
+            &updated_deposit_data.depositor_signature,
+            &get_evm_update_deposit_details_hash(&update),
+        );
+    }
+
+    let update_hash = get_update_deposit_details_hash(&update);
+    verify_ed25519_signature(
+        instructions_sysvar,
+        &relay_data.depositor,
+        &updated_deposit_data.depositor_signature,
+        &update_hash,
+    )
+}
+
 // Helper to unwrap optional instruction params with fallback loading from buffer account.
-fn unwrap_fill_relay_params(
+fn unwrap_fill_relay_with_updated_deposit_params(
     relay_data: Option<RelayData>,
     repayment_chain_id: Option<u64>,
     repayment_address: Option<Pubkey>,
-    account: &Option<Account<FillRelayParams>>,
-) -> FillRelayParams {
-    match (relay_data, repayment_chain_id, repayment_address) {
-        (Some(relay_data), Some(repayment_chain_id), Some(repayment_address)) => {
-            FillRelayParams { relay_data, repayment_chain_id, repayment_address }
+    updated_deposit_data: Option<UpdatedDepositData>,
+    account: &Option<Account<FillRelayWithUpdatedDepositParams>>,
+) -> FillRelayWithUpdatedDepositParams {
+    match (relay_data, repayment_chain_id, repayment_address, updated_deposit_data) {
+        (Some(relay_data), Some(repayment_chain_id), Some(repayment_address), Some(updated_deposit_data)) => {
+            FillRelayWithUpdatedDepositParams {
+                relay_data,
+                repayment_chain_id,
+                repayment_address,
+                updated_deposit_data,
+            }
         }
         _ => account
             .as_ref()
-            .map(|account| FillRelayParams {
+            .map(|account| FillRelayWithUpdatedDepositParams {
                 relay_data: account.relay_data.clone(),
                 repayment_chain_id: account.repayment_chain_id,
                 repayment_address: account.repayment_address,
+                updated_deposit_data: account.updated_deposit_data.clone(),
             })
             .unwrap(), // We do not expect this to panic here as missing instruction_params is unwrapped in context.
     }
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FillRelaysBatch<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // This is required as fallback when None instruction params are passed in arguments.
+    #[account(mut, seeds = [b"instruction_params", signer.key().as_ref()], bump, close = signer)]
+    pub instruction_params: Option<Account<'info, FillRelaysBatchParams>>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["delegate", seed_hash]; used as a CPI signer.
+    pub delegate: UncheckedAccount<'info>,
+
+    // Output token of all batched relays is checked against this mint in the handler.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+        token::token_program = token_program
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fill_relays_batch<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, FillRelaysBatch<'info>>,
+    relay_hashes: Option<Vec<[u8; 32]>>,
+    relay_data: Option<Vec<RelayData>>,
+    repayment_chain_id: Option<u64>,
+    repayment_address: Option<Pubkey>,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    require!(!ctx.accounts.state.paused_fills, CommonError::FillsArePaused);
+
+    let FillRelaysBatchParams { relay_hashes, relay_data, repayment_chain_id, repayment_address } =
+        unwrap_fill_relays_batch_params(
+            relay_hashes,
+            relay_data,
+            repayment_chain_id,
+            repayment_address,
+            &ctx.accounts.instruction_params,
+        )?;
+
+    if relay_hashes.is_empty() || relay_hashes.len() != relay_data.len() {
+        return err!(SvmError::InvalidBatchFill);
+    }
+
+    // Each relay requires its recipient's ATA followed by its fill status PDA in the remaining accounts, and also its
+    // fill status bucket for relays past the compressed fill status cutover.
+    if ctx.remaining_accounts.len() != get_batch_fill_account_count(&ctx.accounts.state, relay_data.iter()) {
+        return err!(SvmError::InvalidBatchFillAccounts);
+    }
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+
+    // Relayer must have delegated the sum of all output amounts to the delegate PDA bound to the whole batch.
+    let seed_hash =
+        derive_seed_hash(&(FillBatchSeedData { relay_hashes: &relay_hashes, repayment_chain_id, repayment_address }));
+
+    let mut recipient_account_index = 0;
+    for (relay_hash, relay_data) in relay_hashes.iter().zip(relay_data.iter()) {
+        if !is_relay_hash_valid(relay_hash, relay_data, &ctx.accounts.state) {
+            return err!(SvmError::InvalidRelayHash);
+        }
+        if relay_data.output_token != ctx.accounts.mint.key() {
+            return err!(SvmError::InvalidMint);
+        }
+        // Remaining accounts are used for the batch, so message handlers cannot be invoked.
+        if !relay_data.message.is_empty() {
+            return err!(SvmError::BatchFillMessageNotSupported);
+        }
+
+        validate_fill_deadlines(relay_data, &ctx.accounts.signer.key(), current_time)?;
+
+        let recipient_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&ctx.remaining_accounts[recipient_account_index])
+                .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", recipient_account_index)))?;
+        if !is_valid_associated_token_account(
+            &recipient_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &relay_data.recipient,
+        ) {
+            return Err(Error::from(ErrorCode::AccountNotAssociatedTokenAccount)
+                .with_account_name(format!("remaining_accounts[{}]", recipient_account_index)));
+        }
+
+        let fill_status_index = recipient_account_index + 1;
+        let (fill_status_bucket, next_account_index) =
+            match ctx.accounts.state.uses_compressed_fill_status(relay_data.fill_deadline) {
+                true => (Some(&ctx.remaining_accounts[fill_status_index + 1]), fill_status_index + 2),
+                false => (None, fill_status_index + 1),
+            };
+        recipient_account_index = next_account_index;
+        let mut fill_status = RelayFillStatus::load(
+            &ctx.accounts.state,
+            relay_hash,
+            relay_data,
+            &ctx.remaining_accounts[fill_status_index],
+            fill_status_bucket,
+        )
+        .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", fill_status_index)))?;
+        let fill_type = get_fill_type(&fill_status.status)?;
+
+        TokenLimits::update(&ctx.accounts.token_limits, |limits| {
+            limits.record_fill(current_time, relay_data.output_amount)
+        })?;
+
+        transfer_from(
+            &ctx.accounts.relayer_token_account,
+            &recipient_token_account,
+            relay_data.output_amount,
+            &ctx.accounts.delegate,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            seed_hash,
+        )?;
+
+        fill_status
+            .set(
+                FillStatus::Filled,
+                Some(ctx.accounts.signer.key()),
+                &ctx.accounts.signer.to_account_info(),
+                &ctx.accounts.system_program,
+            )
+            .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", fill_status_index)))?;
+
+        // Messages are empty, so zeroed bytes32 is emitted for the message hash.
+        let message_hash = hash_non_empty_message(&relay_data.message);
+
+        emit_cpi!(FilledRelay {
+            input_token: relay_data.input_token,
+            output_token: relay_data.output_token,
+            input_amount: relay_data.input_amount,
+            output_amount: relay_data.output_amount,
+            repayment_chain_id,
+            origin_chain_id: relay_data.origin_chain_id,
+            deposit_id: relay_data.deposit_id,
+            fill_deadline: relay_data.fill_deadline,
+            exclusivity_deadline: relay_data.exclusivity_deadline,
+            exclusive_relayer: relay_data.exclusive_relayer,
+            relayer: repayment_address,
+            depositor: relay_data.depositor,
+            recipient: relay_data.recipient,
+            message_hash,
+            relay_execution_info: RelayExecutionEventInfo {
+                updated_recipient: relay_data.recipient,
+                updated_message_hash: message_hash,
+                updated_output_amount: relay_data.output_amount,
+                fill_type,
+            },
+        });
+    }
+
+    Ok(())
+}
+
+// Batched relays past the compressed fill status cutover also require their fill status bucket in remaining accounts.
+pub(crate) fn get_batch_fill_account_count<'a>(
+    state: &State,
+    relay_data: impl Iterator<Item = &'a RelayData>,
+) -> usize {
+    relay_data
+        .map(|relay_data| 2 + state.uses_compressed_fill_status(relay_data.fill_deadline) as usize)
+        .sum()
+}
+
+// Helper to unwrap optional instruction params with fallback loading from buffer account.
+fn unwrap_fill_relays_batch_params(
+    relay_hashes: Option<Vec<[u8; 32]>>,
+    relay_data: Option<Vec<RelayData>>,
+    repayment_chain_id: Option<u64>,
+    repayment_address: Option<Pubkey>,
+    account: &Option<Account<FillRelaysBatchParams>>,
+) -> Result<FillRelaysBatchParams> {
+    match (relay_hashes, relay_data, repayment_chain_id, repayment_address) {
+        (Some(relay_hashes), Some(relay_data), Some(repayment_chain_id), Some(repayment_address)) => {
+            Ok(FillRelaysBatchParams { relay_hashes, relay_data, repayment_chain_id, repayment_address })
+        }
+        // Unlike single fills, the context does not unwrap instruction_params, so it can be missing here.
+        _ => account
+            .as_ref()
+            .map(|account| FillRelaysBatchParams {
+                relay_hashes: account.relay_hashes.clone(),
+                relay_data: account.relay_data.clone(),
+                repayment_chain_id: account.repayment_chain_id,
+                repayment_address: account.repayment_address,
+            })
+            .ok_or(SvmError::InvalidInstructionParams.into()),
+    }
+}
+
 #[derive(Accounts)]
 pub struct CloseFillPda<'info> {
     #[account(mut, address = fill_status.relayer @ SvmError::NotRelayer)]
@@ -215,3 +1115,59 @@ pub fn close_fill_pda(ctx: Context<CloseFillPda>) -> Result<()> {
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct CloseFillPdas<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+}
+
+pub fn close_fill_pdas<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, CloseFillPdas<'info>>) -> Result<()>
+where
+    'c: 'info,
+{
+    // Each fill status PDA must be followed by its recorded relayer account in the remaining accounts.
+    let account_pairs = ctx.remaining_accounts.chunks_exact(2);
+    if ctx.remaining_accounts.is_empty() || !account_pairs.remainder().is_empty() {
+        return err!(SvmError::InvalidCloseFillPdasAccounts);
+    }
+
+    let current_time = get_current_time(&ctx.accounts.state)?;
+
+    for (i, accounts) in account_pairs.enumerate() {
+        let fill_status_index = i * 2;
+        let relayer_index = fill_status_index + 1;
+
+        // No need to check seed derivation as only the fill deadline and relayer recorded in this account are evaluated.
+        let fill_status_account: Account<'info, FillStatusAccount> = Account::try_from(&accounts[0])
+            .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", fill_status_index)))?;
+        if !accounts[0].is_writable {
+            return Err(Error::from(ErrorCode::ConstraintMut)
+                .with_account_name(format!("remaining_accounts[{}]", fill_status_index)));
+        }
+
+        // Rent is always returned to the relayer recorded in the fill status PDA.
+        if accounts[1].key() != fill_status_account.relayer {
+            return Err(
+                Error::from(SvmError::NotRelayer).with_account_name(format!("remaining_accounts[{}]", relayer_index))
+            );
+        }
+        if !accounts[1].is_writable {
+            return Err(Error::from(ErrorCode::ConstraintMut)
+                .with_account_name(format!("remaining_accounts[{}]", relayer_index)));
+        }
+
+        if current_time <= fill_status_account.fill_deadline {
+            return Err(Error::from(SvmError::CanOnlyCloseFillStatusPdaIfFillDeadlinePassed)
+                .with_account_name(format!("remaining_accounts[{}]", fill_status_index)));
+        }
+
+        fill_status_account
+            .close(accounts[1].to_account_info())
+            .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", fill_status_index)))?;
+    }
+
+    Ok(())
+}
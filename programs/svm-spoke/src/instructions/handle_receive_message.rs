@@ -6,11 +6,13 @@ use anchor_lang::{
 use crate::{
     constants::MESSAGE_TRANSMITTER_PROGRAM_ID,
     error::{CallDataError, SvmError},
+    event::{AppliedRemoteMessage, IgnoredRemoteMessage},
     program::SvmSpoke,
-    state::State,
+    state::{RemoteAdmin, RemoteNonce, State},
     utils::{self, EncodeInstructionData},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: HandleReceiveMessageParams)]
 pub struct HandleReceiveMessage<'info> {
@@ -21,18 +23,22 @@ pub struct HandleReceiveMessage<'info> {
         seeds::program = MESSAGE_TRANSMITTER_PROGRAM_ID
     )]
     pub authority_pda: Signer<'info>,
-    #[account(
-        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
-        bump,
-        constraint = params.remote_domain == state.remote_domain @ SvmError::InvalidRemoteDomain,
-        constraint = params.sender == state.cross_domain_admin @ SvmError::InvalidRemoteSender,
-    )]
+    // Remote domain and sender are authenticated in the handler as they can also match additional remote admins.
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
     pub state: Account<'info, State>,
 
-    /// CHECK: empty PDA, used in authenticating self-CPI invoked by the received message.
+    /// CHECK: remote_nonce PDA of the remote domain, checked in the handler after the remote domain is authenticated
+    /// and only used for sequenced messages.
+    #[account(mut)]
+    pub remote_nonce: UncheckedAccount<'info>,
+
+    /// CHECK: empty PDA, used in authenticating self-CPI invoked by the message from the cross-domain admin.
     #[account(seeds = [b"self_authority"], bump)]
     pub self_authority: UncheckedAccount<'info>,
-    pub program: Program<'info, SvmSpoke>,
+
+    /// CHECK: empty PDA, used in authenticating self-CPI invoked by the message from additional remote admins.
+    #[account(seeds = [b"remote_admin_authority"], bump)]
+    pub remote_admin_authority: UncheckedAccount<'info>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -43,13 +49,77 @@ pub struct HandleReceiveMessageParams {
     pub authority_bump: u8,
 }
 
-pub fn handle_receive_message<'info>(
-    ctx: Context<'_, '_, '_, 'info, HandleReceiveMessage<'info>>,
+pub fn handle_receive_message<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, HandleReceiveMessage<'info>>,
     params: HandleReceiveMessageParams,
-) -> Result<()> {
-    let self_ix_data = translate_message(&params.message_body)?;
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let (nonce, message_body) = unwrap_sequenced_message(&params.message_body)?;
+    let selector = utils::get_solidity_selector(&message_body)?;
+    let state = &ctx.accounts.state;
+
+    // Messages from the cross domain admin on the primary remote domain can call any supported selector. Additional
+    // remote admins must pass their remote_admin PDA as the first remaining account, followed by accounts for the
+    // self-invoked CPI, and are restricted to their permitted selectors.
+    let is_cross_domain_admin =
+        params.remote_domain == state.remote_domain && params.sender == state.cross_domain_admin;
+    let self_ix_accounts = if is_cross_domain_admin {
+        ctx.remaining_accounts
+    } else {
+        let remote_admin = ctx.remaining_accounts.first().and_then(|account_info| {
+            RemoteAdmin::try_from(account_info, state.seed, params.remote_domain, &params.sender)
+        });
+        let remote_admin = match remote_admin {
+            Some(remote_admin) => remote_admin,
+            None if params.remote_domain != state.remote_domain => return err!(SvmError::InvalidRemoteDomain),
+            None => return err!(SvmError::InvalidRemoteSender),
+        };
+
+        if !remote_admin.selectors.contains(&selector) {
+            return err!(SvmError::RemoteSelectorNotPermitted);
+        }
+
+        &ctx.remaining_accounts[1..]
+    };
+
+    // Sequenced messages from the same remote domain are applied only if their nonce is greater than the last applied
+    // one, whichever remote admin sent them. Stale messages are ignored without failing, so that CCTP still marks them
+    // as received and they cannot be replayed after a more recent message.
+    if let Some(nonce) = nonce {
+        if !RemoteNonce::apply_nonce(&ctx.accounts.remote_nonce, state.seed, params.remote_domain, nonce)? {
+            emit_cpi!(IgnoredRemoteMessage {
+                remote_domain: params.remote_domain,
+                sender: params.sender,
+                selector,
+                nonce
+            });
+
+            return Ok(());
+        }
+    }
+
+    let self_ix_data = translate_message(&message_body)?;
+
+    invoke_self(&ctx, is_cross_domain_admin, self_ix_accounts, &self_ix_data)?;
+
+    emit_cpi!(AppliedRemoteMessage { remote_domain: params.remote_domain, sender: params.sender, selector, nonce });
 
-    invoke_self(&ctx, &self_ix_data)
+    Ok(())
+}
+
+// Sequenced messages wrap an admin call together with a nonce that must be strictly increasing for each remote domain,
+// so that a delayed message cannot be applied after a more recent one. Other messages are returned unchanged.
+fn unwrap_sequenced_message(data: &Vec<u8>) -> Result<(Option<u64>, Vec<u8>)> {
+    if utils::get_solidity_selector(data)? != utils::encode_solidity_selector("relaySequencedMessage(uint64,bytes)") {
+        return Ok((None, data.to_owned()));
+    }
+
+    let nonce = utils::decode_solidity_uint64(&utils::get_solidity_arg(data, 0)?)?;
+    let message_body = utils::decode_solidity_bytes(data, 1)?;
+
+    Ok((Some(nonce), message_body))
 }
 
 fn translate_message(data: &Vec<u8>) -> Result<Vec<u8>> {
@@ -64,6 +134,21 @@ fn translate_message(data: &Vec<u8>) -> Result<Vec<u8>> {
 
             pause.encode_instruction_data("global:pause_fills")
         }
+        s if s == utils::encode_solidity_selector("pauseRefunds(bool)") => {
+            let pause = utils::decode_solidity_bool(&utils::get_solidity_arg(data, 0)?)?;
+
+            pause.encode_instruction_data("global:pause_refunds")
+        }
+        s if s == utils::encode_solidity_selector("pauseClaims(bool)") => {
+            let pause = utils::decode_solidity_bool(&utils::get_solidity_arg(data, 0)?)?;
+
+            pause.encode_instruction_data("global:pause_claims")
+        }
+        s if s == utils::encode_solidity_selector("pauseBridge(bool)") => {
+            let pause = utils::decode_solidity_bool(&utils::get_solidity_arg(data, 0)?)?;
+
+            pause.encode_instruction_data("global:pause_bridge")
+        }
         s if s == utils::encode_solidity_selector("setCrossDomainAdmin(address)") => {
             let new_cross_domain_admin = utils::decode_solidity_address(&utils::get_solidity_arg(data, 0)?)?;
 
@@ -73,28 +158,128 @@ fn translate_message(data: &Vec<u8>) -> Result<Vec<u8>> {
             let relayer_refund_root = utils::get_solidity_arg(data, 0)?;
             let slow_relay_root = utils::get_solidity_arg(data, 1)?;
 
-            (relayer_refund_root, slow_relay_root).encode_instruction_data("global:relay_root_bundle")
+            // HubPool does not relay the leaf count, so root bundles relayed remotely can only be closed once superseded.
+            (relayer_refund_root, slow_relay_root, None::<u32>).encode_instruction_data("global:relay_root_bundle")
+        }
+        s if s == utils::encode_solidity_selector("relayTypedRoot(uint32,uint8,bytes32)") => {
+            let root_bundle_id = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+            let root_type = utils::decode_solidity_uint8(&utils::get_solidity_arg(data, 1)?)?;
+            let root = utils::get_solidity_arg(data, 2)?;
+
+            (root_bundle_id, root_type, root).encode_instruction_data("global:relay_typed_root")
         }
         s if s == utils::encode_solidity_selector("emergencyDeleteRootBundle(uint256)") => {
             let root_id = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
 
             root_id.encode_instruction_data("global:emergency_delete_root_bundle")
         }
+        s if s == utils::encode_solidity_selector("vetoRootBundle(uint256)") => {
+            let root_id = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+
+            root_id.encode_instruction_data("global:veto_root_bundle")
+        }
+        s if s == utils::encode_solidity_selector("transferOwnership(bytes32)") => {
+            let new_owner = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 0)?)?;
+
+            new_owner.encode_instruction_data("global:transfer_ownership")
+        }
+        s if s == utils::encode_solidity_selector("cancelOwnershipTransfer()") => {
+            ().encode_instruction_data("global:cancel_ownership_transfer")
+        }
+        s if s == utils::encode_solidity_selector("setEnableRoute(bytes32,uint64,bool)") => {
+            let origin_token = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 0)?)?;
+            let destination_chain_id = utils::decode_solidity_uint64(&utils::get_solidity_arg(data, 1)?)?;
+            let enabled = utils::decode_solidity_bool(&utils::get_solidity_arg(data, 2)?)?;
+
+            (origin_token, destination_chain_id, enabled).encode_instruction_data("global:set_enable_route")
+        }
+        s if s == utils::encode_solidity_selector("setTokenLimits(bytes32,uint64,uint64,uint64,uint32)") => {
+            let mint = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 0)?)?;
+            let max_deposit_amount = utils::decode_solidity_uint64(&utils::get_solidity_arg(data, 1)?)?;
+            let max_deposit_volume = utils::decode_solidity_uint64(&utils::get_solidity_arg(data, 2)?)?;
+            let max_outflow_volume = utils::decode_solidity_uint64(&utils::get_solidity_arg(data, 3)?)?;
+            let window_length = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 4)?)?;
+
+            (mint, max_deposit_amount, max_deposit_volume, max_outflow_volume, window_length)
+                .encode_instruction_data("global:set_token_limits")
+        }
+        s if s == utils::encode_solidity_selector("setDepositQuoteTimeBuffer(uint32)") => {
+            let deposit_quote_time_buffer = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+
+            deposit_quote_time_buffer.encode_instruction_data("global:set_deposit_quote_time_buffer")
+        }
+        s if s == utils::encode_solidity_selector("setFillDeadlineBuffer(uint32)") => {
+            let fill_deadline_buffer = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+
+            fill_deadline_buffer.encode_instruction_data("global:set_fill_deadline_buffer")
+        }
+        s if s == utils::encode_solidity_selector("setRootBundleCloseGracePeriod(uint32)") => {
+            let root_bundle_close_grace_period = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+
+            root_bundle_close_grace_period.encode_instruction_data("global:set_root_bundle_close_grace_period")
+        }
+        s if s == utils::encode_solidity_selector("setCompressedFillStatusCutover(uint32)") => {
+            let compressed_fill_status_cutover = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+
+            compressed_fill_status_cutover.encode_instruction_data("global:set_compressed_fill_status_cutover")
+        }
+        s if s == utils::encode_solidity_selector("setRootBundleTimelock(uint32)") => {
+            let root_bundle_timelock = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+
+            root_bundle_timelock.encode_instruction_data("global:set_root_bundle_timelock")
+        }
+        s if s == utils::encode_solidity_selector("setRemoteAdmin(uint32,bytes32,bytes4[])") => {
+            let remote_domain = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+            let sender = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 1)?)?;
+            let selectors = utils::decode_solidity_array(data, 2, utils::decode_solidity_bytes4)?;
+
+            (remote_domain, sender, selectors).encode_instruction_data("global:set_remote_admin")
+        }
+        s if s == utils::encode_solidity_selector("removeRemoteAdmin(uint32,bytes32)") => {
+            let remote_domain = utils::decode_solidity_uint32(&utils::get_solidity_arg(data, 0)?)?;
+            let sender = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 1)?)?;
+
+            (remote_domain, sender).encode_instruction_data("global:remove_remote_admin")
+        }
+        s if s == utils::encode_solidity_selector("grantRole(uint8,bytes32)") => {
+            let role = utils::decode_solidity_uint8(&utils::get_solidity_arg(data, 0)?)?;
+            let member = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 1)?)?;
+
+            (role, member).encode_instruction_data("global:grant_role")
+        }
+        s if s == utils::encode_solidity_selector("revokeRole(uint8,bytes32)") => {
+            let role = utils::decode_solidity_uint8(&utils::get_solidity_arg(data, 0)?)?;
+            let member = utils::decode_solidity_bytes32(&utils::get_solidity_arg(data, 1)?)?;
+
+            (role, member).encode_instruction_data("global:revoke_role")
+        }
         _ => Err(CallDataError::UnsupportedSelector.into()),
     }
 }
 
 // Invokes self CPI for remote domain invoked message calls. We use low level invoke_signed with seeds corresponding to
-// the self_authority account and passing all remaining accounts from the context. Instruction data is obtained within
-// handle_receive_message by translating the received message body into a valid instruction data for the invoked CPI.
-fn invoke_self<'info>(ctx: &Context<'_, '_, '_, 'info, HandleReceiveMessage<'info>>, data: &Vec<u8>) -> Result<()> {
-    let self_authority_seeds: &[&[&[u8]]] = &[&[b"self_authority", &[ctx.bumps.self_authority]]];
+// the self_authority account for messages from the cross-domain admin or the remote_admin_authority account for
+// messages from additional remote admins and passing the provided remaining accounts from the context. Instruction
+// data is obtained within handle_receive_message by translating the received message body into a valid instruction
+// data for the CPI.
+fn invoke_self<'info>(
+    ctx: &Context<'_, '_, '_, 'info, HandleReceiveMessage<'info>>,
+    is_cross_domain_admin: bool,
+    remaining_accounts: &[AccountInfo<'info>],
+    data: &Vec<u8>,
+) -> Result<()> {
+    let (authority, authority_seed, authority_bump) = if is_cross_domain_admin {
+        (&ctx.accounts.self_authority, b"self_authority".as_ref(), ctx.bumps.self_authority)
+    } else {
+        (&ctx.accounts.remote_admin_authority, b"remote_admin_authority".as_ref(), ctx.bumps.remote_admin_authority)
+    };
+    let authority_seeds: &[&[&[u8]]] = &[&[authority_seed, &[authority_bump]]];
 
-    let mut accounts = Vec::with_capacity(1 + ctx.remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(1 + remaining_accounts.len());
 
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.self_authority.key(), true));
+    accounts.push(AccountMeta::new_readonly(authority.key(), true));
 
-    for acc in ctx.remaining_accounts {
+    for acc in remaining_accounts {
         if acc.is_writable {
             accounts.push(AccountMeta::new(acc.key(), acc.is_signer));
         } else {
@@ -106,8 +291,8 @@ fn invoke_self<'info>(ctx: &Context<'_, '_, '_, 'info, HandleReceiveMessage<'inf
 
     program::invoke_signed(
         &instruction,
-        &[&[ctx.accounts.self_authority.to_account_info()], ctx.remaining_accounts].concat(),
-        self_authority_seeds,
+        &[&[authority.to_account_info()], remaining_accounts].concat(),
+        authority_seeds,
     )?;
 
     Ok(())
@@ -0,0 +1,148 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::{DEFAULT_ROOT_BUNDLE_CLOSE_GRACE_PERIOD, DISCRIMINATOR_SIZE},
+    error::SvmError,
+    state::{RemoteNonce, RootBundle, RootBundleV0, State, StateV0},
+    utils::get_current_time,
+};
+
+#[derive(Accounts)]
+#[instruction(seed: u64, remote_domain: u32)]
+pub struct MigrateState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: State PDA in the StateV0 layout that cannot be loaded as State until it is migrated.
+    #[account(mut, seeds = [b"state", seed.to_le_bytes().as_ref()], bump)]
+    pub state: UncheckedAccount<'info>,
+
+    // Sequenced admin messages were not supported before the upgrade, so their nonces start being tracked here.
+    #[account(
+        init_if_needed, // Repeated migration is rejected in the handler as AccountAlreadyMigrated.
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + RemoteNonce::INIT_SPACE,
+        seeds = [b"remote_nonce", seed.to_le_bytes().as_ref(), remote_domain.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub remote_nonce: Account<'info, RemoteNonce>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_state(ctx: Context<MigrateState>, _seed: u64, remote_domain: u32) -> Result<()> {
+    let state_info = ctx.accounts.state.to_account_info();
+    let legacy_state = StateV0::try_from_account_info(&state_info)?;
+    if remote_domain != legacy_state.remote_domain {
+        return err!(SvmError::InvalidRemoteDomain);
+    }
+
+    // Appended fields are backfilled with the same values as on initialization.
+    let state = State {
+        paused_deposits: legacy_state.paused_deposits,
+        paused_fills: legacy_state.paused_fills,
+        owner: legacy_state.owner,
+        seed: legacy_state.seed,
+        number_of_deposits: legacy_state.number_of_deposits,
+        chain_id: legacy_state.chain_id,
+        current_time: legacy_state.current_time,
+        remote_domain: legacy_state.remote_domain,
+        cross_domain_admin: legacy_state.cross_domain_admin,
+        root_bundle_id: legacy_state.root_bundle_id,
+        deposit_quote_time_buffer: legacy_state.deposit_quote_time_buffer,
+        fill_deadline_buffer: legacy_state.fill_deadline_buffer,
+        root_bundle_close_grace_period: DEFAULT_ROOT_BUNDLE_CLOSE_GRACE_PERIOD,
+        // Fill status PDAs created before the upgrade remain authoritative until the owner sets the cutover.
+        compressed_fill_status_cutover: 0,
+        // No ownership transfer could have been started, so the legacy owner is kept without a pending one.
+        pending_owner: Pubkey::default(),
+        // Root bundles relayed before the upgrade were never timelocked, so the timelock starts disabled.
+        root_bundle_timelock: 0,
+        // Legacy deposit and fill pauses are kept as is, while the guardian pauses added since start unpaused.
+        paused_refunds: false,
+        paused_claims: false,
+        paused_bridge: false,
+    };
+
+    ctx.accounts.remote_nonce.remote_domain = remote_domain;
+
+    let space = DISCRIMINATOR_SIZE + State::INIT_SPACE;
+    write_migrated_account(&state_info, &state, space, &ctx.accounts.payer, &ctx.accounts.system_program)
+}
+
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32)]
+pub struct MigrateRootBundle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: Root bundle PDA in the RootBundleV0 layout that cannot be loaded as RootBundle until it is migrated.
+    #[account(
+        mut,
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub root_bundle: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_root_bundle(ctx: Context<MigrateRootBundle>, _root_bundle_id: u32) -> Result<()> {
+    let root_bundle_info = ctx.accounts.root_bundle.to_account_info();
+    let legacy_root_bundle = RootBundleV0::try_from_account_info(&root_bundle_info)?;
+
+    // The leaf count was not recorded for legacy root bundles, so they are treated as remotely relayed ones that can
+    // only be closed once superseded. Migration is permissionless, so the rent is returned to the owner on close rather
+    // than to whoever funds the migration.
+    let claimed_leaf_count = legacy_root_bundle
+        .claimed_bitmap
+        .iter()
+        .map(|byte| byte.count_ones())
+        .sum();
+    let space = std::cmp::max(
+        DISCRIMINATOR_SIZE + RootBundle::INIT_SPACE + legacy_root_bundle.claimed_bitmap.len().saturating_sub(1),
+        root_bundle_info.data_len(),
+    );
+    let root_bundle = RootBundle {
+        relayer_refund_root: legacy_root_bundle.relayer_refund_root,
+        slow_relay_root: legacy_root_bundle.slow_relay_root,
+        claimed_bitmap: legacy_root_bundle.claimed_bitmap,
+        payer: ctx.accounts.state.owner,
+        created_at: get_current_time(&ctx.accounts.state)?,
+        leaf_count: None,
+        claimed_leaf_count,
+        typed_roots: Vec::new(),
+        // Legacy root bundles were active as soon as they were relayed, so they are never made pending.
+        pending_until: 0,
+    };
+
+    write_migrated_account(&root_bundle_info, &root_bundle, space, &ctx.accounts.payer, &ctx.accounts.system_program)
+}
+
+// Grows the account with the payer funding the additional rent and overwrites it in the current layout.
+fn write_migrated_account<'info, T: AccountSerialize>(
+    account_info: &AccountInfo<'info>,
+    account: &T,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let required_lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        let transfer_accounts = Transfer { from: payer.to_account_info(), to: account_info.clone() };
+        let cpi_context = CpiContext::new(system_program.to_account_info(), transfer_accounts);
+        transfer(cpi_context, required_lamports)?;
+    }
+    account_info.realloc(space, true)?;
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
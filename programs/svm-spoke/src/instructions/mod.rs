@@ -1,21 +1,37 @@
 mod admin;
 mod bundle;
+mod compressed_fill;
 mod create_token_accounts;
 mod deposit;
 mod fill;
 mod handle_receive_message;
 mod instruction_params;
+mod migration;
+mod native_recipient;
+mod origin_refund;
+mod payout;
 mod refund_claims;
+mod relayer_inventory;
+mod relayer_profile;
 mod slow_fill;
 mod token_bridge;
+mod token_limits;
 
 pub use admin::*;
 pub use bundle::*;
+pub use compressed_fill::*;
 pub use create_token_accounts::*;
 pub use deposit::*;
 pub use fill::*;
 pub use handle_receive_message::*;
 pub use instruction_params::*;
+pub use migration::*;
+pub use native_recipient::*;
+pub use origin_refund::*;
+pub use payout::*;
 pub use refund_claims::*;
+pub use relayer_inventory::*;
+pub use relayer_profile::*;
 pub use slow_fill::*;
 pub use token_bridge::*;
+pub use token_limits::*;
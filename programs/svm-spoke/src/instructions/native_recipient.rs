@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::DISCRIMINATOR_SIZE, event::SetNativeRecipient, state::NativeRecipient};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetNativeRecipientState<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = DISCRIMINATOR_SIZE + NativeRecipient::INIT_SPACE,
+        seeds = [b"native_recipient", signer.key().as_ref()],
+        bump
+    )]
+    pub native_recipient: Account<'info, NativeRecipient>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_native_recipient(ctx: Context<SetNativeRecipientState>, enabled: bool) -> Result<()> {
+    ctx.accounts.native_recipient.enabled = enabled;
+
+    emit_cpi!(SetNativeRecipient { recipient: ctx.accounts.signer.key(), enabled });
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, ORIGIN_REFUND_LEAF_DOMAIN, ORIGIN_REFUND_ROOT_TYPE},
+    error::{CommonError, SvmError},
+    event::ExecutedOriginRefund,
+    state::{OriginRefundClaim, RootBundle, State, TokenLimits},
+    utils::{get_current_time, validate_mint_extensions, verify_merkle_proof},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OriginRefundLeaf {
+    pub chain_id: u64,
+    pub deposit_id: [u8; 32],
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fill_deadline: u32,
+}
+
+impl OriginRefundLeaf {
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        // Same as for relayer refund leaves, the first 64 bytes are 0 to block EVM leaves from being used on SVM. The
+        // following domain separator distinguishes this leaf from RelayerRefundLeaf and any other SVM leaf type.
+        bytes.extend_from_slice(&[0u8; 64]);
+        bytes.extend_from_slice(ORIGIN_REFUND_LEAF_DOMAIN);
+
+        AnchorSerialize::serialize(&self, &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    pub fn to_keccak_hash(&self) -> Result<[u8; 32]> {
+        let input = self.to_bytes()?;
+
+        Ok(keccak::hash(&input).to_bytes())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32, origin_refund_leaf: OriginRefundLeaf)]
+pub struct ExecuteOriginRefundLeaf<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+
+    #[account(
+        init, // Init prevents refunding the same deposit more than once, even across root bundles.
+        payer = signer,
+        space = DISCRIMINATOR_SIZE + OriginRefundClaim::INIT_SPACE,
+        seeds = [b"origin_refund", state.seed.to_le_bytes().as_ref(), origin_refund_leaf.deposit_id.as_ref()],
+        bump
+    )]
+    pub origin_refund_claim: Account<'info, OriginRefundClaim>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = state, // Ensure owner is the state.
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = origin_refund_leaf.mint @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = origin_refund_leaf.depositor, // Refunds always go to the depositor's ATA.
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_origin_refund_leaf(
+    ctx: Context<ExecuteOriginRefundLeaf>,
+    root_bundle_id: u32,
+    origin_refund_leaf: OriginRefundLeaf,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let root = ctx
+        .accounts
+        .root_bundle
+        .get_typed_root(ORIGIN_REFUND_ROOT_TYPE)
+        .ok_or(SvmError::TypedRootNotSet)?;
+    let leaf = origin_refund_leaf.to_keccak_hash()?;
+    verify_merkle_proof(root, leaf, proof)?;
+
+    let state = &ctx.accounts.state;
+    if origin_refund_leaf.chain_id != state.chain_id {
+        return err!(CommonError::InvalidChainId);
+    }
+
+    // Deposits can only be refunded on origin once they can no longer be filled on destination.
+    let current_time = get_current_time(state)?;
+    if origin_refund_leaf.fill_deadline >= current_time {
+        return err!(SvmError::OriginRefundBeforeFillDeadline);
+    }
+
+    let amount = origin_refund_leaf.amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_outflow(current_time, amount))?;
+
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
+    // Derive the signer seeds for the state required for the transfer from vault.
+    let state_seed_bytes = state.seed.to_le_bytes();
+    let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.depositor_token_account.to_account_info(),
+        authority: ctx.accounts.state.to_account_info(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    ctx.accounts.origin_refund_claim.root_bundle_id = root_bundle_id;
+
+    emit_cpi!(ExecutedOriginRefund {
+        root_bundle_id,
+        deposit_id: origin_refund_leaf.deposit_id,
+        depositor: origin_refund_leaf.depositor,
+        mint: origin_refund_leaf.mint,
+        amount,
+        caller: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
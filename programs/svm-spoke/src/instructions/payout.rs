@@ -0,0 +1,178 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, ORIGIN_REFUND_ROOT_TYPE, PAYOUT_LEAF_DOMAIN},
+    error::{CommonError, SvmError},
+    event::ExecutedPayout,
+    state::{RootBundle, State, TokenLimits, TypedRootClaims},
+    utils::{get_current_time, is_claimed, set_claimed, validate_mint_extensions, verify_merkle_proof},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PayoutLeaf {
+    pub chain_id: u64,
+    pub leaf_id: u32,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl PayoutLeaf {
+    pub fn to_bytes(&self, root_type: u8) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        // Same as for relayer refund leaves, the first 64 bytes are 0 to block EVM leaves from being used on SVM. The
+        // following domain separator and root type distinguish payout leaves of each category from one another and
+        // from any other SVM leaf type.
+        bytes.extend_from_slice(&[0u8; 64]);
+        bytes.extend_from_slice(PAYOUT_LEAF_DOMAIN);
+        bytes.push(root_type);
+
+        AnchorSerialize::serialize(&self, &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    pub fn to_keccak_hash(&self, root_type: u8) -> Result<[u8; 32]> {
+        let input = self.to_bytes(root_type)?;
+
+        Ok(keccak::hash(&input).to_bytes())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(root_bundle_id: u32, root_type: u8, payout_leaf: PayoutLeaf)]
+pub struct ExecutePayoutLeaf<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [b"root_bundle", state.seed.to_le_bytes().as_ref(), root_bundle_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"typed_root_claims",
+            state.seed.to_le_bytes().as_ref(),
+            root_bundle_id.to_le_bytes().as_ref(),
+            &[root_type]
+        ],
+        bump,
+        // Realloc to let the size of the claimed bitmap grow as leafs are executed.
+        realloc = std::cmp::max(
+            DISCRIMINATOR_SIZE + TypedRootClaims::INIT_SPACE + payout_leaf.leaf_id as usize / 8,
+            typed_root_claims.to_account_info().data_len()
+        ),
+        realloc::payer = signer,
+        realloc::zero = false
+    )]
+    pub typed_root_claims: Account<'info, TypedRootClaims>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = state, // Ensure owner is the state.
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = payout_leaf.mint @ SvmError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = payout_leaf.recipient, // Payouts always go to the recipient's ATA.
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_payout_leaf(
+    ctx: Context<ExecutePayoutLeaf>,
+    root_bundle_id: u32,
+    root_type: u8,
+    payout_leaf: PayoutLeaf,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Origin refunds use their own leaf encoding and are executed via execute_origin_refund_leaf.
+    if root_type == ORIGIN_REFUND_ROOT_TYPE {
+        return err!(SvmError::InvalidPayoutRootType);
+    }
+
+    let root = ctx
+        .accounts
+        .root_bundle
+        .get_typed_root(root_type)
+        .ok_or(SvmError::TypedRootNotSet)?;
+    let leaf = payout_leaf.to_keccak_hash(root_type)?;
+    verify_merkle_proof(root, leaf, proof)?;
+
+    let state = &ctx.accounts.state;
+    if payout_leaf.chain_id != state.chain_id {
+        return err!(CommonError::InvalidChainId);
+    }
+
+    let claimed_bitmap = &mut ctx.accounts.typed_root_claims.claimed_bitmap;
+    if is_claimed(claimed_bitmap, payout_leaf.leaf_id) {
+        return err!(CommonError::ClaimedMerkleLeaf);
+    }
+    set_claimed(claimed_bitmap, payout_leaf.leaf_id);
+
+    let current_time = get_current_time(state)?;
+    let amount = payout_leaf.amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| limits.record_outflow(current_time, amount))?;
+
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
+    // Derive the signer seeds for the state required for the transfer from vault.
+    let state_seed_bytes = state.seed.to_le_bytes();
+    let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.state.to_account_info(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    emit_cpi!(ExecutedPayout {
+        root_bundle_id,
+        root_type,
+        leaf_id: payout_leaf.leaf_id,
+        mint: payout_leaf.mint,
+        recipient: payout_leaf.recipient,
+        amount,
+        caller: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ use crate::{
     error::SvmError,
     event::ClaimedRelayerRefund,
     state::{ClaimAccount, State},
+    utils::validate_mint_extensions,
 };
 
 #[derive(Accounts)]
@@ -48,7 +49,11 @@ pub struct ClaimRelayerRefund<'info> {
     #[account(mut, address = claim_account.initializer @ SvmError::InvalidClaimInitializer)]
     pub initializer: UncheckedAccount<'info>,
 
-    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_claims @ SvmError::ClaimsArePaused
+    )]
     pub state: Account<'info, State>,
 
     #[account(
@@ -101,6 +106,8 @@ pub fn claim_relayer_refund(ctx: Context<ClaimRelayerRefund>) -> Result<()> {
     let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
     let signer_seeds = &[&seeds[..]];
 
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
     // Transfer the claim amount from the vault to the relayer token account.
     let transfer_accounts = TransferChecked {
         from: ctx.accounts.vault.to_account_info(),
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    event::{FundedRelayerInventory, WithdrewRelayerInventory},
+    utils::validate_mint_extensions,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundRelayerInventory<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: PDA derived with seeds ["relayer_inventory", signer]; used as the inventory token account authority.
+    #[account(seeds = [b"relayer_inventory", signer.key().as_ref()], bump)]
+    pub relayer_inventory: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+        token::token_program = token_program
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = relayer_inventory,
+        associated_token::token_program = token_program
+    )]
+    pub inventory_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_relayer_inventory(ctx: Context<FundRelayerInventory>, amount: u64) -> Result<()> {
+    // Fills debit the inventory by the output amount, so it must receive the exact amount sent.
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.relayer_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.inventory_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_accounts);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    emit_cpi!(FundedRelayerInventory { relayer: ctx.accounts.signer.key(), mint: ctx.accounts.mint.key(), amount });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawRelayerInventory<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: PDA derived with seeds ["relayer_inventory", signer]; used as the inventory token account authority.
+    #[account(seeds = [b"relayer_inventory", signer.key().as_ref()], bump)]
+    pub relayer_inventory: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = relayer_inventory,
+        associated_token::token_program = token_program
+    )]
+    pub inventory_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_relayer_inventory(ctx: Context<WithdrawRelayerInventory>, amount: u64) -> Result<()> {
+    let signer_key = ctx.accounts.signer.key();
+    let seeds = &[
+        b"relayer_inventory",
+        signer_key.as_ref(),
+        &[ctx.bumps.relayer_inventory],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.inventory_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.relayer_token_account.to_account_info(),
+        authority: ctx.accounts.relayer_inventory.to_account_info(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    emit_cpi!(WithdrewRelayerInventory { relayer: signer_key, mint: ctx.accounts.mint.key(), amount });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::DISCRIMINATOR_SIZE, event::SetRelayerProfile, state::RelayerProfile};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRelayerProfileState<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = DISCRIMINATOR_SIZE + RelayerProfile::INIT_SPACE,
+        seeds = [b"relayer_profile", signer.key().as_ref()],
+        bump
+    )]
+    pub relayer_profile: Account<'info, RelayerProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_relayer_profile(
+    ctx: Context<SetRelayerProfileState>,
+    repayment_chain_id: u64,
+    repayment_address: Pubkey,
+    delegate: Pubkey,
+) -> Result<()> {
+    let relayer_profile = &mut ctx.accounts.relayer_profile;
+    relayer_profile.relayer = ctx.accounts.signer.key();
+    relayer_profile.repayment_chain_id = repayment_chain_id;
+    relayer_profile.repayment_address = repayment_address;
+    relayer_profile.delegate = delegate;
+
+    emit_cpi!(SetRelayerProfile {
+        relayer: ctx.accounts.signer.key(),
+        repayment_chain_id,
+        repayment_address,
+        delegate
+    });
+
+    Ok(())
+}
@@ -4,16 +4,22 @@ use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInt
 use crate::event::{FillType, FilledRelay, RelayExecutionEventInfo, RequestedSlowFill};
 use crate::{
     common::RelayData,
-    constants::DISCRIMINATOR_SIZE,
-    constraints::is_relay_hash_valid,
+    constraints::{is_relay_hash_valid, is_valid_associated_token_account},
     error::{CommonError, SvmError},
-    state::{ExecuteSlowRelayLeafParams, FillStatus, FillStatusAccount, RequestSlowFillParams, RootBundle, State},
-    utils::{get_current_time, hash_non_empty_message, invoke_handler, verify_merkle_proof},
+    instructions::get_batch_fill_account_count,
+    state::{
+        ExecuteSlowRelayLeafParams, ExecuteSlowRelayLeavesParams, FillStatus, RelayFillStatus, RequestSlowFillParams,
+        RootBundle, State, TokenLimits,
+    },
+    utils::{
+        get_current_time, get_relay_hash, hash_non_empty_message, invoke_handler, validate_mint_extensions,
+        verify_merkle_multi_proof, verify_merkle_proof,
+    },
 };
 
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(_relay_hash: [u8; 32], relay_data: Option<RelayData>)]
+#[instruction(relay_hash: [u8; 32], relay_data: Option<RelayData>)]
 pub struct RequestSlowFill<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -29,27 +35,36 @@ pub struct RequestSlowFill<'info> {
     )]
     pub state: Account<'info, State>,
 
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and is initialized in the handler when needed.
     #[account(
-        init_if_needed,
-        payer = signer,
-        space = DISCRIMINATOR_SIZE + FillStatusAccount::INIT_SPACE,
-        seeds = [b"fills", _relay_hash.as_ref()],
+        mut,
+        seeds = [b"fills", relay_hash.as_ref()],
         bump,
         constraint = is_relay_hash_valid(
-            &_relay_hash,
+            &relay_hash,
             &relay_data.clone().unwrap_or_else(|| instruction_params.as_ref().unwrap().relay_data.clone()),
             &state) @ SvmError::InvalidRelayHash
     )]
-    pub fill_status: Account<'info, FillStatusAccount>,
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn request_slow_fill(ctx: Context<RequestSlowFill>, relay_data: Option<RelayData>) -> Result<()> {
+pub fn request_slow_fill(
+    ctx: Context<RequestSlowFill>,
+    relay_hash: [u8; 32],
+    relay_data: Option<RelayData>,
+) -> Result<()> {
     let RequestSlowFillParams { relay_data } =
         unwrap_request_slow_fill_params(relay_data, &ctx.accounts.instruction_params);
 
     let state = &ctx.accounts.state;
-
     let current_time = get_current_time(state)?;
 
     // Check if the fill is past the exclusivity window & within the fill deadline.
@@ -61,14 +76,24 @@ pub fn request_slow_fill(ctx: Context<RequestSlowFill>, relay_data: Option<Relay
     }
 
     // Check the fill status is unfilled.
-    let fill_status_account = &mut ctx.accounts.fill_status;
-    if fill_status_account.status != FillStatus::Unfilled {
+    let mut fill_status = RelayFillStatus::load(
+        state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.fill_status,
+        ctx.accounts.fill_status_bucket.as_deref(),
+    )?;
+    if fill_status.status != FillStatus::Unfilled {
         return err!(CommonError::InvalidSlowFillRequest);
     }
 
-    fill_status_account.status = FillStatus::RequestedSlowFill; // Update the fill status to RequestedSlowFill
-    fill_status_account.relayer = ctx.accounts.signer.key();
-    fill_status_account.fill_deadline = relay_data.fill_deadline;
+    // Update the fill status to RequestedSlowFill
+    fill_status.set(
+        FillStatus::RequestedSlowFill,
+        Some(ctx.accounts.signer.key()),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
 
     // Emit the RequestedSlowFill event. Empty message is not hashed and emits zeroed bytes32 for easier observability
     let message_hash = hash_non_empty_message(&relay_data.message);
@@ -133,15 +158,20 @@ impl SlowFill {
 
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(_relay_hash: [u8; 32], slow_fill_leaf: Option<SlowFill>, _root_bundle_id: Option<u32>)]
+#[instruction(relay_hash: [u8; 32], slow_fill_leaf: Option<SlowFill>, _root_bundle_id: Option<u32>)]
 pub struct ExecuteSlowRelayLeaf<'info> {
+    #[account(mut)]
     pub signer: Signer<'info>,
 
     // This is required as fallback when None instruction params are passed in arguments.
     #[account(mut, seeds = [b"instruction_params", signer.key().as_ref()], bump, close = signer)]
     pub instruction_params: Option<Account<'info, ExecuteSlowRelayLeafParams>>,
 
-    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
     pub state: Account<'info, State>,
 
     #[account(
@@ -153,24 +183,32 @@ pub struct ExecuteSlowRelayLeaf<'info> {
                 .to_le_bytes()
                 .as_ref(),
         ],
-        bump
+        bump,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
     )]
     pub root_bundle: Account<'info, RootBundle>,
 
+    /// CHECK: PDA derived with seeds ["fills", relay_hash]; tracks the fill status of relays before the compressed fill
+    /// status cutover and must have been initialized when the slow fill was requested.
     #[account(
         mut,
-        seeds = [b"fills", _relay_hash.as_ref()],
+        seeds = [b"fills", relay_hash.as_ref()],
         bump,
         // Make sure caller provided relay_hash used in PDA seeds is valid.
         constraint = is_relay_hash_valid(
-            &_relay_hash,
+            &relay_hash,
             &slow_fill_leaf
                 .clone()
                 .unwrap_or_else(|| instruction_params.as_ref().unwrap().slow_fill_leaf.clone())
                 .relay_data,
             &state) @ SvmError::InvalidRelayHash
     )]
-    pub fill_status: Account<'info, FillStatusAccount>,
+    pub fill_status: UncheckedAccount<'info>,
+
+    /// CHECK: PDA derived with seeds ["fill_status_bucket", origin_chain_id, bucket_id, shard]; checked and initialized
+    /// in the handler. Required only for relays past the compressed fill status cutover.
+    #[account(mut)]
+    pub fill_status_bucket: Option<UncheckedAccount<'info>>,
 
     #[account(
         mint::token_program = token_program,
@@ -182,6 +220,10 @@ pub struct ExecuteSlowRelayLeaf<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::mint = mint,
@@ -208,6 +250,7 @@ pub struct ExecuteSlowRelayLeaf<'info> {
 
 pub fn execute_slow_relay_leaf<'info>(
     ctx: Context<'_, '_, '_, 'info, ExecuteSlowRelayLeaf<'info>>,
+    relay_hash: [u8; 32],
     slow_fill_leaf: Option<SlowFill>,
     proof: Option<Vec<[u8; 32]>>,
 ) -> Result<()> {
@@ -234,16 +277,29 @@ pub fn execute_slow_relay_leaf<'info>(
     }
 
     // Check if the fill status is not filled
-    let fill_status_account = &mut ctx.accounts.fill_status;
-    if fill_status_account.status == FillStatus::Filled {
+    let mut fill_status = RelayFillStatus::load(
+        &ctx.accounts.state,
+        &relay_hash,
+        &relay_data,
+        &ctx.accounts.fill_status,
+        ctx.accounts.fill_status_bucket.as_deref(),
+    )?;
+    if fill_status.status == FillStatus::Filled {
         return err!(CommonError::RelayFilled);
     }
 
+    let updated_output_amount = slow_fill_leaf.updated_output_amount;
+    TokenLimits::update(&ctx.accounts.token_limits, |limits| {
+        limits.record_outflow(current_time, updated_output_amount)
+    })?;
+
     // Derive the signer seeds for the state
     let state_seed_bytes = ctx.accounts.state.seed.to_le_bytes();
     let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
     let signer_seeds = &[&seeds[..]];
 
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
     // Invoke the transfer_checked instruction on the token program
     let transfer_accounts = TransferChecked {
         from: ctx.accounts.vault.to_account_info(), // Pull from the vault
@@ -256,7 +312,7 @@ pub fn execute_slow_relay_leaf<'info>(
     transfer_checked(cpi_context, slow_fill_leaf.updated_output_amount, ctx.accounts.mint.decimals)?;
 
     // Update the fill status. We don't set the relayer and fill deadline as it is set when the slow fill was requested.
-    fill_status_account.status = FillStatus::Filled;
+    fill_status.set(FillStatus::Filled, None, &ctx.accounts.signer.to_account_info(), &ctx.accounts.system_program)?;
 
     if !relay_data.message.is_empty() {
         invoke_handler(ctx.accounts.signer.as_ref(), ctx.remaining_accounts, &relay_data.message)?;
@@ -313,3 +369,228 @@ fn unwrap_execute_slow_relay_leaf_params(
             .unwrap(), // We do not expect this to panic here as missing instruction_params is unwrapped in context.
     }
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(_root_bundle_id: Option<u32>)]
+pub struct ExecuteSlowRelayLeaves<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // This is required as fallback when None instruction params are passed in arguments.
+    #[account(mut, seeds = [b"instruction_params", signer.key().as_ref()], bump, close = signer)]
+    pub instruction_params: Option<Account<'info, ExecuteSlowRelayLeavesParams>>,
+
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_refunds @ SvmError::RefundsArePaused
+    )]
+    pub state: Account<'info, State>,
+
+    #[account(
+        seeds = [
+            b"root_bundle",
+            state.seed.to_le_bytes().as_ref(),
+            _root_bundle_id
+                .unwrap_or_else(|| instruction_params.as_ref().unwrap().root_bundle_id)
+                .to_le_bytes()
+                .as_ref(),
+        ],
+        bump,
+        constraint = root_bundle.is_active() @ SvmError::RootBundlePending
+    )]
+    pub root_bundle: Account<'info, RootBundle>,
+
+    // Output token of all slow fill leaves is checked against this mint in the handler.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(mut, seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.key().as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = state,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_slow_relay_leaves<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteSlowRelayLeaves<'info>>,
+    root_bundle_id: Option<u32>,
+    slow_fill_leaves: Option<Vec<SlowFill>>,
+    proof: Option<Vec<[u8; 32]>>,
+    proof_flags: Option<Vec<bool>>,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let ExecuteSlowRelayLeavesParams { slow_fill_leaves, proof, proof_flags, .. } =
+        unwrap_execute_slow_relay_leaves_params(
+            root_bundle_id,
+            slow_fill_leaves,
+            proof,
+            proof_flags,
+            &ctx.accounts.instruction_params,
+        )?;
+
+    if slow_fill_leaves.is_empty() {
+        return err!(SvmError::InvalidBatchFill);
+    }
+
+    // Each slow fill leaf requires its recipient's ATA followed by its fill status PDA in the remaining accounts, and
+    // also its fill status bucket for relays past the compressed fill status cutover.
+    let state = &ctx.accounts.state;
+    let account_count = get_batch_fill_account_count(state, slow_fill_leaves.iter().map(|leaf| &leaf.relay_data));
+    if ctx.remaining_accounts.len() != account_count {
+        return err!(SvmError::InvalidBatchFillAccounts);
+    }
+
+    let current_time = get_current_time(state)?;
+
+    // This overrides caller provided chain_id, same as in execute_slow_relay_leaf.
+    let slow_fills: Vec<SlowFill> = slow_fill_leaves
+        .into_iter()
+        .map(|slow_fill_leaf| SlowFill { chain_id: state.chain_id, ..slow_fill_leaf })
+        .collect();
+    let leaves = slow_fills
+        .iter()
+        .map(|slow_fill| slow_fill.to_keccak_hash())
+        .collect::<Result<Vec<_>>>()?;
+    verify_merkle_multi_proof(ctx.accounts.root_bundle.slow_relay_root, &leaves, &proof, &proof_flags)?;
+
+    // Derive the signer seeds for the state
+    let state_seed_bytes = state.seed.to_le_bytes();
+    let seeds = &[b"state", state_seed_bytes.as_ref(), &[ctx.bumps.state]];
+    let signer_seeds = &[&seeds[..]];
+
+    validate_mint_extensions(&ctx.accounts.mint)?;
+
+    let mut recipient_account_index = 0;
+    for slow_fill in slow_fills.iter() {
+        let relay_data = &slow_fill.relay_data;
+        if relay_data.output_token != ctx.accounts.mint.key() {
+            return err!(SvmError::InvalidMint);
+        }
+        // Remaining accounts are used for the batch, so message handlers cannot be invoked.
+        if !relay_data.message.is_empty() {
+            return err!(SvmError::BatchFillMessageNotSupported);
+        }
+
+        if relay_data.fill_deadline < current_time {
+            return err!(CommonError::ExpiredFillDeadline);
+        }
+
+        let recipient_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&ctx.remaining_accounts[recipient_account_index])
+                .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", recipient_account_index)))?;
+        if !is_valid_associated_token_account(
+            &recipient_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &relay_data.recipient,
+        ) {
+            return Err(Error::from(ErrorCode::AccountNotAssociatedTokenAccount)
+                .with_account_name(format!("remaining_accounts[{}]", recipient_account_index)));
+        }
+
+        let fill_status_index = recipient_account_index + 1;
+        let (fill_status_bucket, next_account_index) = match state.uses_compressed_fill_status(relay_data.fill_deadline)
+        {
+            true => (Some(&ctx.remaining_accounts[fill_status_index + 1]), fill_status_index + 2),
+            false => (None, fill_status_index + 1),
+        };
+        recipient_account_index = next_account_index;
+        let relay_hash = get_relay_hash(relay_data, state.chain_id);
+        let mut fill_status = RelayFillStatus::load(
+            state,
+            &relay_hash,
+            relay_data,
+            &ctx.remaining_accounts[fill_status_index],
+            fill_status_bucket,
+        )
+        .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", fill_status_index)))?;
+        if fill_status.status == FillStatus::Filled {
+            return err!(CommonError::RelayFilled);
+        }
+
+        TokenLimits::update(&ctx.accounts.token_limits, |limits| {
+            limits.record_outflow(current_time, slow_fill.updated_output_amount)
+        })?;
+
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: recipient_token_account.to_account_info(),
+            authority: state.to_account_info(),
+        };
+        let cpi_context =
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_accounts, signer_seeds);
+        transfer_checked(cpi_context, slow_fill.updated_output_amount, ctx.accounts.mint.decimals)?;
+
+        // We don't set the relayer and fill deadline as it is set when the slow fill was requested.
+        fill_status
+            .set(FillStatus::Filled, None, &ctx.accounts.signer.to_account_info(), &ctx.accounts.system_program)
+            .map_err(|e| e.with_account_name(format!("remaining_accounts[{}]", fill_status_index)))?;
+
+        // Messages are empty, so zeroed bytes32 is emitted for the message hash.
+        let message_hash = hash_non_empty_message(&relay_data.message);
+
+        emit_cpi!(FilledRelay {
+            input_token: relay_data.input_token,
+            output_token: relay_data.output_token,
+            input_amount: relay_data.input_amount,
+            output_amount: relay_data.output_amount,
+            repayment_chain_id: 0, // There is no repayment chain id for slow fills.
+            origin_chain_id: relay_data.origin_chain_id,
+            deposit_id: relay_data.deposit_id,
+            fill_deadline: relay_data.fill_deadline,
+            exclusivity_deadline: relay_data.exclusivity_deadline,
+            exclusive_relayer: relay_data.exclusive_relayer,
+            relayer: Pubkey::default(), // There is no repayment address for slow
+            depositor: relay_data.depositor,
+            recipient: relay_data.recipient,
+            message_hash,
+            relay_execution_info: RelayExecutionEventInfo {
+                updated_recipient: relay_data.recipient,
+                updated_message_hash: message_hash,
+                updated_output_amount: slow_fill.updated_output_amount,
+                fill_type: FillType::SlowFill,
+            },
+        });
+    }
+
+    Ok(())
+}
+
+// Helper to unwrap optional instruction params with fallback loading from buffer account.
+fn unwrap_execute_slow_relay_leaves_params(
+    root_bundle_id: Option<u32>,
+    slow_fill_leaves: Option<Vec<SlowFill>>,
+    proof: Option<Vec<[u8; 32]>>,
+    proof_flags: Option<Vec<bool>>,
+    account: &Option<Account<ExecuteSlowRelayLeavesParams>>,
+) -> Result<ExecuteSlowRelayLeavesParams> {
+    match (root_bundle_id, slow_fill_leaves, proof, proof_flags) {
+        (Some(root_bundle_id), Some(slow_fill_leaves), Some(proof), Some(proof_flags)) => {
+            Ok(ExecuteSlowRelayLeavesParams { root_bundle_id, slow_fill_leaves, proof, proof_flags })
+        }
+        // The context only unwraps instruction_params when root_bundle_id is missing, so it can be missing here.
+        _ => account
+            .as_ref()
+            .map(|account| ExecuteSlowRelayLeavesParams {
+                root_bundle_id: account.root_bundle_id,
+                slow_fill_leaves: account.slow_fill_leaves.clone(),
+                proof: account.proof.clone(),
+                proof_flags: account.proof_flags.clone(),
+            })
+            .ok_or(SvmError::InvalidInstructionParams.into()),
+    }
+}
@@ -22,7 +22,11 @@ pub struct BridgeTokensToHubPool<'info> {
     #[account(mut, mint::token_program = token_program)]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    #[account(
+        seeds = [b"state", state.seed.to_le_bytes().as_ref()],
+        bump,
+        constraint = !state.paused_bridge @ SvmError::BridgeIsPaused
+    )]
     pub state: Account<'info, State>,
 
     #[account(mut, seeds = [b"transfer_liability", mint.key().as_ref()], bump)]
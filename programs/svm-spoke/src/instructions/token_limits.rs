@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{State, TokenLimits, TokenLimitsCapacity},
+    utils::get_current_time,
+};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct GetTokenLimitsCapacity<'info> {
+    #[account(seeds = [b"state", state.seed.to_le_bytes().as_ref()], bump)]
+    pub state: Account<'info, State>,
+
+    /// CHECK: PDA derived with seeds ["token_limits", state.seed, mint]; holds TokenLimits only if set for the mint.
+    #[account(seeds = [b"token_limits", state.seed.to_le_bytes().as_ref(), mint.as_ref()], bump)]
+    pub token_limits: UncheckedAccount<'info>,
+}
+
+pub fn get_token_limits_capacity(ctx: Context<GetTokenLimitsCapacity>) -> Result<TokenLimitsCapacity> {
+    let current_time = get_current_time(&ctx.accounts.state)?;
+
+    let capacity = match TokenLimits::load(&ctx.accounts.token_limits)? {
+        Some(token_limits) => token_limits.get_capacity(current_time),
+        None => TokenLimitsCapacity {
+            max_deposit_amount: u64::MAX,
+            remaining_deposit_volume: u64::MAX,
+            remaining_outflow_volume: u64::MAX,
+            remaining_fill_volume: u64::MAX,
+        },
+    };
+
+    Ok(capacity)
+}
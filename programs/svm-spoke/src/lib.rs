@@ -55,6 +55,8 @@ pub mod svm_spoke {
     /// ### Required Accounts:
     /// - signer (Writable, Signer): The account that pays for the transaction and will own the state.
     /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - remote_nonce (Writable): Tracks the nonce of the last sequenced admin message applied from the remote domain.
+    ///   Seed: ["remote_nonce",state.seed,remote_domain].
     /// - system_program: The system program required for account creation.
     ///
     /// ### Parameters:
@@ -87,42 +89,163 @@ pub mod svm_spoke {
         )
     }
 
-    /// Pauses the Spoke Pool from accepting deposits. Only callable by the owner.
+    /// Migrates the state from the initially deployed layout by appending the fields added since then. Callable by
+    /// anyone once after upgrading the program, as the state cannot be loaded by any other instruction until migrated.
+    ///
+    /// Appended fields are backfilled with the same values as on initialization, so the root bundle close grace period
+    /// is set to its default and compressed fill statuses stay disabled until the owner sets their cutover. The owner
+    /// is kept without any pending ownership transfer and the root bundle timelock stays disabled. Deposit and fill
+    /// pauses are preserved, while refunds, claims and bridging start unpaused.
+    ///
+    /// ### Required Accounts:
+    /// - payer (Signer): The account that pays for the additional rent of the state.
+    /// - state (Writable): Spoke state PDA in the legacy layout. Seed: ["state",seed] where seed is 0 on mainnet.
+    /// - remote_nonce (Writable): Created to track the nonce of the last sequenced admin message applied from the
+    ///   remote domain. Seed: ["remote_nonce",seed,remote_domain].
+    /// - system_program: The system program required for reallocating the state.
+    ///
+    /// ### Parameters:
+    /// - seed: The seed used to derive the state account's address. 0 on Mainnet.
+    /// - remote_domain: The CCTP domain stored in the legacy state.
+    pub fn migrate_state(ctx: Context<MigrateState>, seed: u64, remote_domain: u32) -> Result<()> {
+        instructions::migrate_state(ctx, seed, remote_domain)
+    }
+
+    /// Migrates a root bundle from the initially deployed layout by appending the fields added since then. Callable by
+    /// anyone once the state is migrated and required for each root bundle that was relayed before the upgrade.
+    ///
+    /// Migrated root bundles are active regardless of the root bundle timelock and keep their claimed bitmap. As their
+    /// leaf count was not recorded, they can only be closed once superseded, returning the rent to the owner.
+    ///
+    /// ### Required Accounts:
+    /// - payer (Signer): The account that pays for the additional rent. It is not refunded when the bundle is closed.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Writable): The root bundle PDA in the legacy layout. Seed: ["root_bundle",state.seed,
+    ///   root_bundle_id].
+    /// - system_program: The system program required for reallocating the root bundle.
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle to migrate.
+    pub fn migrate_root_bundle(ctx: Context<MigrateRootBundle>, root_bundle_id: u32) -> Result<()> {
+        instructions::migrate_root_bundle(ctx, root_bundle_id)
+    }
+
+    /// Pauses the Spoke Pool from accepting deposits. Only callable by the owner. Accounts granted the pauser role can
+    /// also pause, but not unpause, deposits by passing their role_member PDA as the first remaining account.
     ///
     /// ### Required Accounts:
-    /// - signer (Signer): The account that must be the owner to authorize the pause.
+    /// - signer (Signer): The account that must be the owner or a pauser to authorize the pause.
     /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed], where `seed` is 0 on mainnet.
     ///
     /// ### Parameters:
     /// - pause: `true` to pause the system, `false` to unpause it.
-    pub fn pause_deposits(ctx: Context<PauseDeposits>, pause: bool) -> Result<()> {
+    pub fn pause_deposits<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseDeposits<'info>>, pause: bool) -> Result<()>
+    where
+        'c: 'info,
+    {
         instructions::pause_deposits(ctx, pause)
     }
 
-    /// Pauses the Spoke Pool from processing fills. Only callable by the owner.
+    /// Pauses the Spoke Pool from processing fills. Only callable by the owner. Accounts granted the pauser role can
+    /// also pause, but not unpause, fills by passing their role_member PDA as the first remaining account.
     ///
     /// ### Required Accounts:
-    /// - signer (Signer): The account that must be the owner to authorize the pause.
+    /// - signer (Signer): The account that must be the owner or a pauser to authorize the pause.
     /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed], where `seed` is 0 on mainnet.
     ///
     /// ### Parameters:
     /// - pause: `true` to pause the system, `false` to unpause it.
-    pub fn pause_fills(ctx: Context<PauseFills>, pause: bool) -> Result<()> {
+    pub fn pause_fills<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseFills<'info>>, pause: bool) -> Result<()>
+    where
+        'c: 'info,
+    {
         instructions::pause_fills(ctx, pause)
     }
 
-    /// Transfers ownership of the Spoke Pool. Only callable by the current owner.
+    /// Pauses execution of relayer refund, slow relay, origin refund and payout leaves. Only callable by the owner.
+    /// Accounts granted the guardian role can also pause, but not unpause, leaf execution by passing their role_member
+    /// PDA as the first remaining account.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner or a guardian to authorize the pause.
+    /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed], where `seed` is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - pause: `true` to pause leaf execution, `false` to unpause it.
+    pub fn pause_refunds<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseRefunds<'info>>, pause: bool) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::pause_refunds(ctx, pause)
+    }
+
+    /// Pauses claiming relayer refunds from claim accounts. Only callable by the owner. Accounts granted the guardian
+    /// role can also pause, but not unpause, claims by passing their role_member PDA as the first remaining account.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner or a guardian to authorize the pause.
+    /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed], where `seed` is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - pause: `true` to pause claims, `false` to unpause them.
+    pub fn pause_claims<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseClaims<'info>>, pause: bool) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::pause_claims(ctx, pause)
+    }
+
+    /// Pauses bridging tokens back to the HubPool. Only callable by the owner. Accounts granted the guardian role can
+    /// also pause, but not unpause, bridging by passing their role_member PDA as the first remaining account.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner or a guardian to authorize the pause.
+    /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed], where `seed` is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - pause: `true` to pause bridging, `false` to unpause it.
+    pub fn pause_bridge<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, PauseBridge<'info>>, pause: bool) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::pause_bridge(ctx, pause)
+    }
+
+    /// Starts the ownership transfer of the Spoke Pool. Only callable by the current owner or remotely by the
+    /// cross-domain admin.
+    ///
+    /// The new owner is stored as the pending owner and only takes over once it calls `accept_ownership`. Proposing
+    /// another owner replaces any pending transfer. Transferring to the zero address renounces ownership immediately.
     ///
     /// ### Required Accounts:
     /// - signer (Signer): The account that must be the current owner to authorize the transfer.
     /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed] where `seed` is 0 on mainnet.
     ///
     /// ### Parameters:
-    /// - new_owner: The public key of the new owner.
+    /// - new_owner: The public key of the proposed new owner.
     pub fn transfer_ownership(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()> {
         instructions::transfer_ownership(ctx, new_owner)
     }
 
+    /// Accepts the pending ownership transfer of the Spoke Pool. Only callable by the pending owner.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the pending owner set in `transfer_ownership`.
+    /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed] where `seed` is 0 on mainnet.
+    pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+        instructions::accept_ownership(ctx)
+    }
+
+    /// Cancels the pending ownership transfer of the Spoke Pool. Only callable by the current owner or remotely by
+    /// the cross-domain admin.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the current owner to authorize the cancellation.
+    /// - state (Writable): The Spoke state PDA. Seed: ["state",state.seed] where `seed` is 0 on mainnet.
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        instructions::cancel_ownership_transfer(ctx)
+    }
+
     /// Sets the cross-domain admin for the Spoke Pool. Only callable by owner. Used if Hubpool upgrades.
     ///
     /// ### Required Accounts:
@@ -135,15 +258,249 @@ pub mod svm_spoke {
         instructions::set_cross_domain_admin(ctx, cross_domain_admin)
     }
 
-    /// Stores a new root bundle for later execution. Only callable by the owner.
+    /// Enables or disables deposits of the origin token to the destination chain. Only callable by owner.
+    ///
+    /// Deposits are rejected unless their route was enabled, so that funds are not locked in routes that the HubPool
+    /// would never refund.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the route change.
+    /// - payer (Signer): The account who pays rent to create route PDA.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - route (Writable): The route PDA. Seed: ["route",origin_token,state.seed,destination_chain_id].
+    /// - system_program (Program): The system program required for account creation.
+    ///
+    /// ### Parameters:
+    /// - origin_token: The mint of the input token deposited on this chain.
+    /// - destination_chain_id: The destination chain identifier of the route.
+    /// - enabled: True to enable deposits on the route, false to disable them.
+    pub fn set_enable_route(
+        ctx: Context<SetEnableRoute>,
+        origin_token: Pubkey,
+        destination_chain_id: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_enable_route(ctx, origin_token, destination_chain_id, enabled)
+    }
+
+    /// Sets deposit caps and rate limits for the mint. Only callable by owner.
+    ///
+    /// Once set, deposits of the mint are bounded by the max single deposit amount and the deposit volume per window.
+    /// Fills funded by relayers and outflows from the vault (slow fills, relayer refunds and other payouts) are each
+    /// bounded by the outflow volume per window and tracked separately, so that fills cannot block leaf execution. A
+    /// single vault outflow larger than the outflow volume is admitted when nothing else was paid out in the window.
+    /// Tracked volumes reset when a new window starts after window_length seconds. Mints without limits are not
+    /// restricted.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the limits change.
+    /// - payer (Signer): The account who pays rent to create token_limits PDA.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - token_limits (Writable): The token limits PDA. Seed: ["token_limits",state.seed,mint].
+    /// - system_program (Program): The system program required for account creation.
+    ///
+    /// ### Parameters:
+    /// - mint: The mint that the limits apply to.
+    /// - max_deposit_amount: Max input amount of a single deposit. 0 disables this limit.
+    /// - max_deposit_volume: Max total input amount deposited within a window. 0 disables this limit.
+    /// - max_outflow_volume: Max total amount of fills and, separately, of vault outflows within a window. 0 disables
+    ///   this limit.
+    /// - window_length: Length of the volume tracking window in seconds. Must be non-zero.
+    pub fn set_token_limits(
+        ctx: Context<SetTokenLimitsState>,
+        mint: Pubkey,
+        max_deposit_amount: u64,
+        max_deposit_volume: u64,
+        max_outflow_volume: u64,
+        window_length: u32,
+    ) -> Result<()> {
+        instructions::set_token_limits(
+            ctx,
+            mint,
+            max_deposit_amount,
+            max_deposit_volume,
+            max_outflow_volume,
+            window_length,
+        )
+    }
+
+    /// Sets the deposit quote time buffer. Only callable by owner.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the buffer change.
+    /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - deposit_quote_time_buffer: Quote timestamps can't be set more than this amount into the past from deposit.
+    ///   Must be non-zero and at most one day.
+    pub fn set_deposit_quote_time_buffer(
+        ctx: Context<SetDepositQuoteTimeBufferState>,
+        deposit_quote_time_buffer: u32,
+    ) -> Result<()> {
+        instructions::set_deposit_quote_time_buffer(ctx, deposit_quote_time_buffer)
+    }
+
+    /// Sets the fill deadline buffer. Only callable by owner.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the buffer change.
+    /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - fill_deadline_buffer: Fill deadlines can't be set more than this amount into the future from deposit.
+    ///   Must be non-zero and at most one week.
+    pub fn set_fill_deadline_buffer(ctx: Context<SetFillDeadlineBufferState>, fill_deadline_buffer: u32) -> Result<()> {
+        instructions::set_fill_deadline_buffer(ctx, fill_deadline_buffer)
+    }
+
+    /// Sets the period after which anyone can close a superseded root bundle via close_root_bundle. Only callable by
+    /// owner.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the grace period change.
+    /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - root_bundle_close_grace_period: Seconds since relaying a superseded root bundle before it can be closed.
+    ///   Must be non-zero. Defaults to 30 days on initialization.
+    pub fn set_root_bundle_close_grace_period(
+        ctx: Context<SetRootBundleCloseGracePeriodState>,
+        root_bundle_close_grace_period: u32,
+    ) -> Result<()> {
+        instructions::set_root_bundle_close_grace_period(ctx, root_bundle_close_grace_period)
+    }
+
+    /// Sets the cutover fill deadline from which relays track their fill status in compressed FillStatusBucket
+    /// accounts instead of FillStatusAccount PDAs. Only callable by owner.
+    ///
+    /// Relays with fill deadlines at or after the cutover track their fill status in their bucket with all fill
+    /// methods, which then require the fill_status_bucket account, while relays with earlier fill deadlines keep using
+    /// their PDAs. The compressed methods only accept relays past the cutover. Existing FillStatusAccount PDAs of
+    /// relays past the cutover must be moved to their bucket via migrate_fill_status before these relays can be filled.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the cutover change.
+    /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - compressed_fill_status_cutover: Fill deadline from which relays use compressed fill statuses. Must be
+    ///   non-zero and once set can only be moved earlier.
+    pub fn set_compressed_fill_status_cutover(
+        ctx: Context<SetCompressedFillStatusCutoverState>,
+        compressed_fill_status_cutover: u32,
+    ) -> Result<()> {
+        instructions::set_compressed_fill_status_cutover(ctx, compressed_fill_status_cutover)
+    }
+
+    /// Sets the timelock for root bundles relayed by local signers. Only callable by owner.
+    ///
+    /// Root bundles relayed by the local owner, bundle relayers or additional remote admins stay pending until the
+    /// timelock passes, during which the cross-domain admin can veto them via veto_root_bundle. Root bundles relayed by
+    /// the cross-domain admin are not timelocked as they already passed the HubPool challenge period. The local owner
+    /// and additional remote admins can only extend the timelock, while the cross-domain admin can also shorten or
+    /// disable it.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the timelock change.
+    /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    ///
+    /// ### Parameters:
+    /// - root_bundle_timelock: Seconds that locally relayed root bundles stay pending. 0 disables the timelock.
+    pub fn set_root_bundle_timelock(ctx: Context<SetRootBundleTimelockState>, root_bundle_timelock: u32) -> Result<()> {
+        instructions::set_root_bundle_timelock(ctx, root_bundle_timelock)
+    }
+
+    /// Registers an additional remote admin that can govern the Spoke Pool over CCTP. Only callable by owner.
+    ///
+    /// This allows authorizing a secondary governance chain or a backup HubPool deployment without a program upgrade.
+    /// Calling this again for an already registered remote admin replaces its permitted selectors.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the remote admin change.
+    /// - payer (Signer): The account who pays rent to create remote_admin PDA.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - remote_admin (Writable): The remote admin PDA. Seed: ["remote_admin",state.seed,remote_domain,sender].
+    /// - remote_nonce (Writable): Tracks the nonce of the last sequenced admin message applied from the remote domain,
+    ///   created if not yet used by other remote admins. Seed: ["remote_nonce",state.seed,remote_domain].
+    /// - system_program (Program): The system program required for account creation.
+    ///
+    /// ### Parameters:
+    /// - remote_domain: The CCTP domain of the remote admin.
+    /// - sender: The sender of CCTP messages on the remote domain.
+    /// - selectors: Solidity selectors that the remote admin is permitted to call. At most 16 selectors are allowed.
+    pub fn set_remote_admin(
+        ctx: Context<SetRemoteAdmin>,
+        remote_domain: u32,
+        sender: Pubkey,
+        selectors: Vec<[u8; 4]>,
+    ) -> Result<()> {
+        instructions::set_remote_admin(ctx, remote_domain, sender, selectors)
+    }
+
+    /// Removes an additional remote admin. Only callable by owner.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize the remote admin removal.
+    /// - closer (SystemAccount): The account that will receive the lamports from closing the remote_admin account.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - remote_admin (Writable): The remote admin PDA. Seed: ["remote_admin",state.seed,remote_domain,sender].
+    ///
+    /// ### Parameters:
+    /// - remote_domain: The CCTP domain of the remote admin.
+    /// - sender: The sender of CCTP messages on the remote domain.
+    pub fn remove_remote_admin(ctx: Context<RemoveRemoteAdmin>, remote_domain: u32, sender: Pubkey) -> Result<()> {
+        instructions::remove_remote_admin(ctx, remote_domain, sender)
+    }
+
+    /// Grants a role to an account. Only callable by owner.
+    ///
+    /// Roles permit calling a narrow set of admin instructions without being the owner: role 0 (pauser) can pause
+    /// deposits and fills, role 1 (bundle relayer) can relay root bundles and typed roots, role 2 (emergency admin)
+    /// can emergency delete root bundles and role 3 (guardian) can pause refunds, claims and bridging. Role members
+    /// pass their role_member PDA as the first remaining account.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize granting the role.
+    /// - payer (Signer): The account who pays rent to create role_member PDA.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - role_member (Writable): The role member PDA. Seed: ["role_member",state.seed,role,member].
+    /// - system_program (Program): The system program required for account creation.
+    ///
+    /// ### Parameters:
+    /// - role: The role to grant.
+    /// - member: The account to grant the role to.
+    pub fn grant_role(ctx: Context<GrantRole>, role: u8, member: Pubkey) -> Result<()> {
+        instructions::grant_role(ctx, role, member)
+    }
+
+    /// Revokes a role from an account. Only callable by owner.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner to authorize revoking the role.
+    /// - closer (SystemAccount): The account that will receive the lamports from closing the role_member account.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - role_member (Writable): The role member PDA. Seed: ["role_member",state.seed,role,member].
+    ///
+    /// ### Parameters:
+    /// - role: The role to revoke.
+    /// - member: The account to revoke the role from.
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: u8, member: Pubkey) -> Result<()> {
+        instructions::revoke_role(ctx, role, member)
+    }
+
+    /// Stores a new root bundle for later execution. Only callable by the owner or accounts granted the bundle relayer
+    /// role, passing their role_member PDA as the first remaining account.
     ///
     /// Once stored, these roots are used to execute relayer refunds, slow fills, and pool rebalancing actions.
     /// This method initializes a root_bundle PDA to store the root bundle data. The caller
-    /// of this method is responsible for paying the rent for this PDA.
+    /// of this method is responsible for paying the rent for this PDA. When root_bundle_timelock is set, root bundles
+    /// relayed by local signers stay pending until activated via activate_root_bundle once the timelock has passed.
     ///
     /// ### Required Accounts:
-    /// - signer (Signer): The account that must be the owner to authorize the addition of the new root bundle.
-    /// - payer (Signer): The account who pays rent to create root_bundle PDA.
+    /// - signer (Signer): The account that must be the owner or a bundle relayer to authorize the addition of the new
+    ///   root bundle.
+    /// - payer (Signer): The account who pays rent to create root_bundle PDA. Receives the rent back when the root
+    ///   bundle is closed via close_root_bundle.
     /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
     /// - root_bundle (Writable): The newly created bundle PDA to store root bundle data. Each root bundle has an
     ///   incrementing ID, stored in the state. Seed: ["root_bundle",state.seed,root_bundle_id].
@@ -152,34 +509,172 @@ pub mod svm_spoke {
     /// ### Parameters:
     /// - relayer_refund_root: Merkle root of the relayer refund tree.
     /// - slow_relay_root: Merkle root of the slow relay tree.
-    pub fn relay_root_bundle(
-        ctx: Context<RelayRootBundle>,
+    /// - leaf_count: Optional number of leaves in the relayer refund tree. When provided, the claimed bitmap is
+    ///   allocated upfront so executing leaves does not need to grow the root_bundle PDA, leaf IDs at or above the count
+    ///   are rejected and the root bundle can be closed via close_root_bundle as soon as all of its relayer refund leaves
+    ///   have been executed. The HubPool does not relay the leaf count, so it is always unset for root bundles relayed
+    ///   via CCTP and those can only be closed once superseded.
+    pub fn relay_root_bundle<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RelayRootBundle<'info>>,
         relayer_refund_root: [u8; 32],
         slow_relay_root: [u8; 32],
-    ) -> Result<()> {
-        instructions::relay_root_bundle(ctx, relayer_refund_root, slow_relay_root)
+        leaf_count: Option<u32>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::relay_root_bundle(ctx, relayer_refund_root, slow_relay_root, leaf_count)
     }
 
-    /// Deletes a root bundle in case of emergencies where bad bundle has reached the Spoke. Only callable by the owner.
+    /// Stores an additional typed merkle root for an already relayed root bundle. Only callable by the owner or accounts
+    /// granted the bundle relayer role, passing their role_member PDA as the first remaining account.
+    ///
+    /// Root type 0 is the origin refund tree executed via execute_origin_refund_leaf. Any other root type is a generic
+    /// payout tree (e.g. LP rewards or periphery rebates) executed via execute_payout_leaf, so new payout categories
+    /// do not require program or CCTP message format changes. Each root type can be relayed only once per root bundle
+    /// and gets its own claimed bitmap stored in the typed_root_claims PDA. When root_bundle_timelock is set, typed roots
+    /// relayed by signers other than the cross-domain admin can only be added to pending root bundles and extend their
+    /// pending period until the timelock passes again.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that must be the owner or a bundle relayer to authorize relaying the root.
+    /// - payer (Signer): The account who pays rent to create typed_root_claims PDA and receives it back when closed.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Writable): The root bundle PDA to store the root. Seed: ["root_bundle",state.seed,root_bundle_id].
+    /// - typed_root_claims (Writable): The newly created PDA to track claimed leaves of the typed root.
+    ///   Seed: ["typed_root_claims",state.seed,root_bundle_id,root_type].
+    /// - system_program (Program): The system program required for account creation.
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle to store the root in.
+    /// - root_type: Type of the root.
+    /// - root: Merkle root of the typed tree.
+    pub fn relay_typed_root<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RelayTypedRoot<'info>>,
+        root_bundle_id: u32,
+        root_type: u8,
+        root: [u8; 32],
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::relay_typed_root(ctx, root_bundle_id, root_type, root)
+    }
+
+    /// Deletes a root bundle in case of emergencies where bad bundle has reached the Spoke. Only callable by the owner
+    /// or accounts granted the emergency admin role, passing their role_member PDA as the first remaining account.
     ///
     /// This function will close the PDA for the associated `root_bundle_id`.
     /// Note: Using this function does not decrement `state.root_bundle_id`.
     ///
     /// ### Required Accounts:
-    /// - signer (Signer): The account that must be the owner to authorize the deletion.
+    /// - signer (Signer): The account that must be the owner or an emergency admin to authorize the deletion.
     /// - closer (SystemAccount): The account that will receive the lamports from closing the root_bundle account.
     /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
     /// - root_bundle (Writable): The root bundle PDA to be closed. Seed: ["root_bundle",state.seed,root_bundle_id].
     ///
     /// ### Parameters:
     /// - root_bundle_id: Index of the root bundle that needs to be deleted.
-    pub fn emergency_delete_root_bundle(
-        ctx: Context<EmergencyDeleteRootBundleState>,
+    pub fn emergency_delete_root_bundle<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, EmergencyDeleteRootBundleState<'info>>,
         root_bundle_id: u32,
-    ) -> Result<()> {
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
         instructions::emergency_delete_root_bundle(ctx, root_bundle_id)
     }
 
+    /// Activates a pending root bundle once its timelock has passed. Callable by anyone.
+    ///
+    /// Relayer refund, slow relay, origin refund and payout leaves can only be executed against active root bundles.
+    ///
+    /// ### Required Accounts:
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Writable): The pending root bundle PDA. Seed: ["root_bundle",state.seed,root_bundle_id].
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle to activate.
+    pub fn activate_root_bundle(ctx: Context<ActivateRootBundle>, root_bundle_id: u32) -> Result<()> {
+        instructions::activate_root_bundle(ctx, root_bundle_id)
+    }
+
+    /// Vetoes a pending root bundle during its timelock. Only callable remotely by the cross-domain admin.
+    ///
+    /// This closes the root bundle PDA and returns its rent to the payer that relayed it.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The self_authority PDA signing the self-invoked CPI from handle_receive_message.
+    /// - payer (SystemAccount): The account that paid rent when relaying the root bundle.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Writable): The pending root bundle PDA. Seed: ["root_bundle",state.seed,root_bundle_id].
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle to veto.
+    pub fn veto_root_bundle(ctx: Context<VetoRootBundle>, root_bundle_id: u32) -> Result<()> {
+        instructions::veto_root_bundle(ctx, root_bundle_id)
+    }
+
+    /// Closes a root bundle and returns its rent to the payer that relayed it. Callable by anyone.
+    ///
+    /// The root bundle can be closed either after a newer root bundle has been relayed and the configured
+    /// root_bundle_close_grace_period has passed since relaying it, or once all of its relayer refund leaves have been
+    /// executed. The latter requires the leaf count to have been provided when relaying the root bundle and applies
+    /// only to root bundles without a slow relay root or typed roots. Any unexecuted leaves of a closed root bundle can
+    /// no longer be executed.
+    ///
+    /// ### Required Accounts:
+    /// - payer (Writable): The account that paid rent for the root_bundle PDA when relaying it.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Writable): The root bundle PDA to be closed. Seed: ["root_bundle",state.seed,root_bundle_id].
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle to close.
+    pub fn close_root_bundle(ctx: Context<CloseRootBundle>, root_bundle_id: u32) -> Result<()> {
+        instructions::close_root_bundle(ctx, root_bundle_id)
+    }
+
+    /// Closes the typed_root_claims PDA of a typed root and returns its rent to the payer that relayed the typed root.
+    /// Callable by anyone once the root bundle of the typed root has been closed, vetoed or deleted, as its typed root
+    /// leaves can no longer be executed.
+    ///
+    /// ### Required Accounts:
+    /// - payer (Writable): The account that paid rent for the typed_root_claims PDA when relaying the typed root.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Account): The closed root bundle PDA. Seed: ["root_bundle",state.seed,root_bundle_id].
+    /// - typed_root_claims (Writable): The PDA to be closed. Seed: ["typed_root_claims",state.seed,root_bundle_id,
+    ///   root_type].
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle that stored the typed root.
+    /// - root_type: Type of the root.
+    pub fn close_typed_root_claims(
+        ctx: Context<CloseTypedRootClaims>,
+        root_bundle_id: u32,
+        root_type: u8,
+    ) -> Result<()> {
+        instructions::close_typed_root_claims(ctx, root_bundle_id, root_type)
+    }
+
+    /// Reports which relayer refund leaves of a root bundle have been executed. This acts like a "view" function for
+    /// off-chain actors to find outstanding leaves without scanning events. Returns the leaf count, the number of
+    /// executed leaves and the claimed bitmap where bit leaf_id % 8 of byte leaf_id / 8 is set for each executed leaf.
+    /// As return data is limited to 1024 bytes, this can report on root bundles with up to 8000 leaves.
+    ///
+    /// Outstanding leaves can only be derived when the leaf count was provided when relaying the root bundle. Root
+    /// bundles relayed from the HubPool via CCTP and migrated root bundles have no leaf count, so for those only the
+    /// executed leaves are known.
+    ///
+    /// ### Required Accounts:
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Account): The root bundle PDA. Seed: ["root_bundle",state.seed,root_bundle_id].
+    ///
+    /// ### Parameters:
+    /// - _root_bundle_id: Index of the root bundle to report on.
+    pub fn get_root_bundle_leaves(ctx: Context<GetRootBundleLeaves>, _root_bundle_id: u32) -> Result<RootBundleLeaves> {
+        instructions::get_root_bundle_leaves(ctx)
+    }
+
     // **************************************
     //          DEPOSIT FUNCTIONS           *
     // *************************************
@@ -200,10 +695,14 @@ pub mod svm_spoke {
     /// ### Required Accounts:
     /// - signer (Signer): The account that authorizes the deposit.
     /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - route (Account): Enabled deposit route PDA. Seed: ["route",input_token,state.seed,destination_chain_id].
     /// - depositor_token_account (Writable): The depositor's ATA for the input token.
     /// - vault (Writable): Programs ATA for the associated input token. This is where the depositor's assets are sent.
     ///   Authority must be the state.
-    /// - mint (Account): The mint account for the input token.
+    /// - mint (Account): The mint account for the input token. Token-2022 mints with transfer fee or transfer hook
+    ///   extensions are not supported.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
     /// - token_program (Interface): The token program.
     /// - delegate (Account): The account used to delegate the input amount of the input token.
     ///
@@ -336,6 +835,76 @@ pub mod svm_spoke {
         )
     }
 
+    /// Equivalent to deposit, except that the signer deposits native SOL lamports that are wrapped into the vault's
+    /// wSOL ATA, so the depositor does not need to hold a wSOL token account. The emitted FundsDeposited event is
+    /// identical to deposit with the wSOL mint as input_token, and the global `number_of_deposits` counter is used
+    /// for the deposit ID.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The depositor that sends the lamports. Must match depositor.
+    /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - route (Account): Enabled deposit route PDA. Seed: ["route",input_token,state.seed,destination_chain_id].
+    /// - vault (Writable): Programs wSOL ATA. This is where the depositor's lamports are wrapped. Authority must be
+    ///   the state.
+    /// - mint (Account): The native wSOL mint. Must match input_token.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - token_program (Interface): The token program.
+    /// - associated_token_program (Interface): The associated token program.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// Same as in deposit, where input_amount is the amount of lamports to wrap and deposit.
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_native(
+        ctx: Context<DepositNative>,
+        depositor: Pubkey,
+        recipient: Pubkey,
+        input_token: Pubkey,
+        output_token: Pubkey,
+        input_amount: u64,
+        output_amount: [u8; 32],
+        destination_chain_id: u64,
+        exclusive_relayer: Pubkey,
+        quote_timestamp: u32,
+        fill_deadline: u32,
+        exclusivity_parameter: u32,
+        message: Vec<u8>,
+    ) -> Result<()> {
+        instructions::deposit_native(
+            ctx,
+            depositor,
+            recipient,
+            input_token,
+            output_token,
+            input_amount,
+            output_amount,
+            destination_chain_id,
+            exclusive_relayer,
+            quote_timestamp,
+            fill_deadline,
+            exclusivity_parameter,
+            message,
+        )
+    }
+
+    /// Reports the remaining deposit, outflow and fill capacity of the mint in the current window. This acts like a
+    /// "view" function for off-chain actors to check if a deposit, fill or refund would exceed the token limits.
+    /// Disabled or unset limits are reported as u64::MAX.
+    ///
+    /// ### Required Accounts:
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - token_limits (Account): The token limits PDA. Seed: ["token_limits",state.seed,mint].
+    ///
+    /// ### Parameters:
+    /// - _mint: The mint to report the capacity for.
+    pub fn get_token_limits_capacity(
+        ctx: Context<GetTokenLimitsCapacity>,
+        _mint: Pubkey,
+    ) -> Result<TokenLimitsCapacity> {
+        instructions::get_token_limits_capacity(ctx)
+    }
+
     /// Computes the deposit ID for the depositor using the provided deposit_nonce. This acts like a "view" function for
     /// off-chain actors to compute what the expected deposit ID is for a given depositor and deposit nonce will be.
     ///
@@ -352,6 +921,47 @@ pub mod svm_spoke {
         Ok(utils::get_unsafe_deposit_id(signer, depositor, deposit_nonce))
     }
 
+    /// Emits an event requesting relayers to fill a deposit with updated parameters signed by the depositor.
+    ///
+    /// Depositor can request to update the output amount, recipient and message of a deposit originated on this chain
+    /// by signing the update with their Ed25519 key. The signature must be verified by the Ed25519 precompile program
+    /// in the instruction immediately preceding this one in the same transaction. The signed message is the keccak256
+    /// hash of the Borsh serialized deposit_id, origin_chain_id (this spoke's chain_id), updated_output_amount,
+    /// updated_recipient and updated_message. Relayers can then use this signature in fill_relay_with_updated_deposit
+    /// on the destination chain. The speed up is not validated against the original deposit on this chain.
+    ///
+    /// ### Required Accounts:
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - instructions (UncheckedAccount): The instructions sysvar used to load the Ed25519 verification instruction.
+    ///
+    /// ### Parameters:
+    /// - depositor: The depositor that must sign the speed up request.
+    /// - deposit_id: The deposit ID of the deposit to be sped up.
+    /// - updated_output_amount: New output amount for the relayer to send to the recipient. This is big-endian
+    ///   encoded as a 32-byte array, same as output_amount in deposit().
+    /// - updated_recipient: New recipient of the output tokens on the destination chain.
+    /// - updated_message: New message to be sent to the recipient on the destination chain.
+    /// - depositor_signature: Depositor's Ed25519 signature over the updated deposit details.
+    pub fn speed_up_deposit(
+        ctx: Context<SpeedUpDeposit>,
+        depositor: Pubkey,
+        deposit_id: [u8; 32],
+        updated_output_amount: [u8; 32],
+        updated_recipient: Pubkey,
+        updated_message: Vec<u8>,
+        depositor_signature: Vec<u8>,
+    ) -> Result<()> {
+        instructions::speed_up_deposit(
+            ctx,
+            depositor,
+            deposit_id,
+            updated_output_amount,
+            updated_recipient,
+            updated_message,
+            depositor_signature,
+        )
+    }
+
     // **************************************
     //          RELAYER FUNCTIONS           *
     // *************************************
@@ -371,11 +981,18 @@ pub mod svm_spoke {
     ///   instruction data due to message size constraints. Pass this program ID to represent None. When Some, this must
     ///   be derived from the signer's public key with seed ["instruction_params",signer].
     /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
-    /// - mint (Account): The mint of the output token, sent from the relayer to the recipient.
+    /// - mint (Account): The mint of the output token, sent from the relayer to the recipient. Token-2022 mints with
+    ///   transfer fee or transfer hook extensions are not supported.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
     /// - relayer_token_account (Writable): The relayer's ATA for the input token.
     /// - recipient_token_account (Writable): The recipient's ATA for the output token.
     /// - fill_status (Writable): The fill status PDA, created on this function call to track the fill status to prevent
-    ///   re-entrancy & double fills. Also used to track requested slow fills. Seed: ["fills",relay_hash].
+    ///   re-entrancy & double fills. Also used to track requested slow fills. Seed: ["fills",relay_hash]. Not created
+    ///   for relays past the compressed fill status cutover, where it must not exist.
+    /// - fill_status_bucket (Writable): Optional compressed fill status bucket that tracks the fill status instead of
+    ///   the PDA, required only for relays past the compressed fill status cutover. Pass this program ID to represent
+    ///   None. Created if needed. Seed: ["fill_status_bucket",origin_chain_id,fill_deadline / 3600,relay_hash[0] % 16].
     /// - token_program (Interface): The token program.
     /// - associated_token_program (Interface): The associated token program.
     /// - system_program (Interface): The system program.
@@ -408,6 +1025,9 @@ pub mod svm_spoke {
     /// - repayment_address: The address of the recipient on the repayment chain that they want to be refunded to.
     /// Note: relay_data, repayment_chain_id, and repayment_address are optional parameters. If None for any of these
     /// is passed, the caller must load them via the instruction_params account.
+    /// Note: relays with fill deadlines at or after the compressed fill status cutover can also be filled with
+    /// fill_relay_compressed that does not take the optional instruction_params account. All other fill methods require
+    /// the fill_status_bucket account for such relays, same as fill_relay.
     pub fn fill_relay<'info>(
         ctx: Context<'_, '_, '_, 'info, FillRelay<'info>>,
         relay_hash: [u8; 32],
@@ -418,6 +1038,265 @@ pub mod svm_spoke {
         instructions::fill_relay(ctx, relay_hash, relay_data, repayment_chain_id, repayment_address)
     }
 
+    /// Fulfill request to bridge cross chain by sending output tokens to the recipient with the updated parameters
+    /// signed by the depositor.
+    ///
+    /// Equivalent to fill_relay, except that the relayer sends updated_output_amount to the updated_recipient and the
+    /// updated_message is executed instead of the original one. The relay_hash still identifies the original deposit,
+    /// so this fill cannot be repeated with fill_relay or any other updated parameters. For Solana depositors, the
+    /// depositor's Ed25519 signature over the updated deposit details must be verified by the Ed25519 precompile program
+    /// in the instruction immediately preceding this one in the same transaction. See speed_up_deposit for the signed
+    /// message. For EVM depositors (relay_data.depositor is a 20-byte address left padded with zeroes), the signature
+    /// must be the 65-byte secp256k1 signature over the same EIP-712 UpdateDepositDetails typed data that is accepted
+    /// by speedUpDeposit on the EVM origin chain.
+    ///
+    /// ### Required Accounts:
+    /// Same as in fill_relay, except:
+    /// - instruction_params (Account): Optional account to load instruction parameters when they are not passed in the
+    ///   instruction data due to message size constraints. Pass this program ID to represent None. When Some, this must
+    ///   be derived from the signer's public key with seed ["instruction_params",signer].
+    /// - recipient_token_account (Writable): The updated recipient's ATA for the output token.
+    /// - instructions (UncheckedAccount): The instructions sysvar used to load the Ed25519 verification instruction.
+    ///   Unused for EVM depositors.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, relay_data, repayment_chain_id, repayment_address: Same as in fill_relay.
+    /// - updated_deposit_data: Struct containing the deposit update signed by the depositor:
+    ///   - updated_output_amount: The amount of output tokens that the caller will send to the updated recipient.
+    ///   - updated_recipient: The account receiving funds on this chain instead of relay_data.recipient.
+    ///   - updated_message: The message to execute instead of relay_data.message.
+    ///   - depositor_signature: Depositor's Ed25519 or EVM signature over the updated deposit details.
+    /// Note: relay_data, repayment_chain_id, repayment_address and updated_deposit_data are optional parameters. If
+    /// None for any of these is passed, the caller must load them via the instruction_params account.
+    pub fn fill_relay_with_updated_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRelayWithUpdatedDeposit<'info>>,
+        relay_hash: [u8; 32],
+        relay_data: Option<RelayData>,
+        repayment_chain_id: Option<u64>,
+        repayment_address: Option<Pubkey>,
+        updated_deposit_data: Option<UpdatedDepositData>,
+    ) -> Result<()> {
+        instructions::fill_relay_with_updated_deposit(
+            ctx,
+            relay_hash,
+            relay_data,
+            repayment_chain_id,
+            repayment_address,
+            updated_deposit_data,
+        )
+    }
+
+    /// Opts the signer in or out of receiving wSOL fills as native lamports through fill_relay_native.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The recipient that opts in or out of native fills.
+    /// - native_recipient (Writable): The native recipient PDA. Seed: ["native_recipient",signer].
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - enabled: True to receive wSOL fills as native lamports, false to opt out.
+    pub fn set_native_recipient(ctx: Context<SetNativeRecipientState>, enabled: bool) -> Result<()> {
+        instructions::set_native_recipient(ctx, enabled)
+    }
+
+    /// Creates or updates the signer's relayer profile used by fill_relay_with_profile.
+    ///
+    /// To fill with the profile, the relayer approves the relayer profile PDA as a standing delegate on its token
+    /// accounts instead of approving a fresh delegate PDA for each fill as in fill_relay.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The relayer that owns the profile.
+    /// - relayer_profile (Writable): The relayer profile PDA. Seed: ["relayer_profile",signer].
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - repayment_chain_id: Default repayment chain for fills using this profile.
+    /// - repayment_address: Default repayment address for fills using this profile.
+    /// - delegate: Additional signer allowed to fill from the relayer's token accounts using this profile, e.g. a hot
+    ///   key of the relayer's bot. Pass the zero address to allow only the relayer.
+    pub fn set_relayer_profile(
+        ctx: Context<SetRelayerProfileState>,
+        repayment_chain_id: u64,
+        repayment_address: Pubkey,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::set_relayer_profile(ctx, repayment_chain_id, repayment_address, delegate)
+    }
+
+    /// Equivalent to fill_relay, except that the relayer sends output_amount of native lamports directly to the
+    /// recipient's system account instead of wSOL to its ATA. Only supported when the output token is the wSOL mint,
+    /// the recipient has opted in via set_native_recipient and the message is empty. The emitted FilledRelay event is
+    /// identical to fill_relay.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the fill and sends the lamports. No permission requirements.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the wSOL mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - recipient (Writable): The recipient's system account. Must match relay_data.recipient.
+    /// - native_recipient (Account): The recipient's enabled native recipient PDA. Seed: ["native_recipient",recipient].
+    /// - fill_status (Writable): The fill status PDA. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): Optional compressed fill status bucket, same as in fill_relay.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, relay_data, repayment_chain_id, repayment_address: Same as in fill_relay, but are not optional.
+    pub fn fill_relay_native(
+        ctx: Context<FillRelayNative>,
+        relay_hash: [u8; 32],
+        relay_data: RelayData,
+        repayment_chain_id: u64,
+        repayment_address: Pubkey,
+    ) -> Result<()> {
+        instructions::fill_relay_native(ctx, relay_hash, relay_data, repayment_chain_id, repayment_address)
+    }
+
+    /// Equivalent to fill_relay, except that the repayment chain and address are taken from the relayer profile and
+    /// the output tokens are pulled from the relayer's token account by the relayer profile PDA that the relayer has
+    /// approved as a standing delegate. The emitted FilledRelay event contains the repayment values from the profile
+    /// and the fill status records the profile relayer even when its delegate signs the fill.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The relayer or the delegate set in its relayer profile. Pays for the fill status PDA.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - relayer_profile (Account): The relayer profile PDA. Seed: ["relayer_profile",relayer].
+    /// - mint (Account): The mint of the output token.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - relayer_token_account (Writable): The relayer's token account that approved the relayer profile PDA.
+    /// - recipient_token_account (Writable): The recipient's ATA.
+    /// - fill_status (Writable): The fill status PDA. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): Optional compressed fill status bucket, same as in fill_relay.
+    /// - token_program (Interface): The token program.
+    /// - associated_token_program (Interface): The associated token program.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, relay_data: Same as in fill_relay, but relay_data is not optional.
+    pub fn fill_relay_with_profile<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRelayWithProfile<'info>>,
+        relay_hash: [u8; 32],
+        relay_data: RelayData,
+    ) -> Result<()> {
+        instructions::fill_relay_with_profile(ctx, relay_hash, relay_data)
+    }
+
+    /// Moves tokens from the signer's token account into its relayer inventory that fill_relay_from_inventory debits.
+    ///
+    /// The inventory is the ATA of the relayer inventory PDA, created if needed, so only the relayer can fill from or
+    /// withdraw its inventory through this program.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The relayer funding its inventory.
+    /// - relayer_inventory (Account): The relayer inventory PDA. Seed: ["relayer_inventory",signer].
+    /// - mint (Account): The mint of the funded token.
+    /// - relayer_token_account (Writable): The relayer's token account to move tokens from.
+    /// - inventory_token_account (Writable): The ATA of the relayer inventory PDA.
+    /// - token_program (Interface): The token program.
+    /// - associated_token_program (Interface): The associated token program.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - amount: Amount of tokens to move into the inventory.
+    pub fn fund_relayer_inventory(ctx: Context<FundRelayerInventory>, amount: u64) -> Result<()> {
+        instructions::fund_relayer_inventory(ctx, amount)
+    }
+
+    /// Moves tokens from the signer's relayer inventory to any token account of the same mint.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The relayer withdrawing from its inventory.
+    /// - relayer_inventory (Account): The relayer inventory PDA. Seed: ["relayer_inventory",signer].
+    /// - mint (Account): The mint of the withdrawn token.
+    /// - relayer_token_account (Writable): The token account that receives the withdrawn tokens.
+    /// - inventory_token_account (Writable): The ATA of the relayer inventory PDA.
+    /// - token_program (Interface): The token program.
+    ///
+    /// ### Parameters:
+    /// - amount: Amount of tokens to withdraw from the inventory.
+    pub fn withdraw_relayer_inventory(ctx: Context<WithdrawRelayerInventory>, amount: u64) -> Result<()> {
+        instructions::withdraw_relayer_inventory(ctx, amount)
+    }
+
+    /// Equivalent to fill_relay, except that the output tokens are debited from the signer's relayer inventory funded
+    /// via fund_relayer_inventory. This does not require approving a delegate PDA for each fill. The per-fill delegate
+    /// path through fill_relay remains available.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The relayer filling from its inventory. Pays for the fill status PDA.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - relayer_inventory (Account): The relayer inventory PDA. Seed: ["relayer_inventory",signer].
+    /// - mint (Account): The mint of the output token.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - inventory_token_account (Writable): The ATA of the relayer inventory PDA.
+    /// - recipient_token_account (Writable): The recipient's ATA.
+    /// - fill_status (Writable): The fill status PDA. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): Optional compressed fill status bucket, same as in fill_relay.
+    /// - token_program (Interface): The token program.
+    /// - associated_token_program (Interface): The associated token program.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, relay_data, repayment_chain_id, repayment_address: Same as in fill_relay, but are not optional.
+    pub fn fill_relay_from_inventory<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRelayFromInventory<'info>>,
+        relay_hash: [u8; 32],
+        relay_data: RelayData,
+        repayment_chain_id: u64,
+        repayment_address: Pubkey,
+    ) -> Result<()> {
+        instructions::fill_relay_from_inventory(ctx, relay_hash, relay_data, repayment_chain_id, repayment_address)
+    }
+
+    /// Fulfill multiple requests to bridge cross chain in a single instruction.
+    ///
+    /// Equivalent to calling fill_relay for each relay in the batch, except that the relayer delegates the sum of all
+    /// output amounts once to the delegate PDA bound to the whole batch. All relays in the batch must have the same
+    /// output token and empty messages, as the remaining accounts are used to pass per-relay accounts. One FilledRelay
+    /// event is emitted for each filled relay. The whole batch fails if any of the relays cannot be filled.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the fills (filler). No permission requirements.
+    /// - instruction_params (Account): Optional account to load instruction parameters when they are not passed in the
+    ///   instruction data due to message size constraints. Pass this program ID to represent None. When Some, this must
+    ///   be derived from the signer's public key with seed ["instruction_params",signer].
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - delegate (Account): The account used to delegate the sum of output amounts of the output token. Seed:
+    ///   ["delegate",seed_hash] where seed_hash is the hash of the relay_hashes, repayment_chain_id and
+    ///   repayment_address.
+    /// - mint (Account): The mint of the output token of all relays, sent from the relayer to the recipients.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - relayer_token_account (Writable): The relayer's token account for the output token.
+    /// - token_program (Interface): The token program.
+    /// - system_program (Interface): The system program.
+    /// - remaining_accounts: For each relay in the batch, in the same order, the recipient's ATA for the output token
+    ///   (Writable) followed by the fill status PDA (Writable) with seed ["fills",relay_hash]. Relays past the
+    ///   compressed fill status cutover are also followed by their fill status bucket (Writable), same as in
+    ///   fill_relay. Fill status PDAs and buckets are created if needed with the signer paying for rent.
+    ///
+    /// ### Parameters:
+    /// - relay_hashes: The hashes identifying the deposits to be filled. See fill_relay for details.
+    /// - relay_data: Structs containing all the data needed to identify the deposits to be filled, in the same order as
+    ///   relay_hashes. See fill_relay for details.
+    /// - repayment_chain_id: Chain of SpokePool where relayer wants to be refunded for all relays in the batch.
+    /// - repayment_address: The address of the recipient on the repayment chain that they want to be refunded to.
+    /// Note: relay_hashes, relay_data, repayment_chain_id, and repayment_address are optional parameters. If None for
+    /// any of these is passed, the caller must load them via the instruction_params account.
+    pub fn fill_relays_batch<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, FillRelaysBatch<'info>>,
+        relay_hashes: Option<Vec<[u8; 32]>>,
+        relay_data: Option<Vec<RelayData>>,
+        repayment_chain_id: Option<u64>,
+        repayment_address: Option<Pubkey>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::fill_relays_batch(ctx, relay_hashes, relay_data, repayment_chain_id, repayment_address)
+    }
+
     /// Closes the FillStatusAccount PDA to reclaim relayer rent.
     ///
     /// This function is used to close the FillStatusAccount associated with a specific relay hash, effectively marking
@@ -432,6 +1311,107 @@ pub mod svm_spoke {
         instructions::close_fill_pda(ctx)
     }
 
+    /// Closes multiple expired FillStatusAccount PDAs, returning the rent of each to its recorded relayer.
+    ///
+    /// Unlike close_fill_pda, this can be called by anyone, so rent does not stay locked when the relayer does not
+    /// clean up its fill PDAs, e.g. after rotating its key. Each PDA can only be closed once its fill deadline passed.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the closure. No permission requirements.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - remaining_accounts: For each closed PDA, the writable FillStatusAccount PDA followed by the writable relayer
+    ///   account recorded in it that receives the rent.
+    pub fn close_fill_pdas<'c, 'info>(ctx: Context<'_, '_, 'c, 'info, CloseFillPdas<'info>>) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::close_fill_pdas(ctx)
+    }
+
+    /// Equivalent to fill_relay, except that the fill status is tracked in a compressed FillStatusBucket shared with
+    /// other relays instead of creating a FillStatusAccount PDA for this relay. This is only supported for relays with
+    /// fill deadlines at or after the compressed fill status cutover.
+    ///
+    /// The signer pays rent only for the bucket entry of this relay (and for the bucket itself if it does not exist
+    /// yet), which is returned once the expired bucket is closed via close_fill_status_bucket. Buckets hold relays from
+    /// the same origin chain with fill deadlines in the same hour, split into 16 shards by the first byte of the relay
+    /// hash, and each bucket grows with its entries up to the maximum account size.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the fill and pays for the bucket entry. No permission requirements.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - delegate (Account): The delegate PDA, same as in fill_relay.
+    /// - mint (Account): The mint of the output token.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - relayer_token_account (Writable): The relayer's token account.
+    /// - recipient_token_account (Writable): The recipient's ATA.
+    /// - legacy_fill_status (Account): The fill status PDA that must not exist. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): The compressed fill status bucket, created if needed. Seed: ["fill_status_bucket",
+    ///   origin_chain_id,fill_deadline / 3600,relay_hash[0] % 16].
+    /// - token_program (Interface): The token program.
+    /// - associated_token_program (Interface): The associated token program.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, relay_data, repayment_chain_id, repayment_address: Same as in fill_relay, but are not optional.
+    pub fn fill_relay_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRelayCompressed<'info>>,
+        relay_hash: [u8; 32],
+        relay_data: RelayData,
+        repayment_chain_id: u64,
+        repayment_address: Pubkey,
+    ) -> Result<()> {
+        instructions::fill_relay_compressed(ctx, relay_hash, relay_data, repayment_chain_id, repayment_address)
+    }
+
+    /// Moves the status of an existing FillStatusAccount PDA into its compressed FillStatusBucket and closes the PDA,
+    /// returning its rent to the recorded relayer.
+    ///
+    /// This is the migration path for relays with fill deadlines at or after the compressed fill status cutover that
+    /// got their PDA before the cutover was set. Such relays cannot be filled or slow filled with the compressed
+    /// methods until migrated. Callable by anyone.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that pays for the bucket entry.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - relayer (Writable): The relayer recorded in the fill status PDA that receives its rent.
+    /// - fill_status (Writable): The fill status PDA to be closed. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): The compressed fill status bucket, created if needed. Seed: ["fill_status_bucket",
+    ///   origin_chain_id,fill_deadline / 3600,relay_hash[0] % 16].
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash: The hash identifying the relay.
+    /// - relay_data: The relay data used to validate the relay hash and derive the bucket.
+    pub fn migrate_fill_status(
+        ctx: Context<MigrateFillStatus>,
+        relay_hash: [u8; 32],
+        relay_data: RelayData,
+    ) -> Result<()> {
+        instructions::migrate_fill_status(ctx, relay_hash, relay_data)
+    }
+
+    /// Returns the rent of a compressed FillStatusBucket to whoever funded it once the fill deadlines of all relays it
+    /// can track have passed. Callable by anyone.
+    ///
+    /// The rent of each entry is returned to the funder recorded in it, removing its entries from the bucket. Once all
+    /// entries are removed, the bucket is closed with the rent of the empty bucket returned to the payer that created
+    /// it. Buckets with many funders can be cleaned up across multiple calls.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the closure. No permission requirements.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - payer (Writable): The payer recorded in the bucket that receives the rent of the empty bucket.
+    /// - fill_status_bucket (Writable): The compressed fill status bucket to be closed.
+    /// - remaining_accounts: Writable funder accounts recorded in the bucket entries that receive the rent of their
+    ///   entries.
+    pub fn close_fill_status_bucket<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseFillStatusBucket<'info>>,
+    ) -> Result<()> {
+        instructions::close_fill_status_bucket(ctx)
+    }
+
     /// Claims a relayer refund for the caller.
     ///
     /// In the event a relayer refund was sent to a claim account, then this function enables the relayer to claim it by
@@ -501,6 +1481,8 @@ pub mod svm_spoke {
     ///   was initially bridged. seed: ["root_bundle",state.seed,root_bundle_id].
     /// - vault (Writable): The ATA for refunded mint. Authority must be the state.
     /// - mint (Account): The mint account for the token being refunded.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
     /// - transfer_liability (Writable): Account to track pending refunds to be sent to the Ethereum hub pool. Only used
     ///   if the amount_to_return value is non-zero within the leaf. Seed: ["transfer_liability",mint]
     /// - token_program: The token program.
@@ -513,7 +1495,7 @@ pub mod svm_spoke {
     where
         'c: 'info,
     {
-        instructions::execute_relayer_refund_leaf(ctx, false)
+        instructions::execute_relayer_refund_leaf(ctx, RefundMode::Direct)
     }
 
     /// Similar to execute_relayer_refund_leaf, but executes in mode where refunds are allocated to claim_account PDAs.
@@ -523,7 +1505,159 @@ pub mod svm_spoke {
     where
         'c: 'info,
     {
-        instructions::execute_relayer_refund_leaf(ctx, true)
+        instructions::execute_relayer_refund_leaf(ctx, RefundMode::Deferred)
+    }
+
+    /// Similar to execute_relayer_refund_leaf, but executes in hybrid mode where each refund is sent to the relayer's
+    /// ATA directly when it can receive the transfer and otherwise falls back to the relayer's claim_account PDA.
+    ///
+    /// This lets the rest of the relayers in the leaf get paid directly even if some of them have closed or frozen
+    /// their ATA. The caller must pass refund token accounts (ATAs) for all refund_addresses in remaining accounts,
+    /// followed by claim_account PDAs for all refund_addresses in the same order. Claim accounts are only accessed for
+    /// deferred refunds and these must have been initialized before. The refund_deferrals field in the emitted
+    /// ExecutedRelayerRefundRoot event reports for each refund address whether its refund was deferred.
+    pub fn execute_relayer_refund_leaf_hybrid<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaf<'info>>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::execute_relayer_refund_leaf(ctx, RefundMode::Hybrid)
+    }
+
+    /// Executes multiple relayer refund leaves of the same mint from the same root bundle with a single multiproof.
+    ///
+    /// This is equivalent to calling execute_relayer_refund_leaf for each of the leaves, but verifies all of them
+    /// against the relayer refund root with one OpenZeppelin style multiproof (see MerkleProof.multiProofVerify), so
+    /// the shared parts of the proof are passed only once. ExecutedRelayerRefundRoot and, for non-zero
+    /// amount_to_return, TokensBridged events are emitted for each executed leaf. The whole batch fails if any of the
+    /// leaves cannot be executed. Note that leaves whose subtrees are carried up without hashing in odd sized tree
+    /// layers might not be provable together in a multiproof.
+    ///
+    /// instruction_params Parameters:
+    /// - root_bundle_id: The ID of the root bundle containing the relayer refund root.
+    /// - relayer_refund_leaves: The relayer refund leaves to be executed, in the order expected by the multiproof. See
+    ///   execute_relayer_refund_leaf for their contents. All leaves must refund the same mint.
+    /// - proof: Multiproof elements needed to rebuild the relayer refund root from the leaves.
+    /// - proof_flags: For each hashing step of the multiproof, whether it consumes the next leaf or computed hash
+    ///   (true) or the next proof element (false).
+    ///
+    /// ### Required Accounts:
+    /// Same as for execute_relayer_refund_leaf, except that instruction_params holds the parameters above. The
+    /// remaining accounts hold the refund accounts of each leaf, in the same order as the leaves and laid out the same
+    /// as for a single leaf in the respective refund mode.
+    ///
+    /// execute_relayer_refund_leaves executes in mode where refunds are sent to ATA directly.
+    pub fn execute_relayer_refund_leaves<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaves<'info>>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::execute_relayer_refund_leaves(ctx, RefundMode::Direct)
+    }
+
+    /// Similar to execute_relayer_refund_leaves, but executes in mode where refunds are allocated to claim_account
+    /// PDAs.
+    pub fn execute_relayer_refund_leaves_deferred<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaves<'info>>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::execute_relayer_refund_leaves(ctx, RefundMode::Deferred)
+    }
+
+    /// Similar to execute_relayer_refund_leaves, but executes in hybrid mode as described for
+    /// execute_relayer_refund_leaf_hybrid.
+    pub fn execute_relayer_refund_leaves_hybrid<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteRelayerRefundLeaves<'info>>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::execute_relayer_refund_leaves(ctx, RefundMode::Hybrid)
+    }
+
+    /// Refunds the depositor on this chain for an expired deposit that was not filled on the destination chain.
+    ///
+    /// The origin refund leaf is verified against the origin refund typed root of the root bundle and can only be executed
+    /// after its fill_deadline has passed. Anyone can execute the leaf, but the refund is always sent to the depositor's
+    /// ATA. The origin_refund_claim PDA is created on execution so the same deposit cannot be refunded again.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The caller that pays for the origin_refund_claim PDA. No permission requirements.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Account): The root bundle PDA containing the origin refund root.
+    ///   Seed: ["root_bundle",state.seed,root_bundle_id].
+    /// - origin_refund_claim (Writable): The PDA marking the deposit as refunded.
+    ///   Seed: ["origin_refund",state.seed,deposit_id].
+    /// - vault (Writable): The ATA for the refunded mint. Authority must be the state.
+    /// - mint (Account): The mint of the refunded token.
+    /// - depositor_token_account (Writable): The depositor's ATA for the refunded mint.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - token_program (Interface): The token program.
+    /// - system_program (Program): The system program.
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle containing the origin refund root.
+    /// - origin_refund_leaf: Struct containing the origin refund data:
+    ///   - chain_id: This spoke's chain ID.
+    ///   - deposit_id: The deposit ID of the expired deposit.
+    ///   - depositor: The depositor to refund.
+    ///   - mint: The mint of the refunded token.
+    ///   - amount: The amount to refund.
+    ///   - fill_deadline: The fill deadline of the expired deposit.
+    /// - proof: Merkle proof of the origin refund leaf.
+    pub fn execute_origin_refund_leaf(
+        ctx: Context<ExecuteOriginRefundLeaf>,
+        root_bundle_id: u32,
+        origin_refund_leaf: OriginRefundLeaf,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::execute_origin_refund_leaf(ctx, root_bundle_id, origin_refund_leaf, proof)
+    }
+
+    /// Executes a leaf of a generic payout tree, sending the leaf amount from the vault to the recipient's ATA.
+    ///
+    /// The payout leaf is verified against the typed root of root_type in the root bundle. The leaf hash is prefixed
+    /// with 64 zero bytes, the payout leaf domain separator and the root type, so leaves of different payout
+    /// categories can never be executed against each other. Anyone can execute the leaf.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The caller that pays for extending typed_root_claims. No permission requirements.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Account): The root bundle PDA containing the typed root.
+    ///   Seed: ["root_bundle",state.seed,root_bundle_id].
+    /// - typed_root_claims (Writable): The PDA tracking claimed leaves of the typed root.
+    ///   Seed: ["typed_root_claims",state.seed,root_bundle_id,root_type].
+    /// - vault (Writable): The ATA for the paid out mint. Authority must be the state.
+    /// - mint (Account): The mint of the paid out token.
+    /// - recipient_token_account (Writable): The recipient's ATA for the paid out mint.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - token_program (Interface): The token program.
+    /// - system_program (Program): The system program.
+    ///
+    /// ### Parameters:
+    /// - root_bundle_id: Index of the root bundle containing the typed root.
+    /// - root_type: Type of the payout root. Cannot be the origin refund root type.
+    /// - payout_leaf: Struct containing the payout data:
+    ///   - chain_id: This spoke's chain ID.
+    ///   - leaf_id: Index of the leaf in the claimed bitmap.
+    ///   - mint: The mint of the paid out token.
+    ///   - recipient: The recipient of the payout.
+    ///   - amount: The amount to pay out.
+    /// - proof: Merkle proof of the payout leaf.
+    pub fn execute_payout_leaf(
+        ctx: Context<ExecutePayoutLeaf>,
+        root_bundle_id: u32,
+        root_type: u8,
+        payout_leaf: PayoutLeaf,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::execute_payout_leaf(ctx, root_bundle_id, root_type, payout_leaf, proof)
     }
 
     /// Bridges tokens to the Hub Pool.
@@ -663,22 +1797,25 @@ pub mod svm_spoke {
     /// - state (Writable): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
     /// - fill_status (Writable): The fill status PDA, created on this function call. Updated to track slow fill status.
     ///   Used to prevent double request and fill. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): Optional compressed fill status bucket, same as in fill_relay.
     /// - system_program (Interface): The system program.
     ///
     /// ### Parameters:
-    /// - _relay_hash: The hash identifying the deposit to be filled. Caller must pass this in. Computed as hash of
+    /// - relay_hash: The hash identifying the deposit to be filled. Caller must pass this in. Computed as hash of
     ///   the flattened relay_data & destination_chain_id.
     /// - relay_data: Struct containing all the data needed to identify the deposit that should be slow filled. If any
     ///   of the params are missing or different from the origin chain deposit, then Across will not include a slow
     ///   fill for the intended deposit. See fill_relay & RelayData struct for more details.
     /// Note: relay_data is optional parameter. If None for it is passed, the caller must load it via the
     /// instruction_params account.
+    /// Note: relays with fill deadlines at or after the compressed fill status cutover require the fill_status_bucket
+    /// account, or can use request_slow_fill_compressed instead.
     pub fn request_slow_fill(
         ctx: Context<RequestSlowFill>,
-        _relay_hash: [u8; 32],
+        relay_hash: [u8; 32],
         relay_data: Option<RelayData>,
     ) -> Result<()> {
-        instructions::request_slow_fill(ctx, relay_data)
+        instructions::request_slow_fill(ctx, relay_hash, relay_data)
     }
 
     /// Executes a slow relay leaf stored as part of a root bundle relayed by the HubPool.
@@ -689,7 +1826,8 @@ pub mod svm_spoke {
     /// are sent directly out of this program's vault.
     ///
     /// ### Required Accounts:
-    /// - signer (Signer): The account that authorizes the execution. No permission requirements.
+    /// - signer (Signer): The account that authorizes the execution. No permission requirements. Pays for the bucket
+    ///   entry of relays past the compressed fill status cutover if not yet tracked.
     /// - instruction_params (Account): Optional account to load instruction parameters when they are not passed in the
     ///   instruction data due to message size constraints. Pass this program ID to represent None. When Some, this must
     ///   be derived from the signer's public key with seed ["instruction_params",signer].
@@ -697,14 +1835,17 @@ pub mod svm_spoke {
     /// - root_bundle (Account): Root bundle PDA with slowRelayRoot. Seed: ["root_bundle",state.seed,root_bundle_id].
     /// - fill_status (Writable): The fill status PDA, created when slow request was made. Updated to track slow fill.
     ///   Used to prevent double request and fill. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): Optional compressed fill status bucket, same as in fill_relay.
     /// - mint (Account): The mint account for the output token.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
     /// - recipient_token_account (Writable): The recipient's ATA for the output token.
     /// - vault (Writable): The ATA for refunded mint. Authority must be the state.
     /// - token_program (Interface): The token program.
     /// - system_program (Program): The system program.
     ///
     /// ### Parameters:
-    /// - _relay_hash: The hash identifying the deposit to be filled. Used to identify the deposit to be filled.
+    /// - relay_hash: The hash identifying the deposit to be filled. Used to identify the deposit to be filled.
     /// - slow_fill_leaf: Contains all data necessary to uniquely verify the slow fill. This struct contains:
     ///     - relayData: Struct containing all the data needed to identify the original deposit to be slow filled. Same
     ///       as the relay_data struct in fill_relay().
@@ -722,12 +1863,115 @@ pub mod svm_spoke {
     /// is passed as [u8; 32] array.
     pub fn execute_slow_relay_leaf<'info>(
         ctx: Context<'_, '_, '_, 'info, ExecuteSlowRelayLeaf<'info>>,
-        _relay_hash: [u8; 32],
+        relay_hash: [u8; 32],
         slow_fill_leaf: Option<SlowFill>,
         _root_bundle_id: Option<u32>,
         proof: Option<Vec<[u8; 32]>>,
     ) -> Result<()> {
-        instructions::execute_slow_relay_leaf(ctx, slow_fill_leaf, proof)
+        instructions::execute_slow_relay_leaf(ctx, relay_hash, slow_fill_leaf, proof)
+    }
+
+    /// Executes multiple slow relay leaves from the same root bundle with a single multiproof.
+    ///
+    /// This is equivalent to calling execute_slow_relay_leaf for each of the leaves, but verifies all of them against
+    /// the slow relay root with one OpenZeppelin style multiproof (see MerkleProof.multiProofVerify), so the shared
+    /// parts of the proof are passed only once. All leaves in the batch must have the same output token and empty
+    /// messages, as the remaining accounts are used to pass per-leaf accounts. One FilledRelay event is emitted for each
+    /// executed leaf. The whole batch fails if any of the leaves cannot be executed. Note that leaves whose subtrees are
+    /// carried up without hashing in odd sized tree layers might not be provable together in a multiproof.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the execution. No permission requirements. Pays for the bucket
+    ///   entries of relays past the compressed fill status cutover if not yet tracked.
+    /// - instruction_params (Account): Optional account to load instruction parameters when they are not passed in the
+    ///   instruction data due to message size constraints. Pass this program ID to represent None. When Some, this must
+    ///   be derived from the signer's public key with seed ["instruction_params",signer].
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Account): Root bundle PDA with slowRelayRoot. Seed: ["root_bundle",state.seed,root_bundle_id].
+    /// - mint (Account): The mint of the output token of all slow fill leaves.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - vault (Writable): The ATA for the output token. Authority must be the state.
+    /// - token_program (Interface): The token program.
+    /// - system_program (Program): The system program.
+    /// - remaining_accounts: For each slow fill leaf, in the same order, the recipient's ATA for the output token
+    ///   (Writable) followed by the fill status PDA (Writable) with seed ["fills",relay_hash]. Fill status PDAs must
+    ///   have been created when the slow fill was requested. Relays past the compressed fill status cutover are also
+    ///   followed by their fill status bucket (Writable), same as in fill_relay.
+    ///
+    /// ### Parameters:
+    /// - _root_bundle_id: Unique ID of root bundle containing slow relay root that the leaves are contained in.
+    /// - slow_fill_leaves: Slow fill leaves to execute, in the order expected by the multiproof. See
+    ///   execute_slow_relay_leaf for details.
+    /// - proof: Multiproof elements needed to rebuild the slow relay root from the leaves.
+    /// - proof_flags: For each hashing step of the multiproof, whether it consumes the next leaf or computed hash (true)
+    ///   or the next proof element (false).
+    /// Note: _root_bundle_id, slow_fill_leaves, proof, and proof_flags are optional parameters. If None for any of these
+    /// is passed, the caller must load them via the instruction_params account.
+    pub fn execute_slow_relay_leaves<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteSlowRelayLeaves<'info>>,
+        _root_bundle_id: Option<u32>,
+        slow_fill_leaves: Option<Vec<SlowFill>>,
+        proof: Option<Vec<[u8; 32]>>,
+        proof_flags: Option<Vec<bool>>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::execute_slow_relay_leaves(ctx, _root_bundle_id, slow_fill_leaves, proof, proof_flags)
+    }
+
+    /// Equivalent to request_slow_fill, except that the slow fill request is tracked in a compressed FillStatusBucket
+    /// as in fill_relay_compressed. This is only supported for relays with fill deadlines at or after the compressed
+    /// fill status cutover.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the slow fill request and pays for the bucket entry.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - legacy_fill_status (Account): The fill status PDA that must not exist. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): The compressed fill status bucket, created if needed. Seed: ["fill_status_bucket",
+    ///   origin_chain_id,fill_deadline / 3600,relay_hash[0] % 16].
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, relay_data: Same as in request_slow_fill, but relay_data is not optional.
+    pub fn request_slow_fill_compressed(
+        ctx: Context<RequestSlowFillCompressed>,
+        relay_hash: [u8; 32],
+        relay_data: RelayData,
+    ) -> Result<()> {
+        instructions::request_slow_fill_compressed(ctx, relay_hash, relay_data)
+    }
+
+    /// Equivalent to execute_slow_relay_leaf, except that the fill status is tracked in a compressed FillStatusBucket
+    /// as in fill_relay_compressed. This is only supported for relays with fill deadlines at or after the compressed
+    /// fill status cutover and does not require the slow fill to have been requested with request_slow_fill_compressed.
+    ///
+    /// ### Required Accounts:
+    /// - signer (Signer): The account that authorizes the execution and pays for the bucket entry if not yet tracked.
+    /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet.
+    /// - root_bundle (Account): Root bundle PDA with slowRelayRoot. Seed: ["root_bundle",state.seed,root_bundle_id].
+    /// - legacy_fill_status (Account): The fill status PDA that must not exist. Seed: ["fills",relay_hash].
+    /// - fill_status_bucket (Writable): The compressed fill status bucket, created if needed. Seed: ["fill_status_bucket",
+    ///   origin_chain_id,fill_deadline / 3600,relay_hash[0] % 16].
+    /// - mint (Account): The mint of the output token.
+    /// - token_limits (Writable): Token limits PDA, only enforced if set for the mint.
+    ///   Seed: ["token_limits",state.seed,mint].
+    /// - recipient_token_account (Writable): The recipient's ATA.
+    /// - vault (Writable): The ATA of the state that holds the slow fill funds.
+    /// - token_program (Interface): The token program.
+    /// - system_program (Interface): The system program.
+    ///
+    /// ### Parameters:
+    /// - relay_hash, slow_fill_leaf, root_bundle_id, proof: Same as in execute_slow_relay_leaf, but are not optional.
+    pub fn execute_slow_relay_leaf_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteSlowRelayLeafCompressed<'info>>,
+        relay_hash: [u8; 32],
+        slow_fill_leaf: SlowFill,
+        _root_bundle_id: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::execute_slow_relay_leaf_compressed(ctx, relay_hash, slow_fill_leaf, proof)
     }
 
     // **************************************
@@ -738,7 +1982,15 @@ pub mod svm_spoke {
     ///
     /// This function serves as the permissioned entry point for messages sent from the Ethereum mainnet to the Solana
     /// SVM Spoke program over CCTP. It processes the incoming message by translating it into a corresponding Solana
-    /// instruction and then invokes the instruction within this program.
+    /// instruction and then invokes the instruction within this program. Messages from the cross_domain_admin on the
+    /// primary remote_domain can invoke any supported instruction. Messages from additional remote admins registered
+    /// with set_remote_admin can only invoke instructions corresponding to their permitted selectors.
+    ///
+    /// Admin calls can be wrapped in relaySequencedMessage(uint64,bytes) together with a nonce. The last applied nonce
+    /// of sequenced messages is tracked per remote domain and sequenced messages with a nonce that is not greater are
+    /// ignored, so that delayed messages cannot be applied after more recent ones. Unwrapped messages are applied
+    /// without ordering. Each applied message emits AppliedRemoteMessage, while each ignored message emits
+    /// IgnoredRemoteMessage.
     ///
     /// ### Required Accounts:
     /// - authority_pda: A signer account that ensures this instruction can only be called by the Message Transmitter.
@@ -746,9 +1998,15 @@ pub mod svm_spoke {
     ///   seed:["message_transmitter_authority", program_id]
     /// - state (Account): Spoke state PDA. Seed: ["state",state.seed] where seed is 0 on mainnet. Enforces that the
     ///   remote domain and sender are valid.
-    /// - self_authority: An unchecked account used for authenticating self-CPI invoked by the received message.
-    ///   seed: ["self_authority"].
-    /// - program: The SVM Spoke program account.
+    /// - remote_nonce (Writable): Tracks the nonce of the last sequenced admin message applied from the remote domain.
+    ///   Seed: ["remote_nonce",state.seed,remote_domain].
+    /// - self_authority: An unchecked account used for authenticating self-CPI invoked by the message from the
+    ///   cross_domain_admin. seed: ["self_authority"].
+    /// - remote_admin_authority: An unchecked account used for authenticating self-CPI invoked by the message from
+    ///   additional remote admins, so that they cannot call instructions restricted to the cross_domain_admin.
+    ///   seed: ["remote_admin_authority"].
+    /// - remaining_accounts: Accounts passed to the invoked instruction. For messages from additional remote admins,
+    ///   these must be preceded by the remote_admin PDA. Seed: ["remote_admin",state.seed,remote_domain,sender].
     ///
     /// ### Parameters:
     /// - params: Contains information to process the received message, containing the following fields:
@@ -756,10 +2014,13 @@ pub mod svm_spoke {
     ///     - sender: The sender of the message.
     ///     - message_body: The body of the message.
     ///     - authority_bump: The authority bump for the message transmitter.
-    pub fn handle_receive_message<'info>(
-        ctx: Context<'_, '_, '_, 'info, HandleReceiveMessage<'info>>,
+    pub fn handle_receive_message<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, HandleReceiveMessage<'info>>,
         params: HandleReceiveMessageParams,
-    ) -> Result<()> {
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
         instructions::handle_receive_message(ctx, params)
     }
 
@@ -1,4 +1,15 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer},
+    Discriminator,
+};
+
+use crate::{
+    common::RelayData,
+    constants::{DISCRIMINATOR_SIZE, FILL_STATUS_BUCKET_DURATION, FILL_STATUS_BUCKET_SHARDS},
+    error::SvmError,
+    state::State,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq)]
 pub enum FillStatus {
@@ -14,3 +25,410 @@ pub struct FillStatusAccount {
     pub relayer: Pubkey,    // Address of the relayer that made the fill to control who can close this PDA.
     pub fill_deadline: u32, // Stores the fill deadline to control when this PDA can be safely closed.
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct FillStatusEntry {
+    pub relay_hash: [u8; 32],
+    pub status: FillStatus, // Either RequestedSlowFill or Filled as unfilled relays are not tracked.
+    pub funder: Pubkey,     // Funded the rent of this entry and receives it back when the bucket is closed.
+}
+
+// Compressed alternative to FillStatusAccount PDAs that tracks fill statuses of all relays from the same origin chain with
+// fill deadlines in the same time bucket and relay hashes in the same shard. Seed: ["fill_status_bucket",
+// origin_chain_id, bucket_id, shard].
+// Note: Entries are never deserialized, but accessed in place, so that the bucket can grow up to the maximum account
+// size with each new entry funded by its payer.
+#[account]
+#[derive(InitSpace)]
+pub struct FillStatusBucket {
+    pub origin_chain_id: u64, // Origin chain of all tracked relays.
+    pub bucket_id: u32,       // Fill deadline of all tracked relays divided by FILL_STATUS_BUCKET_DURATION.
+    pub shard: u8,            // First byte of all tracked relay hashes modulo FILL_STATUS_BUCKET_SHARDS.
+    pub payer: Pubkey,        // Funded the rent of the empty bucket and receives it back when the bucket is closed.
+    #[max_len(0)]
+    pub entries: Vec<FillStatusEntry>, // Sorted by relay hash and grows by one entry for each tracked relay.
+}
+
+impl<'info> FillStatusBucket {
+    pub fn get_bucket_id(fill_deadline: u32) -> u32 {
+        fill_deadline / FILL_STATUS_BUCKET_DURATION
+    }
+
+    pub fn get_shard(relay_hash: &[u8; 32]) -> u8 {
+        relay_hash[0] % FILL_STATUS_BUCKET_SHARDS
+    }
+
+    pub fn space(entry_count: usize) -> usize {
+        DISCRIMINATOR_SIZE + Self::INIT_SPACE + entry_count * FillStatusEntry::INIT_SPACE
+    }
+
+    // No relay in the bucket can be filled anymore once fill deadlines of the whole bucket have passed.
+    pub fn is_expired(&self, current_time: u32) -> bool {
+        (self.bucket_id as u64 + 1) * FILL_STATUS_BUCKET_DURATION as u64 <= current_time as u64
+    }
+
+    // Loads the bucket fields with owner and discriminator checks, leaving out its entries that are accessed in place.
+    pub fn try_load_header(account_info: &AccountInfo) -> Result<FillStatusBucket> {
+        let data = Self::try_borrow_checked_data(account_info)?;
+        let mut header = &data[DISCRIMINATOR_SIZE..];
+
+        Ok(FillStatusBucket {
+            origin_chain_id: u64::deserialize(&mut header)?,
+            bucket_id: u32::deserialize(&mut header)?,
+            shard: u8::deserialize(&mut header)?,
+            payer: Pubkey::deserialize(&mut header)?,
+            entries: Vec::new(),
+        })
+    }
+
+    pub fn get_entry_count(account_info: &AccountInfo) -> Result<usize> {
+        Ok(Self::read_entry_count(&Self::try_borrow_checked_data(account_info)?))
+    }
+
+    // Removes all entries funded by the funder, returning their rent to the funder. Removed entries keep the order of
+    // the remaining entries, so they stay sorted. This must only be called on expired buckets.
+    pub fn close_funder_entries(account_info: &AccountInfo<'info>, funder: &AccountInfo<'info>) -> Result<()> {
+        let (entry_count, kept_count) = {
+            let mut data = Self::try_borrow_checked_data(account_info)?;
+            let entry_count = Self::read_entry_count(&data);
+            let mut kept_count = 0;
+            for i in 0..entry_count {
+                let entry_offset = Self::space(i);
+                if &data[entry_offset + FUNDER_OFFSET..entry_offset + FillStatusEntry::INIT_SPACE]
+                    != funder.key.as_ref()
+                {
+                    data.copy_within(entry_offset..entry_offset + FillStatusEntry::INIT_SPACE, Self::space(kept_count));
+                    kept_count += 1;
+                }
+            }
+            Self::write_entry_count(&mut data, kept_count);
+            (entry_count, kept_count)
+        };
+
+        if kept_count == entry_count {
+            return Ok(());
+        }
+        if !funder.is_writable {
+            return Err(Error::from(ErrorCode::ConstraintMut));
+        }
+
+        let rent = Rent::get()?;
+        let refund = rent.minimum_balance(Self::space(entry_count)) - rent.minimum_balance(Self::space(kept_count));
+        account_info.realloc(Self::space(kept_count), false)?;
+        account_info.sub_lamports(refund)?;
+        funder.add_lamports(refund)?;
+
+        Ok(())
+    }
+
+    // Follows Anchor's close logic as the bucket is not loaded as an Anchor account.
+    pub fn close(account_info: &AccountInfo<'info>, payer: &AccountInfo<'info>) -> Result<()> {
+        payer.add_lamports(account_info.lamports())?;
+        account_info.sub_lamports(account_info.lamports())?;
+        account_info.assign(&system_program::ID);
+        account_info.realloc(0, false)?;
+
+        Ok(())
+    }
+
+    // Relays that are not tracked in the bucket have not been filled nor had slow fill requested.
+    fn get_status(account_info: &AccountInfo, relay_hash: &[u8; 32]) -> Result<FillStatus> {
+        if account_info.owner == &system_program::ID {
+            return Ok(FillStatus::Unfilled);
+        }
+
+        let data = Self::try_borrow_checked_data(account_info)?;
+        match Self::find_entry(&data, relay_hash) {
+            Ok(index) => Ok(FillStatus::deserialize(&mut &data[Self::space(index) + STATUS_OFFSET..])?),
+            Err(_) => Ok(FillStatus::Unfilled),
+        }
+    }
+
+    // Updates the status of an already tracked relay or inserts a new entry, initializing the bucket if needed. New
+    // entries grow the account with the payer funding the additional rent. Bucket address is checked by the caller.
+    fn set_status(
+        account_info: &AccountInfo<'info>,
+        bump: u8,
+        relay_hash: &[u8; 32],
+        relay_data: &RelayData,
+        status: FillStatus,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        if !account_info.is_writable {
+            return Err(Error::from(ErrorCode::ConstraintMut));
+        }
+
+        if account_info.owner == &system_program::ID {
+            let origin_chain_id_bytes = relay_data.origin_chain_id.to_le_bytes();
+            let bucket_id_bytes = Self::get_bucket_id(relay_data.fill_deadline).to_le_bytes();
+            let signer_seeds: &[&[u8]] = &[
+                b"fill_status_bucket",
+                origin_chain_id_bytes.as_ref(),
+                bucket_id_bytes.as_ref(),
+                &[Self::get_shard(relay_hash)],
+                &[bump],
+            ];
+            init_pda(account_info, Self::space(0), signer_seeds, payer, system_program)?;
+
+            let bucket = FillStatusBucket {
+                origin_chain_id: relay_data.origin_chain_id,
+                bucket_id: Self::get_bucket_id(relay_data.fill_deadline),
+                shard: Self::get_shard(relay_hash),
+                payer: payer.key(),
+                entries: Vec::new(),
+            };
+            bucket.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+        }
+
+        let (entry_count, insert_index) = {
+            let mut data = Self::try_borrow_checked_data(account_info)?;
+            match Self::find_entry(&data, relay_hash) {
+                Ok(index) => {
+                    data[Self::space(index) + STATUS_OFFSET] = status as u8;
+                    return Ok(());
+                }
+                Err(index) => (Self::read_entry_count(&data), index),
+            }
+        };
+
+        let space = Self::space(entry_count + 1);
+        let required_lamports = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account_info.lamports());
+        if required_lamports > 0 {
+            let transfer_accounts = Transfer { from: payer.to_account_info(), to: account_info.to_account_info() };
+            let cpi_context = CpiContext::new(system_program.to_account_info(), transfer_accounts);
+            transfer(cpi_context, required_lamports)?;
+        }
+        account_info.realloc(space, false)?;
+
+        // Shifts the following entries to keep all entries sorted by relay hash.
+        let mut data = account_info.try_borrow_mut_data()?;
+        let entry_offset = Self::space(insert_index);
+        data.copy_within(entry_offset..Self::space(entry_count), entry_offset + FillStatusEntry::INIT_SPACE);
+        data[entry_offset..entry_offset + STATUS_OFFSET].copy_from_slice(relay_hash);
+        data[entry_offset + STATUS_OFFSET] = status as u8;
+        data[entry_offset + FUNDER_OFFSET..entry_offset + FillStatusEntry::INIT_SPACE]
+            .copy_from_slice(payer.key.as_ref());
+        Self::write_entry_count(&mut data, entry_count + 1);
+
+        Ok(())
+    }
+
+    fn try_borrow_checked_data<'a, 'b>(
+        account_info: &'a AccountInfo<'b>,
+    ) -> Result<std::cell::RefMut<'a, &'b mut [u8]>> {
+        if account_info.owner != &crate::ID {
+            return Err(
+                Error::from(ErrorCode::AccountOwnedByWrongProgram).with_pubkeys((*account_info.owner, crate::ID))
+            );
+        }
+
+        let data = account_info.try_borrow_mut_data()?;
+        if data.get(..DISCRIMINATOR_SIZE) != Some(FillStatusBucket::DISCRIMINATOR) {
+            return Err(Error::from(ErrorCode::AccountDiscriminatorMismatch));
+        }
+
+        Ok(data)
+    }
+
+    fn read_entry_count(data: &[u8]) -> usize {
+        let mut entry_count = [0u8; 4];
+        entry_count.copy_from_slice(&data[Self::space(0) - 4..Self::space(0)]);
+        u32::from_le_bytes(entry_count) as usize
+    }
+
+    fn write_entry_count(data: &mut [u8], entry_count: usize) {
+        data[Self::space(0) - 4..Self::space(0)].copy_from_slice(&(entry_count as u32).to_le_bytes());
+    }
+
+    // Binary searches the sorted entries, returning either the index of the relay entry or the index where it should be
+    // inserted.
+    fn find_entry(data: &[u8], relay_hash: &[u8; 32]) -> std::result::Result<usize, usize> {
+        let (mut low, mut high) = (0, Self::read_entry_count(data));
+        while low < high {
+            let mid = (low + high) / 2;
+            let entry_offset = Self::space(mid);
+            match data[entry_offset..entry_offset + STATUS_OFFSET].cmp(relay_hash.as_ref()) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+}
+
+// Offsets of fields within the serialized FillStatusEntry.
+const STATUS_OFFSET: usize = 32;
+const FUNDER_OFFSET: usize = 33;
+
+// Fill status of a single relay that is tracked in its FillStatusAccount PDA for relays before the compressed fill
+// status cutover, or in its FillStatusBucket otherwise. This is used by all fill methods, so that a relay can never be
+// filled once in each of them. Note: Account name should be appended to any possible errors by the caller when parsing
+// remaining accounts. Changes are written directly to the account data.
+pub struct RelayFillStatus<'a, 'info> {
+    pub status: FillStatus,
+    relay_hash: [u8; 32],
+    relay_data: &'a RelayData,
+    account_info: &'a AccountInfo<'info>, // Either the fill status PDA or the fill status bucket.
+    bump: u8,
+    compressed: bool,
+}
+
+impl<'a, 'info> RelayFillStatus<'a, 'info> {
+    // Fill status PDA must be derived from the relay hash, while the bucket is required only for relays past the
+    // cutover. Relays past the cutover must not have a fill status PDA unless it was migrated via migrate_fill_status.
+    pub fn load(
+        state: &State,
+        relay_hash: &[u8; 32],
+        relay_data: &'a RelayData,
+        fill_status: &'a AccountInfo<'info>,
+        fill_status_bucket: Option<&'a AccountInfo<'info>>,
+    ) -> Result<Self> {
+        let (pda_address, bump) = Pubkey::find_program_address(&[b"fills", relay_hash.as_ref()], &crate::ID);
+        if fill_status.key() != pda_address {
+            return Err(Error::from(ErrorCode::ConstraintSeeds).with_pubkeys((fill_status.key(), pda_address)));
+        }
+
+        if !state.uses_compressed_fill_status(relay_data.fill_deadline) {
+            let status = if fill_status.owner == &system_program::ID {
+                FillStatus::Unfilled
+            } else {
+                FillStatusAccount::try_deserialize(&mut &fill_status.try_borrow_data()?[..])?.status
+            };
+            return Ok(Self {
+                status,
+                relay_hash: *relay_hash,
+                relay_data,
+                account_info: fill_status,
+                bump,
+                compressed: false,
+            });
+        }
+
+        if fill_status.owner == &crate::ID {
+            return err!(SvmError::LegacyFillStatusExists);
+        }
+        let fill_status_bucket = fill_status_bucket.ok_or(SvmError::RelayUsesCompressedFillStatus)?;
+
+        Self::load_bucket(relay_hash, relay_data, fill_status_bucket)
+    }
+
+    // Loads the fill status from the relay bucket only, that is also used when migrating fill status PDAs.
+    pub fn load_bucket(
+        relay_hash: &[u8; 32],
+        relay_data: &'a RelayData,
+        fill_status_bucket: &'a AccountInfo<'info>,
+    ) -> Result<Self> {
+        let (bucket_address, bump) = Pubkey::find_program_address(
+            &[
+                b"fill_status_bucket",
+                relay_data.origin_chain_id.to_le_bytes().as_ref(),
+                FillStatusBucket::get_bucket_id(relay_data.fill_deadline)
+                    .to_le_bytes()
+                    .as_ref(),
+                &[FillStatusBucket::get_shard(relay_hash)],
+            ],
+            &crate::ID,
+        );
+        if fill_status_bucket.key() != bucket_address {
+            return Err(
+                Error::from(ErrorCode::ConstraintSeeds).with_pubkeys((fill_status_bucket.key(), bucket_address))
+            );
+        }
+
+        Ok(Self {
+            status: FillStatusBucket::get_status(fill_status_bucket, relay_hash)?,
+            relay_hash: *relay_hash,
+            relay_data,
+            account_info: fill_status_bucket,
+            bump,
+            compressed: true,
+        })
+    }
+
+    // Sets the new fill status, initializing its account if needed with the payer funding the rent. The relayer is only
+    // recorded in fill status PDAs and when not provided the PDA must already exist.
+    pub fn set(
+        &mut self,
+        status: FillStatus,
+        relayer: Option<Pubkey>,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        self.status = status.clone();
+
+        if self.compressed {
+            return FillStatusBucket::set_status(
+                self.account_info,
+                self.bump,
+                &self.relay_hash,
+                self.relay_data,
+                status,
+                payer,
+                system_program,
+            );
+        }
+
+        if !self.account_info.is_writable {
+            return Err(Error::from(ErrorCode::ConstraintMut));
+        }
+
+        let mut fill_status_account = if self.account_info.owner == &system_program::ID {
+            if relayer.is_none() {
+                return Err(Error::from(ErrorCode::AccountNotInitialized));
+            }
+            let signer_seeds: &[&[u8]] = &[b"fills", self.relay_hash.as_ref(), &[self.bump]];
+            let space = DISCRIMINATOR_SIZE + FillStatusAccount::INIT_SPACE;
+            init_pda(self.account_info, space, signer_seeds, payer, system_program)?;
+            FillStatusAccount { status: FillStatus::Unfilled, relayer: Pubkey::default(), fill_deadline: 0 }
+        } else {
+            FillStatusAccount::try_deserialize(&mut &self.account_info.try_borrow_data()?[..])?
+        };
+
+        fill_status_account.status = status;
+        if let Some(relayer) = relayer {
+            fill_status_account.relayer = relayer;
+            fill_status_account.fill_deadline = self.relay_data.fill_deadline;
+        }
+
+        fill_status_account.try_serialize(&mut &mut self.account_info.try_borrow_mut_data()?[..])
+    }
+}
+
+// This implements Anchor's init constraint for PDAs that also handles accounts that have been pre-funded with lamports.
+// Note: Newly created account has zeroed data without discriminator that must be written by the caller.
+fn init_pda<'info>(
+    account_info: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let signer_seeds = &[signer_seeds];
+    let current_lamports = account_info.lamports();
+    if current_lamports == 0 {
+        let create_accounts = CreateAccount { from: payer.to_account_info(), to: account_info.to_account_info() };
+        let cpi_context = CpiContext::new_with_signer(system_program.to_account_info(), create_accounts, signer_seeds);
+        create_account(cpi_context, rent_exempt_lamports, space as u64, &crate::ID)?;
+    } else {
+        let required_lamports = rent_exempt_lamports.saturating_sub(current_lamports);
+        if required_lamports > 0 {
+            let transfer_accounts = Transfer { from: payer.to_account_info(), to: account_info.to_account_info() };
+            let cpi_context = CpiContext::new(system_program.to_account_info(), transfer_accounts);
+            transfer(cpi_context, required_lamports)?;
+        }
+        let allocate_accounts = Allocate { account_to_allocate: account_info.to_account_info() };
+        let cpi_context =
+            CpiContext::new_with_signer(system_program.to_account_info(), allocate_accounts, signer_seeds);
+        allocate(cpi_context, space as u64)?;
+        let assign_accounts = Assign { account_to_assign: account_info.to_account_info() };
+        let cpi_context = CpiContext::new_with_signer(system_program.to_account_info(), assign_accounts, signer_seeds);
+        assign(cpi_context, &crate::ID)?;
+    }
+
+    Ok(())
+}
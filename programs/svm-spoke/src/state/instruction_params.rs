@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{RelayData, RelayerRefundLeaf, SlowFill};
+use crate::{RelayData, RelayerRefundLeaf, SlowFill, UpdatedDepositData};
 
 #[account]
 pub struct ExecuteRelayerRefundLeafParams {
@@ -9,6 +9,14 @@ pub struct ExecuteRelayerRefundLeafParams {
     pub proof: Vec<[u8; 32]>,                   // Proof to verify the leaf's inclusion in relayer refund merkle tree.
 }
 
+#[account]
+pub struct ExecuteRelayerRefundLeavesParams {
+    pub root_bundle_id: u32,
+    pub relayer_refund_leaves: Vec<RelayerRefundLeaf>,
+    pub proof: Vec<[u8; 32]>,   // Multiproof for all leaves in relayer refund merkle tree.
+    pub proof_flags: Vec<bool>, // Marks multiproof steps that hash leaves or computed hashes instead of proof elements.
+}
+
 #[account]
 pub struct FillRelayParams {
     pub relay_data: RelayData,
@@ -16,6 +24,22 @@ pub struct FillRelayParams {
     pub repayment_address: Pubkey,
 }
 
+#[account]
+pub struct FillRelayWithUpdatedDepositParams {
+    pub relay_data: RelayData,
+    pub repayment_chain_id: u64,
+    pub repayment_address: Pubkey,
+    pub updated_deposit_data: UpdatedDepositData,
+}
+
+#[account]
+pub struct FillRelaysBatchParams {
+    pub relay_hashes: Vec<[u8; 32]>,
+    pub relay_data: Vec<RelayData>,
+    pub repayment_chain_id: u64,
+    pub repayment_address: Pubkey,
+}
+
 #[account]
 pub struct RequestSlowFillParams {
     pub relay_data: RelayData,
@@ -27,3 +51,11 @@ pub struct ExecuteSlowRelayLeafParams {
     pub root_bundle_id: u32,
     pub proof: Vec<[u8; 32]>,
 }
+
+#[account]
+pub struct ExecuteSlowRelayLeavesParams {
+    pub root_bundle_id: u32,
+    pub slow_fill_leaves: Vec<SlowFill>,
+    pub proof: Vec<[u8; 32]>,   // Multiproof for all slow fill leaves in slow relay merkle tree.
+    pub proof_flags: Vec<bool>, // Marks multiproof steps that hash leaves or computed hashes instead of proof elements.
+}
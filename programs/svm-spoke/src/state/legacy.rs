@@ -0,0 +1,64 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    error::SvmError,
+    state::{RootBundle, State},
+};
+
+// Layouts of the State and RootBundle accounts as initially deployed, before new fields were appended to them. These
+// are only loaded by migrate_state and migrate_root_bundle that rewrite the accounts in their current layout.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct StateV0 {
+    pub paused_deposits: bool,
+    pub paused_fills: bool,
+    pub owner: Pubkey,
+    pub seed: u64,
+    pub number_of_deposits: u32,
+    pub chain_id: u64,
+    pub current_time: u32,
+    pub remote_domain: u32,
+    pub cross_domain_admin: Pubkey,
+    pub root_bundle_id: u32,
+    pub deposit_quote_time_buffer: u32,
+    pub fill_deadline_buffer: u32,
+}
+
+#[derive(AnchorDeserialize)]
+pub struct RootBundleV0 {
+    pub relayer_refund_root: [u8; 32],
+    pub slow_relay_root: [u8; 32],
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl StateV0 {
+    pub fn try_from_account_info(account_info: &AccountInfo) -> Result<Self> {
+        try_deserialize_legacy::<State, Self>(account_info)
+    }
+}
+
+impl RootBundleV0 {
+    pub fn try_from_account_info(account_info: &AccountInfo) -> Result<Self> {
+        try_deserialize_legacy::<RootBundle, Self>(account_info)
+    }
+}
+
+// Accounts in the current layout are rejected first, as they also start with the fields of the legacy layout.
+fn try_deserialize_legacy<T, L>(account_info: &AccountInfo) -> Result<L>
+where
+    T: AccountDeserialize + Discriminator,
+    L: AnchorDeserialize,
+{
+    if account_info.owner != &crate::ID {
+        return err!(ErrorCode::AccountOwnedByWrongProgram);
+    }
+
+    let data = account_info.try_borrow_data()?;
+    if T::try_deserialize(&mut &data[..]).is_ok() {
+        return err!(SvmError::AccountAlreadyMigrated);
+    }
+    if !data.starts_with(T::DISCRIMINATOR) {
+        return err!(ErrorCode::AccountDiscriminatorMismatch);
+    }
+
+    L::deserialize(&mut &data[T::DISCRIMINATOR.len()..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
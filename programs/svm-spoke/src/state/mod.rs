@@ -1,13 +1,31 @@
 pub mod fill;
 pub mod instruction_params;
+pub mod legacy;
+pub mod native_recipient;
+pub mod origin_refund;
 pub mod refund_account;
+pub mod relayer_profile;
+pub mod remote_admin;
+pub mod remote_nonce;
+pub mod role;
 pub mod root_bundle;
+pub mod route;
 pub mod state;
+pub mod token_limits;
 pub mod transfer_liability;
 
 pub use fill::*;
 pub use instruction_params::*;
+pub use legacy::*;
+pub use native_recipient::*;
+pub use origin_refund::*;
 pub use refund_account::*;
+pub use relayer_profile::*;
+pub use remote_admin::*;
+pub use remote_nonce::*;
+pub use role::*;
 pub use root_bundle::*;
+pub use route::*;
 pub use state::*;
+pub use token_limits::*;
 pub use transfer_liability::*;
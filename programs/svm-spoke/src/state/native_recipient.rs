@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct NativeRecipient {
+    pub enabled: bool, // Tracks if the recipient opted in to receive wSOL fills as native lamports.
+}
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct OriginRefundClaim {
+    pub root_bundle_id: u32, // Root bundle that refunded the deposit. Existence of this PDA blocks repeated refunds.
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RelayerProfile {
+    pub relayer: Pubkey,           // Relayer whose token accounts fund fills using this profile.
+    pub repayment_chain_id: u64,   // Default repayment chain for fills using this profile.
+    pub repayment_address: Pubkey, // Default repayment address for fills using this profile.
+    pub delegate: Pubkey,          // Additional signer allowed to fill using this profile. Zero if not set.
+}
+
+impl RelayerProfile {
+    pub fn is_allowed_signer(&self, signer: &Pubkey) -> bool {
+        *signer == self.relayer || (*signer == self.delegate && self.delegate != Pubkey::default())
+    }
+}
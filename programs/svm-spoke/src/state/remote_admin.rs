@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REMOTE_ADMIN_SELECTORS;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RemoteAdmin {
+    pub remote_domain: u32, // CCTP domain of the additional remote admin.
+    pub sender: Pubkey,     // Sender of CCTP messages on the remote domain.
+    #[max_len(MAX_REMOTE_ADMIN_SELECTORS)]
+    pub selectors: Vec<[u8; 4]>, // Solidity selectors that this remote admin is permitted to call.
+}
+
+// This implements the following Anchor account constraints when parsing remaining account as a remote admin account:
+// #[account(
+//     seeds = [b"remote_admin", state.seed.to_le_bytes().as_ref(), remote_domain.to_le_bytes().as_ref(), sender.as_ref()],
+//     bump
+// )]
+// pub remote_admin: Account<'info, RemoteAdmin>,
+// Returns None if the account is not the initialized remote admin PDA so that the caller can reject the sender.
+impl<'info> RemoteAdmin {
+    pub fn try_from(
+        account_info: &'info AccountInfo<'info>,
+        seed: u64,
+        remote_domain: u32,
+        sender: &Pubkey,
+    ) -> Option<Account<'info, RemoteAdmin>> {
+        let (pda_address, _bump) = Pubkey::find_program_address(
+            &[
+                b"remote_admin",
+                seed.to_le_bytes().as_ref(),
+                remote_domain.to_le_bytes().as_ref(),
+                sender.as_ref(),
+            ],
+            &crate::ID,
+        );
+        if account_info.key() != pda_address {
+            return None;
+        }
+
+        // Checks ownership on deserialization for the RemoteAdmin.
+        Account::try_from(account_info).ok()
+    }
+}
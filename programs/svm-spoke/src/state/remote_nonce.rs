@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RemoteNonce {
+    pub remote_domain: u32, // CCTP domain that admin messages are received from.
+    pub last_nonce: u64,    // Nonce of the last sequenced admin message applied from this remote domain.
+}
+
+// This implements the following Anchor account constraints when parsing the remote_nonce account, which can only be
+// done after the remote domain of the received message is authenticated:
+// #[account(
+//     mut,
+//     seeds = [b"remote_nonce", state.seed.to_le_bytes().as_ref(), remote_domain.to_le_bytes().as_ref()],
+//     bump
+// )]
+// pub remote_nonce: Account<'info, RemoteNonce>,
+// The nonce of the sequenced message is then stored and true is returned, unless it is not greater than the last
+// applied nonce. In that case false is returned so that the caller ignores the delayed admin message instead of
+// applying it after a more recent one from the same remote domain.
+impl RemoteNonce {
+    pub fn apply_nonce(account_info: &AccountInfo, seed: u64, remote_domain: u32, nonce: u64) -> Result<bool> {
+        let (pda_address, _bump) = Pubkey::find_program_address(
+            &[
+                b"remote_nonce",
+                seed.to_le_bytes().as_ref(),
+                remote_domain.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        if account_info.key() != pda_address {
+            return err!(ErrorCode::ConstraintSeeds);
+        }
+        if account_info.owner != &crate::ID {
+            return err!(ErrorCode::AccountNotInitialized);
+        }
+
+        let mut remote_nonce = RemoteNonce::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        if nonce <= remote_nonce.last_nonce {
+            return Ok(false);
+        }
+        remote_nonce.last_nonce = nonce;
+
+        remote_nonce.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+
+        Ok(true)
+    }
+}
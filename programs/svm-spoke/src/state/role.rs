@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BUNDLE_RELAYER_ROLE, EMERGENCY_ADMIN_ROLE, GUARDIAN_ROLE, PAUSER_ROLE};
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoleMember {
+    pub role: u8,       // Role granted to the member (one of the *_ROLE constants).
+    pub member: Pubkey, // Account that is permitted to call admin instructions requiring the role.
+}
+
+// This implements the following Anchor account constraints when parsing remaining account as a role member account:
+// #[account(
+//     seeds = [b"role_member", state.seed.to_le_bytes().as_ref(), &[role], member.as_ref()],
+//     bump
+// )]
+// pub role_member: Account<'info, RoleMember>,
+// Returns None if the account is not the initialized role member PDA so that the caller can reject the signer.
+impl<'info> RoleMember {
+    pub fn try_from(
+        account_info: &'info AccountInfo<'info>,
+        seed: u64,
+        role: u8,
+        member: &Pubkey,
+    ) -> Option<Account<'info, RoleMember>> {
+        let (pda_address, _bump) = Pubkey::find_program_address(
+            &[b"role_member", seed.to_le_bytes().as_ref(), &[role], member.as_ref()],
+            &crate::ID,
+        );
+        if account_info.key() != pda_address {
+            return None;
+        }
+
+        // Checks ownership on deserialization for the RoleMember.
+        Account::try_from(account_info).ok()
+    }
+
+    pub fn is_valid_role(role: u8) -> bool {
+        matches!(role, PAUSER_ROLE | BUNDLE_RELAYER_ROLE | EMERGENCY_ADMIN_ROLE | GUARDIAN_ROLE)
+    }
+}
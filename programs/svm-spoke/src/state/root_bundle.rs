@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, MAX_TYPED_ROOTS},
+    utils::get_bitmap_len,
+};
+
 #[account]
 #[derive(InitSpace)]
 pub struct RootBundle {
@@ -7,4 +12,57 @@ pub struct RootBundle {
     pub slow_relay_root: [u8; 32],     // Root of the slow relay merkle tree.
     #[max_len(1)]
     pub claimed_bitmap: Vec<u8>, // Dynamic sized vec to store claimed status of each relayer refund root leaf.
+    pub payer: Pubkey,                 // Paid rent when relaying the root bundle and receives it back when closed.
+    pub created_at: u32,               // Time when the root bundle was relayed.
+    pub leaf_count: Option<u32>,       // Number of relayer refund leaves, if provided when relaying the root bundle.
+    pub claimed_leaf_count: u32,       // Number of executed relayer refund leaves.
+    #[max_len(MAX_TYPED_ROOTS)]
+    pub typed_roots: Vec<TypedRoot>, // Additional roots keyed by root type, each with its own TypedRootClaims PDA.
+    pub pending_until: u32, // Time from which a locally relayed root bundle can be activated. 0 once it is active.
+}
+
+impl RootBundle {
+    // Account space with the claimed bitmap preallocated for all relayer refund leaves when the leaf count is known.
+    pub fn space(leaf_count: Option<u32>) -> usize {
+        let bitmap_len = get_bitmap_len(leaf_count.unwrap_or_default());
+
+        DISCRIMINATOR_SIZE + Self::INIT_SPACE + bitmap_len.saturating_sub(1)
+    }
+
+    pub fn is_leaf_id_valid(&self, leaf_id: u32) -> bool {
+        !matches!(self.leaf_count, Some(leaf_count) if leaf_id >= leaf_count)
+    }
+
+    // Leaves can only be executed against active root bundles.
+    pub fn is_active(&self) -> bool {
+        self.pending_until == 0
+    }
+
+    pub fn get_typed_root(&self, root_type: u8) -> Option<[u8; 32]> {
+        self.typed_roots
+            .iter()
+            .find(|typed_root| typed_root.root_type == root_type)
+            .map(|typed_root| typed_root.root)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RootBundleLeaves {
+    pub leaf_count: Option<u32>, // Number of relayer refund leaves, if provided when relaying the root bundle.
+    pub claimed_leaf_count: u32, // Number of executed relayer refund leaves.
+    pub claimed_bitmap: Vec<u8>, // Claimed status of each relayer refund leaf, indexed by leaf ID.
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct TypedRoot {
+    pub root_type: u8,
+    pub root: [u8; 32],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TypedRootClaims {
+    #[max_len(1)]
+    pub claimed_bitmap: Vec<u8>, // Dynamic sized vec to store claimed status of each typed root leaf.
+    pub payer: Pubkey, // Paid rent when relaying the typed root and receives it back when closed.
 }
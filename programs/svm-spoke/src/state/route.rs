@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Route {
+    pub enabled: bool, // Tracks if deposits of the origin token to the destination chain are enabled.
+}
@@ -15,4 +15,26 @@ pub struct State {
     pub root_bundle_id: u32,            // Tracks the next current root bundle id.
     pub deposit_quote_time_buffer: u32, // Deposit quote times can't be set more than this amount into the past/future.
     pub fill_deadline_buffer: u32,      // Fill deadlines can't be set more than this amount into the future.
+    // Superseded root bundles can be closed by anyone after this period since they were relayed.
+    pub root_bundle_close_grace_period: u32,
+    // Relays with fill deadlines from this time track fill status in FillStatusBucket accounts. 0 when not enabled.
+    pub compressed_fill_status_cutover: u32,
+    // Proposed owner that still has to accept the ownership transfer. Zero when there is no pending transfer.
+    pub pending_owner: Pubkey,
+    // Root bundles relayed by local signers stay pending for this period and can be vetoed remotely. 0 when not enabled.
+    pub root_bundle_timelock: u32,
+    // Tracks if execution of relayer refund, slow relay, origin refund and payout leaves is paused.
+    pub paused_refunds: bool,
+    // Tracks if claiming relayer refunds from claim accounts is paused.
+    pub paused_claims: bool,
+    // Tracks if bridging tokens back to the HubPool is paused.
+    pub paused_bridge: bool,
+}
+
+impl State {
+    // Each relay has its fill status tracked either in a FillStatusAccount PDA or in a compressed FillStatusBucket,
+    // depending only on its fill deadline, so that it can never be filled once in each of them.
+    pub fn uses_compressed_fill_status(&self, fill_deadline: u32) -> bool {
+        self.compressed_fill_status_cutover != 0 && fill_deadline >= self.compressed_fill_status_cutover
+    }
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SvmError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TokenLimits {
+    pub max_deposit_amount: u64, // Max input amount of a single deposit. 0 disables this limit.
+    pub max_deposit_volume: u64, // Max total input amount deposited within a window. 0 disables this limit.
+    pub max_outflow_volume: u64, // Max total amount of fills and, separately, of vault outflows within a window.
+    pub window_length: u32,      // Length of the volume tracking window in seconds.
+    pub window_start: u32,       // Start time of the current window.
+    pub deposit_volume: u64,     // Total input amount deposited within the current window.
+    pub outflow_volume: u64,     // Total amount of refunds and slow fills paid from the vault in the current window.
+    pub fill_volume: u64,        // Total amount of fills funded by relayers within the current window.
+}
+
+// Remaining capacity as reported by the get_token_limits_capacity view. Disabled limits are reported as u64::MAX.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TokenLimitsCapacity {
+    pub max_deposit_amount: u64,
+    pub remaining_deposit_volume: u64,
+    pub remaining_outflow_volume: u64,
+    pub remaining_fill_volume: u64,
+}
+
+impl TokenLimits {
+    // Starts a new window once the current one has elapsed, resetting tracked volumes.
+    fn roll_window(&mut self, current_time: u32) {
+        if current_time >= self.window_start.saturating_add(self.window_length) {
+            self.window_start = current_time;
+            self.deposit_volume = 0;
+            self.outflow_volume = 0;
+            self.fill_volume = 0;
+        }
+    }
+
+    fn remaining(max_volume: u64, volume: u64) -> u64 {
+        match max_volume {
+            0 => u64::MAX,
+            _ => max_volume.saturating_sub(volume),
+        }
+    }
+
+    pub fn record_deposit(&mut self, current_time: u32, amount: u64) -> Result<()> {
+        if self.max_deposit_amount != 0 && amount > self.max_deposit_amount {
+            return err!(SvmError::DepositAmountLimitExceeded);
+        }
+
+        self.roll_window(current_time);
+        if amount > Self::remaining(self.max_deposit_volume, self.deposit_volume) {
+            return err!(SvmError::DepositVolumeLimitExceeded);
+        }
+        self.deposit_volume = self.deposit_volume.saturating_add(amount);
+
+        Ok(())
+    }
+
+    // Outflows exceeding the whole max volume are still admitted once at the start of a window, so that refund and slow
+    // fill leaves larger than the limit are not blocked forever.
+    fn is_outflow_admitted(max_volume: u64, volume: u64, amount: u64) -> bool {
+        volume == 0 || amount <= Self::remaining(max_volume, volume)
+    }
+
+    // Tracks refunds and slow fills paid from the vault separately from relayer funded fills, so that relayers cannot
+    // consume the outflow volume and block leaf execution.
+    pub fn record_outflow(&mut self, current_time: u32, amount: u64) -> Result<()> {
+        self.roll_window(current_time);
+        if !Self::is_outflow_admitted(self.max_outflow_volume, self.outflow_volume, amount) {
+            return err!(SvmError::OutflowVolumeLimitExceeded);
+        }
+        self.outflow_volume = self.outflow_volume.saturating_add(amount);
+
+        Ok(())
+    }
+
+    pub fn record_fill(&mut self, current_time: u32, amount: u64) -> Result<()> {
+        self.roll_window(current_time);
+        if !Self::is_outflow_admitted(self.max_outflow_volume, self.fill_volume, amount) {
+            return err!(SvmError::FillVolumeLimitExceeded);
+        }
+        self.fill_volume = self.fill_volume.saturating_add(amount);
+
+        Ok(())
+    }
+
+    pub fn get_capacity(&self, current_time: u32) -> TokenLimitsCapacity {
+        let mut token_limits = self.clone();
+        token_limits.roll_window(current_time);
+
+        TokenLimitsCapacity {
+            max_deposit_amount: Self::remaining(token_limits.max_deposit_amount, 0),
+            remaining_deposit_volume: Self::remaining(token_limits.max_deposit_volume, token_limits.deposit_volume),
+            remaining_outflow_volume: Self::remaining(token_limits.max_outflow_volume, token_limits.outflow_volume),
+            remaining_fill_volume: Self::remaining(token_limits.max_outflow_volume, token_limits.fill_volume),
+        }
+    }
+
+    // Token limits are configured per mint by the owner. Callers must have checked the token_limits PDA address, so
+    // the account holds TokenLimits if it is owned by this program. Otherwise, limits were never set for the mint.
+    pub fn load(account_info: &AccountInfo) -> Result<Option<TokenLimits>> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(TokenLimits::try_deserialize(&mut &account_info.try_borrow_data()?[..])?))
+    }
+
+    // Applies the update to token limits if they were configured for the mint and stores them back to the account.
+    pub fn update(account_info: &AccountInfo, update: impl FnOnce(&mut TokenLimits) -> Result<()>) -> Result<()> {
+        if let Some(mut token_limits) = Self::load(account_info)? {
+            update(&mut token_limits)?;
+            token_limits.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+        }
+
+        Ok(())
+    }
+}
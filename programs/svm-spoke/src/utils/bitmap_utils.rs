@@ -1,3 +1,8 @@
+// Number of bytes needed to track claimed status of leaf_count leaves.
+pub fn get_bitmap_len(leaf_count: u32) -> usize {
+    leaf_count.div_ceil(8) as usize
+}
+
 pub fn is_claimed(claimed_bitmap: &Vec<u8>, index: u32) -> bool {
     let byte_index = (index / 8) as usize; // Index of the byte in the array
     if byte_index >= claimed_bitmap.len() {
@@ -61,6 +61,11 @@ pub fn get_self_authority_pda() -> Pubkey {
     pda_address
 }
 
+pub fn get_remote_admin_authority_pda() -> Pubkey {
+    let (pda_address, _bump) = Pubkey::find_program_address(&[b"remote_admin_authority"], &SvmSpoke::id());
+    pda_address
+}
+
 pub fn decode_solidity_uint32(data: &[u8; 32]) -> Result<u32> {
     let h_value = u128::from_be_bytes(data[..16].try_into().unwrap());
     let l_value = u128::from_be_bytes(data[16..].try_into().unwrap());
@@ -70,6 +75,15 @@ pub fn decode_solidity_uint32(data: &[u8; 32]) -> Result<u32> {
     Ok(l_value as u32)
 }
 
+pub fn decode_solidity_uint8(data: &[u8; 32]) -> Result<u8> {
+    let h_value = u128::from_be_bytes(data[..16].try_into().unwrap());
+    let l_value = u128::from_be_bytes(data[16..].try_into().unwrap());
+    if h_value > 0 || l_value > (u8::MAX as u128) {
+        return err!(CallDataError::InvalidUint8);
+    }
+    Ok(l_value as u8)
+}
+
 pub fn decode_solidity_uint64(data: &[u8; 32]) -> Result<u64> {
     let h_value = u128::from_be_bytes(data[..16].try_into().unwrap());
     let l_value = u128::from_be_bytes(data[16..].try_into().unwrap());
@@ -87,3 +101,62 @@ pub fn decode_solidity_address(data: &[u8; 32]) -> Result<Pubkey> {
     }
     Ok(Pubkey::new_from_array(*data))
 }
+
+pub fn decode_solidity_bytes32(data: &[u8; 32]) -> Result<Pubkey> {
+    Ok(Pubkey::new_from_array(*data))
+}
+
+pub fn decode_solidity_bytes4(data: &[u8; 32]) -> Result<[u8; 4]> {
+    // Fixed size bytes are left aligned and padded with zeroes.
+    if data[4..].iter().any(|&x| x != 0) {
+        return err!(CallDataError::InvalidBytes4);
+    }
+    Ok(<[u8; 4]>::try_from(&data[..4]).unwrap())
+}
+
+pub fn decode_solidity_uint256(data: &[u8; 32]) -> Result<[u8; 32]> {
+    // Big-endian encoded uint256 matches the representation of 32-byte amounts used in this program.
+    Ok(*data)
+}
+
+pub fn decode_solidity_int64(data: &[u8; 32]) -> Result<i64> {
+    // Negative values are sign extended with 0xff bytes, so all high bytes must match the sign of the low i64.
+    let value = i64::from_be_bytes(data[24..].try_into().unwrap());
+    let sign_byte = if value < 0 { 0xff } else { 0x00 };
+    if data[..24].iter().any(|&x| x != sign_byte) {
+        return err!(CallDataError::InvalidInt64);
+    }
+    Ok(value)
+}
+
+// Dynamic type arguments hold the offset of their length-prefixed contents relative to the start of arguments.
+fn get_solidity_dynamic_arg(data: &Vec<u8>, index: usize) -> Result<(usize, usize)> {
+    let offset = decode_solidity_uint32(&get_solidity_arg(data, index)?)? as usize;
+    let length_slice = data
+        .get(4 + offset..4 + offset + 32)
+        .ok_or(CallDataError::InvalidArgument)?;
+    let length = decode_solidity_uint32(&<[u8; 32]>::try_from(length_slice).unwrap())? as usize;
+
+    Ok((4 + offset + 32, length))
+}
+
+pub fn decode_solidity_bytes(data: &Vec<u8>, index: usize) -> Result<Vec<u8>> {
+    let (start, length) = get_solidity_dynamic_arg(data, index)?;
+    let slice = data.get(start..start + length).ok_or(CallDataError::InvalidArgument)?;
+    Ok(slice.to_vec())
+}
+
+pub fn decode_solidity_array<T>(
+    data: &Vec<u8>,
+    index: usize,
+    decode_element: fn(&[u8; 32]) -> Result<T>,
+) -> Result<Vec<T>> {
+    let (start, length) = get_solidity_dynamic_arg(data, index)?;
+    let slice = data
+        .get(start..start + length * 32)
+        .ok_or(CallDataError::InvalidArgument)?;
+    slice
+        .chunks_exact(32)
+        .map(|element| decode_element(&<[u8; 32]>::try_from(element).unwrap()))
+        .collect()
+}
@@ -43,3 +43,10 @@ pub struct FillSeedData {
     pub repayment_chain_id: u64,
     pub repayment_address: Pubkey,
 }
+
+#[derive(AnchorSerialize)]
+pub struct FillBatchSeedData<'a> {
+    pub relay_hashes: &'a Vec<[u8; 32]>,
+    pub repayment_chain_id: u64,
+    pub repayment_address: Pubkey,
+}
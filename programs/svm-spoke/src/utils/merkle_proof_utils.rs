@@ -25,6 +25,20 @@ pub fn verify_merkle_proof(root: [u8; 32], leaf: [u8; 32], proof: Vec<[u8; 32]>)
     Ok(())
 }
 
+pub fn verify_merkle_multi_proof(
+    root: [u8; 32],
+    leaves: &[[u8; 32]],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+) -> Result<()> {
+    let computed_root = process_multi_proof(proof, proof_flags, leaves)?;
+    if computed_root != root {
+        return err!(CommonError::InvalidMerkleProof);
+    }
+
+    Ok(())
+}
+
 // The following is the rust implementation of the merkle proof verification from OpenZeppelin that can be found here:
 // https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/contracts/utils/cryptography/MerkleProof.sol
 pub fn process_proof(proof: &[[u8; 32]], leaf: &[u8; 32]) -> [u8; 32] {
@@ -35,6 +49,57 @@ pub fn process_proof(proof: &[[u8; 32]], leaf: &[u8; 32]) -> [u8; 32] {
     computed_hash
 }
 
+// Rust implementation of the multiproof verification from the same OpenZeppelin MerkleProof library. Leaves must be in
+// the order they are consumed when rebuilding the tree bottom up and proof_flags mark whether each step hashes the next
+// leaf or computed hash (true) or the next proof element (false). Invalid multiproofs are reported as an error instead
+// of reverting on out of bounds access.
+pub fn process_multi_proof(proof: &[[u8; 32]], proof_flags: &[bool], leaves: &[[u8; 32]]) -> Result<[u8; 32]> {
+    if leaves.len() + proof.len() != proof_flags.len() + 1 {
+        return err!(CommonError::InvalidMerkleProof);
+    }
+
+    let mut hashes: Vec<[u8; 32]> = Vec::with_capacity(proof_flags.len());
+    let (mut leaf_pos, mut hash_pos, mut proof_pos) = (0, 0, 0);
+    for &proof_flag in proof_flags {
+        let a = next_multi_proof_node(leaves, &hashes, &mut leaf_pos, &mut hash_pos)?;
+        let b = if proof_flag {
+            next_multi_proof_node(leaves, &hashes, &mut leaf_pos, &mut hash_pos)?
+        } else {
+            proof_pos += 1;
+            *proof.get(proof_pos - 1).ok_or(CommonError::InvalidMerkleProof)?
+        };
+        hashes.push(commutative_keccak256(&a, &b));
+    }
+
+    match hashes.last() {
+        Some(root) if proof_pos == proof.len() => Ok(*root),
+        Some(_) => err!(CommonError::InvalidMerkleProof),
+        None => leaves
+            .first()
+            .or(proof.first())
+            .copied()
+            .ok_or(CommonError::InvalidMerkleProof.into()),
+    }
+}
+
+// Leaves are consumed before any of the hashes computed in the multiproof.
+fn next_multi_proof_node(
+    leaves: &[[u8; 32]],
+    hashes: &[[u8; 32]],
+    leaf_pos: &mut usize,
+    hash_pos: &mut usize,
+) -> Result<[u8; 32]> {
+    let (nodes, pos) = if *leaf_pos < leaves.len() {
+        (leaves, leaf_pos)
+    } else {
+        (hashes, hash_pos)
+    };
+    let node = *nodes.get(*pos).ok_or(CommonError::InvalidMerkleProof)?;
+    *pos += 1;
+
+    Ok(node)
+}
+
 // See https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/contracts/utils/cryptography/Hashes.sol
 fn commutative_keccak256(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
     if a < b {
@@ -4,6 +4,7 @@ pub mod delegate_utils;
 pub mod deposit_utils;
 pub mod merkle_proof_utils;
 pub mod message_utils;
+pub mod signature_utils;
 pub mod testable_utils;
 pub mod transfer_utils;
 
@@ -13,5 +14,6 @@ pub use delegate_utils::*;
 pub use deposit_utils::*;
 pub use merkle_proof_utils::*;
 pub use message_utils::*;
+pub use signature_utils::*;
 pub use testable_utils::*;
 pub use transfer_utils::*;
@@ -0,0 +1,178 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program, keccak,
+        secp256k1_recover::secp256k1_recover,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+use libsecp256k1::Signature as EVMSignature;
+
+use crate::error::{CommonError, SvmError};
+
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+pub const EVM_SIGNATURE_LENGTH: usize = 65;
+
+// Layout of the Ed25519 precompile instruction data: 1 byte for the number of signatures, 1 byte of padding and then
+// 14 bytes of offsets for each signature. See https://docs.anza.xyz/runtime/programs#ed25519-program
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+
+#[derive(AnchorSerialize)]
+pub struct UpdateDepositDetails<'a> {
+    pub deposit_id: [u8; 32],
+    pub origin_chain_id: u64,
+    pub updated_output_amount: [u8; 32],
+    pub updated_recipient: Pubkey,
+    pub updated_message: &'a Vec<u8>,
+}
+
+// Hash of the deposit update that the depositor signs with their Ed25519 key. Output amount is encoded as big-endian
+// 32-byte array, same as on the origin chain deposit, so that the same signature is valid on both origin and
+// destination chains.
+pub fn get_update_deposit_details_hash(update: &UpdateDepositDetails) -> [u8; 32] {
+    let mut data = Vec::new();
+    AnchorSerialize::serialize(update, &mut data).unwrap();
+    keccak::hash(&data).to_bytes()
+}
+
+// Verifies that the instruction immediately preceding the current one is the Ed25519 precompile verifying exactly one
+// signature of the expected signer over the expected message. The precompile fails the whole transaction on invalid
+// signatures, so here we only need to check that it verified the data we care about.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    signature: &[u8],
+    message: &[u8],
+) -> Result<()> {
+    if signature.len() != ED25519_SIGNATURE_LENGTH {
+        return err!(CommonError::InvalidDepositorSignature);
+    }
+
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let ed25519_index = current_index
+        .checked_sub(1)
+        .ok_or(SvmError::InvalidEd25519Instruction)?;
+    let ed25519_ix = load_instruction_at_checked(ed25519_index as usize, instructions_sysvar)?;
+    if ed25519_ix.program_id != ed25519_program::ID || !ed25519_ix.accounts.is_empty() {
+        return err!(SvmError::InvalidEd25519Instruction);
+    }
+
+    let data = &ed25519_ix.data;
+    if data.len() < ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE || data[0] != 1 {
+        return err!(SvmError::InvalidEd25519Instruction);
+    }
+
+    // All offsets must reference data within the Ed25519 instruction itself (instruction index u16::MAX).
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let signature_offset = read_u16(ED25519_OFFSETS_START) as usize;
+    let signature_ix_index = read_u16(ED25519_OFFSETS_START + 2);
+    let pubkey_offset = read_u16(ED25519_OFFSETS_START + 4) as usize;
+    let pubkey_ix_index = read_u16(ED25519_OFFSETS_START + 6);
+    let message_offset = read_u16(ED25519_OFFSETS_START + 8) as usize;
+    let message_size = read_u16(ED25519_OFFSETS_START + 10) as usize;
+    let message_ix_index = read_u16(ED25519_OFFSETS_START + 12);
+    if signature_ix_index != u16::MAX || pubkey_ix_index != u16::MAX || message_ix_index != u16::MAX {
+        return err!(SvmError::InvalidEd25519Instruction);
+    }
+
+    let verified_signature = data
+        .get(signature_offset..signature_offset + ED25519_SIGNATURE_LENGTH)
+        .ok_or(SvmError::InvalidEd25519Instruction)?;
+    let verified_pubkey = data
+        .get(pubkey_offset..pubkey_offset + ED25519_PUBKEY_SIZE)
+        .ok_or(SvmError::InvalidEd25519Instruction)?;
+    let verified_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(SvmError::InvalidEd25519Instruction)?;
+
+    if verified_pubkey != signer.as_ref() || verified_signature != signature || verified_message != message {
+        return err!(CommonError::InvalidDepositorSignature);
+    }
+
+    Ok(())
+}
+
+// EIP-712 parameters matching EVM SpokePool's EIP712CrossChainUpgradeable domain with the origin chain id.
+const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId)";
+const EIP712_DOMAIN_NAME: &str = "ACROSS-V2";
+const EIP712_DOMAIN_VERSION: &str = "1.0.0";
+const UPDATE_DEPOSIT_DETAILS_TYPE: &str = "UpdateDepositDetails(uint256 depositId,uint256 originChainId,\
+    uint256 updatedOutputAmount,bytes32 updatedRecipient,bytes updatedMessage)";
+
+// EVM depositors are stored as 20-byte addresses left padded with zeroes to 32 bytes.
+pub fn is_evm_address(address: &Pubkey) -> bool {
+    address.as_ref()[0..12].iter().all(|&x| x == 0)
+}
+
+fn encode_u64(value: u64) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[24..].copy_from_slice(&value.to_be_bytes());
+    encoded
+}
+
+// EIP-712 typed data hash of the deposit update as signed by EVM depositors when calling speedUpDeposit on the origin
+// chain. Domain separator uses the origin chain id so that the same signature is valid on any destination chain.
+pub fn get_evm_update_deposit_details_hash(update: &UpdateDepositDetails) -> [u8; 32] {
+    let domain_separator = keccak::hashv(&[
+        &keccak::hash(EIP712_DOMAIN_TYPE.as_bytes()).to_bytes(),
+        &keccak::hash(EIP712_DOMAIN_NAME.as_bytes()).to_bytes(),
+        &keccak::hash(EIP712_DOMAIN_VERSION.as_bytes()).to_bytes(),
+        &encode_u64(update.origin_chain_id),
+    ])
+    .to_bytes();
+
+    let struct_hash = keccak::hashv(&[
+        &keccak::hash(UPDATE_DEPOSIT_DETAILS_TYPE.as_bytes()).to_bytes(),
+        &update.deposit_id,
+        &encode_u64(update.origin_chain_id),
+        &update.updated_output_amount,
+        update.updated_recipient.as_ref(),
+        &keccak::hash(update.updated_message).to_bytes(),
+    ])
+    .to_bytes();
+
+    keccak::hashv(&[b"\x19\x01", &domain_separator, &struct_hash]).to_bytes()
+}
+
+// Recovers the EVM address of the signer left padded to 32 bytes. Based on the quote signer recovery in
+// sponsored-cctp-src-periphery that follows CCTP's `recover_attester` implementation.
+pub fn recover_evm_signer(hash: &[u8; 32], signature: &[u8]) -> Result<Pubkey> {
+    if signature.len() != EVM_SIGNATURE_LENGTH {
+        return err!(CommonError::InvalidDepositorSignature);
+    }
+
+    // Extract and validate recovery id from the signature.
+    let ethereum_recovery_id = signature[EVM_SIGNATURE_LENGTH - 1];
+    if !(27..=30).contains(&ethereum_recovery_id) {
+        return err!(CommonError::InvalidDepositorSignature);
+    }
+    let recovery_id = ethereum_recovery_id - 27;
+
+    // Reject high-s value signatures to prevent malleability.
+    let parsed_signature = EVMSignature::parse_standard_slice(&signature[0..EVM_SIGNATURE_LENGTH - 1])
+        .map_err(|_| CommonError::InvalidDepositorSignature)?;
+    if parsed_signature.s.is_high() {
+        return err!(CommonError::InvalidDepositorSignature);
+    }
+
+    let public_key = secp256k1_recover(hash, recovery_id, &signature[0..EVM_SIGNATURE_LENGTH - 1])
+        .map_err(|_| CommonError::InvalidDepositorSignature)?;
+
+    // Hash public key and return last 20 bytes (EVM address) as Pubkey.
+    let mut address = keccak::hash(public_key.to_bytes().as_slice()).to_bytes();
+    address[0..12].iter_mut().for_each(|x| {
+        *x = 0;
+    });
+
+    Ok(Pubkey::new_from_array(address))
+}
+
+pub fn verify_evm_signature(signer: &Pubkey, signature: &[u8], hash: &[u8; 32]) -> Result<()> {
+    if recover_evm_signer(hash, signature)? != *signer {
+        return err!(CommonError::InvalidDepositorSignature);
+    }
+
+    Ok(())
+}
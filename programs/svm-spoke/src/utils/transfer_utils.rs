@@ -1,6 +1,15 @@
 use crate::{error::SvmError, program::SvmSpoke};
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            cpi_guard::CpiGuard, memo_transfer::MemoTransfer, transfer_fee::TransferFeeConfig,
+            transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
 
 pub fn transfer_from<'info>(
     from: &InterfaceAccount<'info, TokenAccount>,
@@ -11,6 +20,8 @@ pub fn transfer_from<'info>(
     token_program: &Interface<'info, TokenInterface>,
     delegate_seed_hash: [u8; 32],
 ) -> Result<()> {
+    validate_mint_extensions(mint)?;
+
     let (pda, bump) = Pubkey::find_program_address(&[b"delegate", &delegate_seed_hash], &SvmSpoke::id());
     if pda != delegate.key() {
         return err!(SvmError::InvalidDelegatePda);
@@ -27,3 +38,48 @@ pub fn transfer_from<'info>(
 
     transfer_checked(cpi_context, amount, mint.decimals)
 }
+
+// Rejects Token-2022 mints with extensions that break the assumption that the transferred amount is the amount
+// received: transfer fees would credit less than the amount sent and transfer hooks would require forwarding extra
+// accounts on every transfer. Transfer fee config is rejected even with zero fee as the fee authority can raise it.
+pub fn validate_mint_extensions(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    if mint_state.get_extension::<TransferFeeConfig>().is_ok() {
+        return err!(SvmError::UnsupportedTransferFeeMint);
+    }
+    if let Ok(transfer_hook) = mint_state.get_extension::<TransferHook>() {
+        if Option::<Pubkey>::from(transfer_hook.program_id).is_some() {
+            return err!(SvmError::UnsupportedTransferHookMint);
+        }
+    }
+
+    Ok(())
+}
+
+// Checks whether a Token-2022 token account has extensions that make transfer CPIs into it abort: memo transfer
+// requires a memo instruction preceding every incoming transfer. Accounts with enabled CPI guard are also treated as
+// restricted so that only recipients without CPI restrictions are credited directly within the program.
+pub fn has_restricted_incoming_transfers(token_account_info: &AccountInfo) -> Result<bool> {
+    if *token_account_info.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let token_account_data = token_account_info.try_borrow_data()?;
+    let token_account_state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&token_account_data)?;
+
+    let requires_memo = token_account_state
+        .get_extension::<MemoTransfer>()
+        .is_ok_and(|memo_transfer| bool::from(memo_transfer.require_incoming_transfer_memos));
+    let locks_cpi = token_account_state
+        .get_extension::<CpiGuard>()
+        .is_ok_and(|cpi_guard| bool::from(cpi_guard.lock_cpi));
+
+    Ok(requires_memo || locks_cpi)
+}
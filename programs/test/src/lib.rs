@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 use svm_spoke::{
     constants::DISCRIMINATOR_SIZE,
     error::CommonError,
-    utils::{is_claimed, process_proof, set_claimed},
+    utils::{
+        decode_solidity_array, decode_solidity_bytes, decode_solidity_bytes4, decode_solidity_int64,
+        decode_solidity_uint256, get_solidity_arg, is_claimed, process_proof, set_claimed, verify_merkle_multi_proof,
+    },
 };
 
 declare_id!("8tsEfDSiE4WUMf97oyyyasLAvWwjeRZb2GByh4w7HckA");
@@ -79,6 +82,35 @@ pub mod test {
         Ok(())
     }
 
+    pub fn verify_multi(
+        _ctx: Context<Verify>,
+        root: [u8; 32],
+        leaves: Vec<[u8; 32]>,
+        proof: Vec<[u8; 32]>,
+        proof_flags: Vec<bool>,
+    ) -> Result<()> {
+        verify_merkle_multi_proof(root, &leaves, &proof, &proof_flags)
+    }
+
+    // Test CallData.
+    #[derive(Accounts)]
+    pub struct DecodeCallData {}
+    pub fn test_decode_uint256(_ctx: Context<DecodeCallData>, data: Vec<u8>, index: u8) -> Result<[u8; 32]> {
+        decode_solidity_uint256(&get_solidity_arg(&data, index as usize)?)
+    }
+
+    pub fn test_decode_int64(_ctx: Context<DecodeCallData>, data: Vec<u8>, index: u8) -> Result<i64> {
+        decode_solidity_int64(&get_solidity_arg(&data, index as usize)?)
+    }
+
+    pub fn test_decode_bytes(_ctx: Context<DecodeCallData>, data: Vec<u8>, index: u8) -> Result<Vec<u8>> {
+        decode_solidity_bytes(&data, index as usize)
+    }
+
+    pub fn test_decode_bytes4_array(_ctx: Context<DecodeCallData>, data: Vec<u8>, index: u8) -> Result<Vec<[u8; 4]>> {
+        decode_solidity_array(&data, index as usize, decode_solidity_bytes4)
+    }
+
     #[derive(Accounts)]
     pub struct EmitLargeLog {}
     #[event]
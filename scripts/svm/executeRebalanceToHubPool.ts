@@ -58,6 +58,7 @@ import {
   decodeMessageHeader,
   getMessages,
   getMessageTransmitterProgram,
+  getRemoteNoncePda,
   getSolanaChainId,
   getSpokePoolProgram,
   isSolanaDevnet,
@@ -87,6 +88,10 @@ const [authorityPda] = PublicKey.findProgramAddressSync(
   messageTransmitterProgram.programId
 );
 const [selfAuthority] = PublicKey.findProgramAddressSync([Buffer.from("self_authority")], svmSpokeProgram.programId);
+const [remoteAdminAuthority] = PublicKey.findProgramAddressSync(
+  [Buffer.from("remote_admin_authority")],
+  svmSpokeProgram.programId
+);
 const [eventAuthority] = PublicKey.findProgramAddressSync(
   [Buffer.from("__event_authority")],
   svmSpokeProgram.programId
@@ -191,13 +196,31 @@ async function executeRebalanceToHubPool(): Promise<void> {
     isWritable: false,
     pubkey: statePda,
   });
-  // self_authority in HandleReceiveMessage accounts, also signer in self-invoked CPIs.
+  // remote_nonce in HandleReceiveMessage accounts (tracks nonces of sequenced messages from the remote domain).
+  remainingAccounts.push({
+    isSigner: false,
+    isWritable: true,
+    pubkey: getRemoteNoncePda(seed, ethereumDomain, svmSpokeProgram.programId),
+  });
+  // self_authority in HandleReceiveMessage accounts, also signer in self-invoked CPIs from the cross-domain admin.
   remainingAccounts.push({
     isSigner: false,
     isWritable: false,
     pubkey: selfAuthority,
   });
-  // program in HandleReceiveMessage accounts.
+  // remote_admin_authority in HandleReceiveMessage accounts, also signer in self-invoked CPIs from remote admins.
+  remainingAccounts.push({
+    isSigner: false,
+    isWritable: false,
+    pubkey: remoteAdminAuthority,
+  });
+  // event_authority in HandleReceiveMessage accounts (appended by Anchor with event_cpi macro).
+  remainingAccounts.push({
+    isSigner: false,
+    isWritable: false,
+    pubkey: eventAuthority,
+  });
+  // program in HandleReceiveMessage accounts (appended by Anchor with event_cpi macro).
   remainingAccounts.push({
     isSigner: false,
     isWritable: false,
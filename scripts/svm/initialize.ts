@@ -5,7 +5,12 @@ import { AnchorProvider, BN } from "@coral-xyz/anchor";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import yargs from "yargs";
 import { hideBin } from "yargs/helpers";
-import { evmAddressToPublicKey, getSpokePoolProgram, SOLANA_SPOKE_STATE_SEED } from "../../src/svm/web3-v1";
+import {
+  evmAddressToPublicKey,
+  getRemoteNoncePda,
+  getSpokePoolProgram,
+  SOLANA_SPOKE_STATE_SEED,
+} from "../../src/svm/web3-v1";
 
 // Set up the provider
 const provider = AnchorProvider.env();
@@ -84,6 +89,7 @@ async function initialize(): Promise<void> {
   )
     .accounts({
       state: statePda,
+      remoteNonce: getRemoteNoncePda(seed, remoteDomain, programId),
       signer: signer,
       systemProgram: SystemProgram.programId,
     })
@@ -92,7 +98,7 @@ async function initialize(): Promise<void> {
   console.log("Transaction signature:", tx);
 
   if (!svmAdmin.equals(provider.wallet.publicKey)) {
-    console.log("Transferring ownership to SVM admin...");
+    console.log("Proposing SVM admin as the new owner...");
     const tx = await program.methods
       .transferOwnership(svmAdmin)
       .accountsPartial({
@@ -102,6 +108,7 @@ async function initialize(): Promise<void> {
       .rpc();
 
     console.log("Transfer ownership transaction signature:", tx);
+    console.log("SVM admin must call accept_ownership to complete the ownership transfer.");
   }
 }
 
@@ -26,6 +26,7 @@ import { hideBin } from "yargs/helpers";
 import {
   getDepositPda,
   getDepositSeedHash,
+  getRoutePda,
   getSpokePoolProgram,
   intToU8Array32,
   SOLANA_SPOKE_STATE_SEED,
@@ -162,6 +163,7 @@ async function nativeDeposit(): Promise<void> {
 
   const depositAccounts = {
     state: statePda,
+    route: getRoutePda(inputToken, seed, destinationChainId, program.programId),
     delegate: delegatePda,
     signer: signer.publicKey,
     depositorTokenAccount: userTokenAccount,
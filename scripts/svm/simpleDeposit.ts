@@ -21,6 +21,7 @@ import { hideBin } from "yargs/helpers";
 import {
   getDepositPda,
   getDepositSeedHash,
+  getRoutePda,
   getSpokePoolProgram,
   intToU8Array32,
   u8Array32ToInt,
@@ -132,6 +133,7 @@ async function deposit(): Promise<void> {
 
   const depositAccounts = {
     state: statePda,
+    route: getRoutePda(inputToken, seed, destinationChainId, program.programId),
     delegate: delegatePda,
     signer: signer.publicKey,
     depositorTokenAccount: userTokenAccount,
@@ -197,7 +197,7 @@ async function testBundleLogic(): Promise<void> {
     { property: "Signer", value: signer.publicKey.toString() },
   ]);
 
-  const relayRootBundleTx = await (program.methods.relayRootBundle(Array.from(root), Array.from(root)) as any)
+  const relayRootBundleTx = await (program.methods.relayRootBundle(Array.from(root), Array.from(root), null) as any)
     .accounts({
       state: statePda,
       rootBundle: rootBundle,
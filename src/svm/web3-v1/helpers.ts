@@ -299,3 +299,183 @@ export function getFillRelayDelegatePda(
 
   return { seedHash, pda };
 }
+
+/**
+ * Fill Batch Delegate Seed Data
+ */
+class FillBatchDelegateSeedData {
+  relayHashes: Uint8Array[];
+  repaymentChainId: BN;
+  repaymentAddress: Uint8Array;
+  constructor(fields: { relayHashes: Uint8Array[]; repaymentChainId: BN; repaymentAddress: Uint8Array }) {
+    this.relayHashes = fields.relayHashes;
+    this.repaymentChainId = fields.repaymentChainId;
+    this.repaymentAddress = fields.repaymentAddress;
+  }
+}
+
+/**
+ * Borsh schema for FillBatchDelegateSeedData
+ */
+const fillBatchDelegateSeedSchema = new Map<any, any>([
+  [
+    FillBatchDelegateSeedData,
+    {
+      kind: "struct",
+      fields: [
+        ["relayHashes", [[32]]],
+        ["repaymentChainId", "u64"],
+        ["repaymentAddress", [32]],
+      ],
+    },
+  ],
+]);
+
+/**
+ * Returns the batch fill delegate seed hash.
+ */
+export function getFillRelaysBatchDelegateSeedHash(
+  relayHashes: Uint8Array[],
+  repaymentChainId: BN,
+  repaymentAddress: PublicKey
+): Uint8Array {
+  const ds = new FillBatchDelegateSeedData({
+    relayHashes,
+    repaymentChainId,
+    repaymentAddress: repaymentAddress.toBuffer(),
+  });
+
+  return deriveSeedHash(fillBatchDelegateSeedSchema, ds);
+}
+
+/**
+ * Returns the batch fill delegate PDA.
+ */
+export function getFillRelaysBatchDelegatePda(
+  relayHashes: Uint8Array[],
+  repaymentChainId: BN,
+  repaymentAddress: PublicKey,
+  programId: PublicKey
+): { seedHash: Uint8Array; pda: PublicKey } {
+  const seedHash = getFillRelaysBatchDelegateSeedHash(relayHashes, repaymentChainId, repaymentAddress);
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from("delegate"), seedHash], programId);
+
+  return { seedHash, pda };
+}
+
+/**
+ * Update Deposit Details signed by the depositor when speeding up a deposit.
+ */
+class UpdateDepositDetailsData {
+  depositId: number[];
+  originChainId: BN;
+  updatedOutputAmount: number[];
+  updatedRecipient: Uint8Array;
+  updatedMessage: Uint8Array;
+  constructor(fields: {
+    depositId: number[];
+    originChainId: BN;
+    updatedOutputAmount: number[];
+    updatedRecipient: Uint8Array;
+    updatedMessage: Uint8Array;
+  }) {
+    this.depositId = fields.depositId;
+    this.originChainId = fields.originChainId;
+    this.updatedOutputAmount = fields.updatedOutputAmount;
+    this.updatedRecipient = fields.updatedRecipient;
+    this.updatedMessage = fields.updatedMessage;
+  }
+}
+
+/**
+ * Borsh schema for UpdateDepositDetailsData
+ */
+const updateDepositDetailsSchema = new Map<any, any>([
+  [
+    UpdateDepositDetailsData,
+    {
+      kind: "struct",
+      fields: [
+        ["depositId", [32]],
+        ["originChainId", "u64"],
+        ["updatedOutputAmount", [32]],
+        ["updatedRecipient", [32]],
+        ["updatedMessage", ["u8"]],
+      ],
+    },
+  ],
+]);
+
+/**
+ * Returns the hash of updated deposit details that the depositor signs with their Ed25519 key to speed up a deposit.
+ * Updated output amount is passed as big-endian encoded 32-byte array.
+ */
+export function getUpdateDepositDetailsHash(
+  depositId: number[],
+  originChainId: BN,
+  updatedOutputAmount: number[],
+  updatedRecipient: PublicKey,
+  updatedMessage: Uint8Array
+): Uint8Array {
+  const data = new UpdateDepositDetailsData({
+    depositId,
+    originChainId,
+    updatedOutputAmount,
+    updatedRecipient: updatedRecipient.toBuffer(),
+    updatedMessage,
+  });
+
+  return deriveSeedHash(updateDepositDetailsSchema, data);
+}
+
+/**
+ * Returns the PDA that tracks whether deposits of the origin token to the destination chain are enabled.
+ */
+export function getRoutePda(originToken: PublicKey, seed: BN, destinationChainId: BN, programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("route"),
+      originToken.toBuffer(),
+      seed.toArrayLike(Buffer, "le", 8),
+      destinationChainId.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+  return pda;
+}
+
+/**
+ * Returns the PDA tracking claimed leaves of a typed root in a root bundle.
+ */
+export function getTypedRootClaimsPda(
+  seed: BN,
+  rootBundleId: number,
+  rootType: number,
+  programId: PublicKey
+): PublicKey {
+  const rootBundleIdBuffer = Buffer.alloc(4);
+  rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+  const [pda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("typed_root_claims"),
+      seed.toArrayLike(Buffer, "le", 8),
+      rootBundleIdBuffer,
+      Buffer.from([rootType]),
+    ],
+    programId
+  );
+  return pda;
+}
+
+/**
+ * Returns the PDA tracking the CCTP nonce of the last admin message applied from a remote domain.
+ */
+export function getRemoteNoncePda(seed: BN, remoteDomain: number, programId: PublicKey): PublicKey {
+  const remoteDomainBuffer = Buffer.alloc(4);
+  remoteDomainBuffer.writeUInt32LE(remoteDomain);
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("remote_nonce"), seed.toArrayLike(Buffer, "le", 8), remoteDomainBuffer],
+    programId
+  );
+  return pda;
+}
@@ -40,6 +40,44 @@ export async function loadExecuteRelayerRefundLeafParams(
   return instructionParams;
 }
 
+/**
+ * Loads execute relayer refund leaves parameters.
+ */
+export async function loadExecuteRelayerRefundLeavesParams(
+  program: Program<SvmSpoke>,
+  caller: PublicKey,
+  rootBundleId: number,
+  relayerRefundLeaves: RelayerRefundLeafSolana[],
+  proof: number[][],
+  proofFlags: boolean[]
+) {
+  const maxInstructionParamsFragment = 900; // Should not exceed message size limit when writing to the data account.
+
+  // Close the instruction params account if the caller has used it before.
+  const [instructionParams] = PublicKey.findProgramAddressSync(
+    [Buffer.from("instruction_params"), caller.toBuffer()],
+    program.programId
+  );
+  const accountInfo = await program.provider.connection.getAccountInfo(instructionParams);
+  if (accountInfo !== null) await program.methods.closeInstructionParams().rpc();
+
+  const accountCoder = new LargeAccountsCoder(program.idl);
+  const instructionParamsBytes = await accountCoder.encode("executeRelayerRefundLeavesParams", {
+    rootBundleId,
+    relayerRefundLeaves,
+    proof,
+    proofFlags,
+  });
+
+  await program.methods.initializeInstructionParams(instructionParamsBytes.length).rpc();
+
+  for (let i = 0; i < instructionParamsBytes.length; i += maxInstructionParamsFragment) {
+    const fragment = instructionParamsBytes.slice(i, i + maxInstructionParamsFragment);
+    await program.methods.writeInstructionParamsFragment(i, fragment).rpc();
+  }
+  return instructionParams;
+}
+
 /**
  * Closes the instruction parameters account.
  */
@@ -194,3 +232,49 @@ export async function loadExecuteSlowRelayLeafParams(
 
   return loadInstructions;
 }
+
+/**
+ * Loads execute slow relay leaves parameters.
+ */
+export async function loadExecuteSlowRelayLeavesParams(
+  program: Program<SvmSpoke>,
+  signer: Keypair,
+  rootBundleId: number,
+  slowFillLeaves: SlowFillLeaf[],
+  proof: number[][],
+  proofFlags: boolean[]
+) {
+  // Close the instruction params account if the caller has used it before.
+  await closeInstructionParams(program, signer);
+
+  // Execute load instructions sequentially.
+  const maxInstructionParamsFragment = 900; // Should not exceed message size limit when writing to the data account.
+
+  const accountCoder = new LargeAccountsCoder(program.idl);
+  const instructionParamsBytes = await accountCoder.encode("executeSlowRelayLeavesParams", {
+    rootBundleId,
+    slowFillLeaves,
+    proof,
+    proofFlags,
+  });
+
+  const loadInstructions: TransactionInstruction[] = [];
+  loadInstructions.push(
+    await program.methods
+      .initializeInstructionParams(instructionParamsBytes.length)
+      .accounts({ signer: signer.publicKey })
+      .instruction()
+  );
+
+  for (let i = 0; i < instructionParamsBytes.length; i += maxInstructionParamsFragment) {
+    const fragment = instructionParamsBytes.slice(i, i + maxInstructionParamsFragment);
+    loadInstructions.push(
+      await program.methods
+        .writeInstructionParamsFragment(i, fragment)
+        .accounts({ signer: signer.publicKey })
+        .instruction()
+    );
+  }
+
+  return loadInstructions;
+}
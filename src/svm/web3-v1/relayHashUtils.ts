@@ -1,6 +1,12 @@
 import { BN } from "@coral-xyz/anchor";
 import { ethers } from "ethers";
-import { RelayerRefundLeaf, RelayerRefundLeafSolana, SlowFillLeaf } from "../../types/svm";
+import {
+  OriginRefundLeaf,
+  PayoutLeaf,
+  RelayerRefundLeaf,
+  RelayerRefundLeafSolana,
+  SlowFillLeaf,
+} from "../../types/svm";
 import { serialize } from "borsh";
 
 /**
@@ -220,3 +226,123 @@ export function slowFillHashFn(slowFillLeaf: SlowFillLeaf): string {
 
   return ethers.utils.keccak256(contentToHash);
 }
+
+/**
+ * Class for origin refund data.
+ */
+class OriginRefundData {
+  constructor(properties: any) {
+    Object.assign(this, properties);
+  }
+}
+
+/**
+ * Schema for origin refund data.
+ */
+const originRefundDataSchema = new Map([
+  [
+    OriginRefundData,
+    {
+      kind: "struct",
+      fields: [
+        ["chainId", "u64"],
+        ["depositId", [32]],
+        ["depositor", [32]],
+        ["mint", [32]],
+        ["amount", "u64"],
+        ["fillDeadline", "u32"],
+      ],
+    },
+  ],
+]);
+
+/**
+ * Domain separator prefixed to origin refund leaves to distinguish them from other SVM leaf types.
+ */
+export const ORIGIN_REFUND_LEAF_DOMAIN = Buffer.from("ACROSS-ORIGIN-REFUND-LEAF");
+
+/**
+ * Hash function for origin refund leaves.
+ */
+export function originRefundHashFn(originRefundLeaf: OriginRefundLeaf): string {
+  const data = new OriginRefundData({
+    chainId: originRefundLeaf.chainId,
+    depositId: Uint8Array.from(originRefundLeaf.depositId),
+    depositor: Uint8Array.from(originRefundLeaf.depositor.toBuffer()),
+    mint: Uint8Array.from(originRefundLeaf.mint.toBuffer()),
+    amount: originRefundLeaf.amount,
+    fillDeadline: originRefundLeaf.fillDeadline,
+  });
+
+  const serializedData = serialize(originRefundDataSchema, data);
+
+  // Same 64 zero bytes as other SVM leaves followed by the origin refund leaf domain separator.
+  const contentToHash = Buffer.concat([Buffer.alloc(64, 0), ORIGIN_REFUND_LEAF_DOMAIN, serializedData]);
+
+  return ethers.utils.keccak256(contentToHash);
+}
+
+/**
+ * Root type of the origin refund tree. Any other root type is a generic payout tree.
+ */
+export const ORIGIN_REFUND_ROOT_TYPE = 0;
+
+/**
+ * Class for payout data.
+ */
+class PayoutData {
+  constructor(properties: any) {
+    Object.assign(this, properties);
+  }
+}
+
+/**
+ * Schema for payout data.
+ */
+const payoutDataSchema = new Map([
+  [
+    PayoutData,
+    {
+      kind: "struct",
+      fields: [
+        ["chainId", "u64"],
+        ["leafId", "u32"],
+        ["mint", [32]],
+        ["recipient", [32]],
+        ["amount", "u64"],
+      ],
+    },
+  ],
+]);
+
+/**
+ * Domain separator prefixed to payout leaves to distinguish them from other SVM leaf types.
+ */
+export const PAYOUT_LEAF_DOMAIN = Buffer.from("ACROSS-PAYOUT-LEAF");
+
+/**
+ * Returns the hash function for payout leaves of the given root type.
+ */
+export function payoutHashFn(rootType: number) {
+  return (payoutLeaf: PayoutLeaf): string => {
+    const data = new PayoutData({
+      chainId: payoutLeaf.chainId,
+      leafId: payoutLeaf.leafId,
+      mint: Uint8Array.from(payoutLeaf.mint.toBuffer()),
+      recipient: Uint8Array.from(payoutLeaf.recipient.toBuffer()),
+      amount: payoutLeaf.amount,
+    });
+
+    const serializedData = serialize(payoutDataSchema, data);
+
+    // Same 64 zero bytes as other SVM leaves followed by the payout leaf domain separator and the root type.
+    const contentToHash = Buffer.concat([
+      Buffer.alloc(64, 0),
+      PAYOUT_LEAF_DOMAIN,
+      Buffer.from([rootType]),
+      serializedData,
+    ]);
+
+    return ethers.utils.keccak256(contentToHash);
+  };
+}
@@ -36,6 +36,29 @@ export interface SlowFillLeaf {
   updatedOutputAmount: BN;
 }
 
+/**
+ * Origin Refund Leaf Interface
+ */
+export interface OriginRefundLeaf {
+  chainId: BN;
+  depositId: number[];
+  depositor: PublicKey;
+  mint: PublicKey;
+  amount: BN;
+  fillDeadline: number;
+}
+
+/**
+ * Payout Leaf Interface
+ */
+export interface PayoutLeaf {
+  chainId: BN;
+  leafId: number;
+  mint: PublicKey;
+  recipient: PublicKey;
+  amount: BN;
+}
+
 /**
  * Relay Data Interface
  */
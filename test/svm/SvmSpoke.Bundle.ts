@@ -1,28 +1,44 @@
 import * as anchor from "@coral-xyz/anchor";
 import { AnchorProvider, BN, Wallet, web3 } from "@coral-xyz/anchor";
 import {
+  createEnableRequiredMemoTransfersInstruction,
   createMint,
+  createReallocateInstruction,
+  ExtensionType,
   getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import { ComputeBudgetProgram, Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import { assert } from "chai";
 import * as crypto from "crypto";
 import { ethers } from "ethers";
 import {
+  getTypedRootClaimsPda,
   loadExecuteRelayerRefundLeafParams,
+  loadExecuteRelayerRefundLeavesParams,
+  ORIGIN_REFUND_ROOT_TYPE,
+  originRefundHashFn,
+  payoutHashFn,
   readEventsUntilFound,
   relayerRefundHashFn,
   sendTransactionWithLookupTable,
 } from "../../src/svm/web3-v1";
-import { RelayerRefundLeafSolana, RelayerRefundLeafType } from "../../src/types/svm";
+import { OriginRefundLeaf, PayoutLeaf, RelayerRefundLeafSolana, RelayerRefundLeafType } from "../../src/types/svm";
 import { MerkleTree } from "../../utils";
 import { common } from "./SvmSpoke.common";
 import { buildRelayerRefundMerkleTree, randomBigInt, readEvents, readProgramEvents } from "./utils";
 
-const { provider, program, owner, initializeState, connection, chainId, assertSE } = common;
+const { provider, program, owner, initializeState, connection, chainId, assertSE, setCurrentTime } = common;
 
 describe("svm_spoke.bundle", () => {
   anchor.setProvider(provider);
@@ -98,7 +114,7 @@ describe("svm_spoke.bundle", () => {
     };
     try {
       await program.methods
-        .relayRootBundle(relayerRefundRootArray, slowRelayRootArray)
+        .relayRootBundle(relayerRefundRootArray, slowRelayRootArray, null)
         .accounts(relayRootBundleAccounts)
         .signers([nonOwner])
         .rpc();
@@ -110,7 +126,7 @@ describe("svm_spoke.bundle", () => {
     // Relay root bundle as owner
     relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
     await program.methods
-      .relayRootBundle(relayerRefundRootArray, slowRelayRootArray)
+      .relayRootBundle(relayerRefundRootArray, slowRelayRootArray, null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -148,7 +164,7 @@ describe("svm_spoke.bundle", () => {
       program: program.programId,
     };
     await program.methods
-      .relayRootBundle(relayerRefundRootArray2, slowRelayRootArray2)
+      .relayRootBundle(relayerRefundRootArray2, slowRelayRootArray2, null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -172,7 +188,7 @@ describe("svm_spoke.bundle", () => {
     // Relay root bundle as owner
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
     const tx = await program.methods
-      .relayRootBundle(relayerRefundRootArray, slowRelayRootArray)
+      .relayRootBundle(relayerRefundRootArray, slowRelayRootArray, null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -218,7 +234,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
       { pubkey: relayerTB, isWritable: true, isSigner: false },
@@ -355,7 +374,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -488,7 +510,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -583,7 +608,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -687,7 +715,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -749,7 +780,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -811,7 +845,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [{ pubkey: relayerTA, isWritable: true, isSigner: false }];
 
@@ -902,7 +939,7 @@ describe("svm_spoke.bundle", () => {
 
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
     await program.methods
-      .relayRootBundle(relayerRefundRootArray, slowRelayRootArray)
+      .relayRootBundle(relayerRefundRootArray, slowRelayRootArray, null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -972,7 +1009,7 @@ describe("svm_spoke.bundle", () => {
       program: program.programId,
     };
     await program.methods
-      .relayRootBundle(newRelayerRefundRootArray, newSlowRelayRootArray)
+      .relayRootBundle(newRelayerRefundRootArray, newSlowRelayRootArray, null)
       .accounts(newRelayRootBundleAccounts)
       .rpc();
 
@@ -992,162 +1029,730 @@ describe("svm_spoke.bundle", () => {
     assert.isTrue(newSlowRelayRootHex === newSlowRelayRootBuffer.toString("hex"), "New slow relay root should be set");
   });
 
-  describe("Execute Max Refunds", () => {
-    const executeMaxRefunds = async (testConfig: {
-      solanaDistributions: number;
-      deferredRefunds: boolean;
-      atomicAccountCreation: boolean;
-    }) => {
-      assert.isTrue(
-        !(testConfig.deferredRefunds && testConfig.atomicAccountCreation),
-        "Incompatible test configuration"
-      );
-      // Add leaves for other EVM chains to have non-empty proofs array to ensure we don't run out of memory when processing.
-      const evmDistributions = 100; // This would fit in 7 proof array elements.
+  it("Refunds expired deposits on origin", async () => {
+    const currentTime = (await program.account.state.fetch(state)).currentTime;
+    const originRefundLeaves: OriginRefundLeaf[] = [
+      {
+        chainId,
+        depositId: Array.from(crypto.randomBytes(32)),
+        depositor: relayerA.publicKey,
+        mint,
+        amount: new BN(250000),
+        fillDeadline: currentTime - 1,
+      },
+      {
+        chainId,
+        depositId: Array.from(crypto.randomBytes(32)),
+        depositor: relayerB.publicKey,
+        mint,
+        amount: new BN(150000),
+        fillDeadline: currentTime + 3600,
+      },
+    ];
+    const merkleTree = new MerkleTree<OriginRefundLeaf>(originRefundLeaves, originRefundHashFn);
+    const root = Array.from(merkleTree.getRoot());
 
-      const refundAccounts: web3.PublicKey[] = []; // These would hold either token accounts or claim accounts.
-      const refundAddresses: web3.PublicKey[] = []; // These are relayer authority addresses used in leaf building.
-      const refundAmounts: BN[] = [];
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
 
-      for (let i = 0; i < testConfig.solanaDistributions; i++) {
-        // Will create token account later if needed.
-        const tokenOwner = Keypair.generate().publicKey;
-        const tokenAccount = getAssociatedTokenAddressSync(mint, tokenOwner);
-        refundAddresses.push(tokenOwner);
+    // Only the owner can relay typed roots.
+    const typedRootClaims = getTypedRootClaimsPda(seed, rootBundleId, ORIGIN_REFUND_ROOT_TYPE, program.programId);
+    const relayTypedRootAccounts = { state, rootBundle, typedRootClaims, program: program.programId };
+    try {
+      await program.methods
+        .relayTypedRoot(rootBundleId, ORIGIN_REFUND_ROOT_TYPE, root)
+        .accounts({ ...relayTypedRootAccounts, signer: nonOwner.publicKey, payer: nonOwner.publicKey })
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Non-owner should not be able to relay typed root");
+    } catch (err: any) {
+      assert.include(err.toString(), "NotOwner", "Expected NotOwner error");
+    }
+    await program.methods
+      .relayTypedRoot(rootBundleId, ORIGIN_REFUND_ROOT_TYPE, root)
+      .accounts({ ...relayTypedRootAccounts, signer: owner, payer: owner })
+      .rpc();
+    const { typedRoots } = await program.account.rootBundle.fetch(rootBundle);
+    assertSE(typedRoots[0].rootType, ORIGIN_REFUND_ROOT_TYPE, "Root type should be set");
+    assertSE(typedRoots[0].root, root, "Root should be set");
 
-        const [claimAccount] = PublicKey.findProgramAddressSync(
-          [Buffer.from("claim_account"), mint.toBuffer(), tokenOwner.toBuffer()],
-          program.programId
-        );
+    // The same root type cannot be relayed again.
+    try {
+      await program.methods
+        .relayTypedRoot(rootBundleId, ORIGIN_REFUND_ROOT_TYPE, Array.from(crypto.randomBytes(32)))
+        .accounts({ ...relayTypedRootAccounts, signer: owner, payer: owner })
+        .rpc();
+      assert.fail("Typed root should not be replaceable");
+    } catch (err: any) {
+      assert.include(err.toString(), "already in use", "Expected typed root claims PDA to already exist");
+    }
 
-        if (!testConfig.deferredRefunds && !testConfig.atomicAccountCreation) {
-          await getOrCreateAssociatedTokenAccount(connection, payer, mint, tokenOwner);
-          refundAccounts.push(tokenAccount);
-        } else if (!testConfig.deferredRefunds && testConfig.atomicAccountCreation) {
-          refundAccounts.push(tokenAccount);
-        } else {
-          await program.methods.initializeClaimAccount().accounts({ mint, refundAddress: tokenOwner }).rpc();
-          refundAccounts.push(claimAccount);
-        }
+    const executeOriginRefundLeaf = (leaf: OriginRefundLeaf, depositorTokenAccount: PublicKey) =>
+      program.methods
+        .executeOriginRefundLeaf(rootBundleId, leaf, merkleTree.getProof(leaf).map((p) => Array.from(p)))
+        .accounts({
+          signer: owner,
+          state,
+          rootBundle,
+          vault,
+          mint,
+          depositorTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: program.programId,
+        })
+        .rpc();
 
-        refundAmounts.push(new BN(randomBigInt(2).toString()));
-      }
+    // Expired deposit is refunded to the depositor.
+    const iVaultBal = (await connection.getTokenAccountBalance(vault)).value.amount;
+    const iRelayerABal = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+    const tx = await executeOriginRefundLeaf(originRefundLeaves[0], relayerTA);
+    const fVaultBal = (await connection.getTokenAccountBalance(vault)).value.amount;
+    const fRelayerABal = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+    assertSE(BigInt(iVaultBal) - BigInt(fVaultBal), originRefundLeaves[0].amount, "Vault balance");
+    assertSE(BigInt(fRelayerABal) - BigInt(iRelayerABal), originRefundLeaves[0].amount, "Depositor balance");
+
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "executedOriginRefund")?.data;
+    assertSE(event.depositId, originRefundLeaves[0].depositId, "Deposit ID should match");
+    assertSE(event.depositor, relayerA.publicKey, "Depositor should match");
+    assertSE(event.amount, originRefundLeaves[0].amount, "Amount should match");
+
+    // Origin refund claim PDA is derived per state so that states with different seeds do not collide.
+    const [originRefundClaim] = PublicKey.findProgramAddressSync(
+      [Buffer.from("origin_refund"), seed.toArrayLike(Buffer, "le", 8), Buffer.from(originRefundLeaves[0].depositId)],
+      program.programId
+    );
+    const originRefundClaimData = await program.account.originRefundClaim.fetch(originRefundClaim);
+    assertSE(originRefundClaimData.rootBundleId, rootBundleId, "Origin refund claim should record the root bundle");
 
-      const { relayerRefundLeaves, merkleTree } = buildRelayerRefundMerkleTree({
-        totalEvmDistributions: evmDistributions,
-        totalSolanaDistributions: testConfig.solanaDistributions,
-        mixLeaves: false,
-        chainId: chainId.toNumber(),
-        mint,
-        svmRelayers: refundAddresses,
-        svmRefundAmounts: refundAmounts,
-      });
+    // The same deposit cannot be refunded twice.
+    try {
+      await executeOriginRefundLeaf(originRefundLeaves[0], relayerTA);
+      assert.fail("Deposit should not be refunded twice");
+    } catch (err: any) {
+      assert.include(err.toString(), "already in use", "Expected origin refund claim PDA to already exist");
+    }
 
-      const root = merkleTree.getRoot();
-      const proof = merkleTree.getProof(relayerRefundLeaves[0]);
-      const leaf = relayerRefundLeaves[0] as RelayerRefundLeafSolana;
+    // Deposits cannot be refunded before their fill deadline.
+    try {
+      await executeOriginRefundLeaf(originRefundLeaves[1], relayerTB);
+      assert.fail("Deposit should not be refunded before its fill deadline");
+    } catch (err: any) {
+      assert.include(err.toString(), "OriginRefundBeforeFillDeadline", "Expected OriginRefundBeforeFillDeadline error");
+    }
+  });
 
-      const stateAccountData = await program.account.state.fetch(state);
-      const rootBundleId = stateAccountData.rootBundleId;
+  it("Executes generic payout leaves", async () => {
+    const rootType = 1;
+    const payoutLeaves: PayoutLeaf[] = [
+      { chainId, leafId: 0, mint, recipient: relayerA.publicKey, amount: new BN(120000) },
+      { chainId, leafId: 1, mint, recipient: relayerB.publicKey, amount: new BN(80000) },
+    ];
+    const merkleTree = new MerkleTree<PayoutLeaf>(payoutLeaves, payoutHashFn(rootType));
 
-      const rootBundleIdBuffer = Buffer.alloc(4);
-      rootBundleIdBuffer.writeUInt32LE(rootBundleId);
-      const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
-      const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+    const typedRootClaims = getTypedRootClaimsPda(seed, rootBundleId, rootType, program.programId);
+    await program.methods
+      .relayTypedRoot(rootBundleId, rootType, Array.from(merkleTree.getRoot()))
+      .accounts({ state, rootBundle, typedRootClaims, signer: owner, payer: owner, program: program.programId })
+      .rpc();
 
-      // Relay root bundle
-      const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-      await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    const executePayoutLeaf = (leaf: PayoutLeaf, recipientTokenAccount: PublicKey, leafRootType = rootType) =>
+      program.methods
+        .executePayoutLeaf(rootBundleId, leafRootType, leaf, merkleTree.getProof(leaf).map((p) => Array.from(p)))
+        .accounts({
+          signer: owner,
+          state,
+          rootBundle,
+          typedRootClaims: getTypedRootClaimsPda(seed, rootBundleId, leafRootType, program.programId),
+          vault,
+          mint,
+          recipientTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: program.programId,
+        })
+        .rpc();
 
-      // Verify valid leaf
-      const proofAsNumbers = proof.map((p) => Array.from(p));
+    // Execute both leaves and verify recipient balances.
+    const iRelayerABal = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+    const iRelayerBBal = (await connection.getTokenAccountBalance(relayerTB)).value.amount;
+    const tx = await executePayoutLeaf(payoutLeaves[0], relayerTA);
+    await executePayoutLeaf(payoutLeaves[1], relayerTB);
+    const fRelayerABal = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+    const fRelayerBBal = (await connection.getTokenAccountBalance(relayerTB)).value.amount;
+    assertSE(BigInt(fRelayerABal) - BigInt(iRelayerABal), payoutLeaves[0].amount, "Recipient A balance");
+    assertSE(BigInt(fRelayerBBal) - BigInt(iRelayerBBal), payoutLeaves[1].amount, "Recipient B balance");
 
-      const [instructionParams] = PublicKey.findProgramAddressSync(
-        [Buffer.from("instruction_params"), owner.toBuffer()],
-        program.programId
-      );
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "executedPayout")?.data;
+    assertSE(event.rootType, rootType, "Root type should match");
+    assertSE(event.leafId, payoutLeaves[0].leafId, "Leaf ID should match");
+    assertSE(event.recipient, relayerA.publicKey, "Recipient should match");
+    assertSE(event.amount, payoutLeaves[0].amount, "Amount should match");
 
-      // We will be using Address Lookup Table (ALT), so to test maximum refunds we better add, not only refund accounts,
-      // but also all static accounts.
-      const staticAccounts = {
-        instructionParams,
-        state: state,
-        rootBundle: rootBundle,
-        signer: owner,
-        vault: vault,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        mint: mint,
-        transferLiability,
-        systemProgram: web3.SystemProgram.programId,
-        // Appended by Acnhor `event_cpi` macro:
-        eventAuthority: PublicKey.findProgramAddressSync([Buffer.from("__event_authority")], program.programId)[0],
-        program: program.programId,
-      };
+    // The same leaf cannot be executed twice.
+    try {
+      await executePayoutLeaf(payoutLeaves[0], relayerTA);
+      assert.fail("Leaf should not be executed twice");
+    } catch (err: any) {
+      assert.include(err.toString(), "ClaimedMerkleLeaf", "Expected ClaimedMerkleLeaf error");
+    }
 
-      const executeRemainingAccounts = refundAccounts.map((account) => ({
-        pubkey: account,
-        isWritable: true,
-        isSigner: false,
-      }));
+    // Leaves cannot be executed against a root type that was not relayed.
+    try {
+      await executePayoutLeaf(payoutLeaves[0], relayerTA, rootType + 1);
+      assert.fail("Leaf should not be executed against a missing root type");
+    } catch (err: any) {
+      assert.include(err.toString(), "AccountNotInitialized", "Expected AccountNotInitialized error");
+    }
+  });
 
-      const createTokenAccountsRemainingAccounts = testConfig.atomicAccountCreation
-        ? refundAddresses.flatMap((authority, index) => [
-            { pubkey: authority, isWritable: false, isSigner: false },
-            { pubkey: refundAccounts[index], isWritable: true, isSigner: false },
-          ])
-        : [];
+  it("Closes fully executed root bundle", async () => {
+    const relayerRefundLeaves: RelayerRefundLeafType[] = [
+      {
+        isSolana: true,
+        leafId: new BN(0),
+        chainId: chainId,
+        amountToReturn: new BN(0),
+        mintPublicKey: mint,
+        refundAddresses: [relayerA.publicKey],
+        refundAmounts: [new BN(100000)],
+      },
+    ];
+    const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+    const leaf = relayerRefundLeaves[0] as RelayerRefundLeafSolana;
 
-      // Build the instruction to execute relayer refund leaf and write its instruction args to the data account.
-      await loadExecuteRelayerRefundLeafParams(program, owner, stateAccountData.rootBundleId, leaf, proofAsNumbers);
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
 
-      const executeInstruction = !testConfig.deferredRefunds
-        ? await program.methods
-            .executeRelayerRefundLeaf()
-            .accounts(staticAccounts)
-            .remainingAccounts(executeRemainingAccounts)
-            .instruction()
-        : await program.methods
-            .executeRelayerRefundLeafDeferred()
-            .accounts(staticAccounts)
-            .remainingAccounts(executeRemainingAccounts)
-            .instruction();
+    // Relay root bundle with the leaf count and without slow relay root.
+    await program.methods
+      .relayRootBundle(Array.from(merkleTree.getRoot()), Array(32).fill(0), 1)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+    const rootBundleData = await program.account.rootBundle.fetch(rootBundle);
+    assertSE(rootBundleData.payer, owner, "Payer should match");
+    assertSE(rootBundleData.leafCount, 1, "Leaf count should match");
 
-      // Build the instruction to increase the CU limit as the default 200k is not sufficient.
-      const computeBudgetInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
+    const closeRootBundleAccounts = { payer: owner, state, rootBundle, program: program.programId };
 
-      // Insert atomic ATA creation if needed.
-      const instructions = [computeBudgetInstruction];
-      if (testConfig.atomicAccountCreation)
-        instructions.push(
-          await program.methods
-            .createTokenAccounts()
-            .accounts({ mint, tokenProgram: TOKEN_PROGRAM_ID })
-            .remainingAccounts(createTokenAccountsRemainingAccounts)
-            .instruction()
-        );
+    // Root bundle cannot be closed before all of its leaves are executed.
+    try {
+      await program.methods.closeRootBundle(rootBundleId).accounts(closeRootBundleAccounts).rpc();
+      assert.fail("Root bundle should not be closed before execution");
+    } catch (err: any) {
+      assert.include(err.toString(), "RootBundleNotClosable", "Expected RootBundleNotClosable error");
+    }
 
-      // Add relay refund leaf execution instruction.
-      instructions.push(executeInstruction);
+    await loadExecuteRelayerRefundLeafParams(
+      program,
+      owner,
+      rootBundleId,
+      leaf,
+      merkleTree.getProof(leaf).map((p) => Array.from(p))
+    );
+    await program.methods
+      .executeRelayerRefundLeaf()
+      .accounts({ signer: owner, state, rootBundle, vault, mint, transferLiability, program: program.programId })
+      .remainingAccounts([{ pubkey: relayerTA, isWritable: true, isSigner: false }])
+      .rpc();
 
-      // Execute using ALT.
-      await sendTransactionWithLookupTable(
-        connection,
-        instructions,
-        (anchor.AnchorProvider.env().wallet as anchor.Wallet).payer
-      );
+    // Rent can only be returned to the payer of the root bundle.
+    try {
+      await program.methods
+        .closeRootBundle(rootBundleId)
+        .accounts({ ...closeRootBundleAccounts, payer: nonOwner.publicKey })
+        .rpc();
+      assert.fail("Root bundle should not be closed to a different payer");
+    } catch (err: any) {
+      assert.include(err.toString(), "ConstraintAddress", "Expected ConstraintAddress error");
+    }
 
-      // Verify all refund account balances (either token or claim accounts).
-      await new Promise((resolve) => setTimeout(resolve, 1000)); // Make sure account balances have been synced.
-      const refundBalances = await Promise.all(
-        refundAccounts.map(async (account) => {
-          if (!testConfig.deferredRefunds) {
-            return (await connection.getTokenAccountBalance(account)).value.amount;
-          } else {
-            return (await program.account.claimAccount.fetch(account)).amount.toString();
-          }
-        })
-      );
-      refundBalances.forEach((balance, i) => {
+    // Closing does not require any signer.
+    const rootBundleLamports = (await connection.getAccountInfo(rootBundle))!.lamports;
+    const iPayerLamports = await connection.getBalance(owner);
+    const tx = await program.methods.closeRootBundle(rootBundleId).accounts(closeRootBundleAccounts).rpc();
+    const fPayerLamports = await connection.getBalance(owner);
+    assert.isNull(await connection.getAccountInfo(rootBundle), "Root bundle should be closed");
+    assert.isAbove(fPayerLamports - iPayerLamports, rootBundleLamports - 10_000, "Payer should receive the rent");
+
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "closedRootBundle")?.data;
+    assertSE(event.rootBundleId, rootBundleId, "Root bundle ID should match");
+    assertSE(event.payer, owner, "Payer should match");
+  });
+
+  it("Closes superseded root bundle after grace period", async () => {
+    const gracePeriod = 3600;
+
+    // Only the owner can set a non-zero grace period.
+    try {
+      await program.methods
+        .setRootBundleCloseGracePeriod(gracePeriod)
+        .accounts({ signer: nonOwner.publicKey, state, program: program.programId })
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Non-owner should not be able to set the grace period");
+    } catch (err: any) {
+      assert.include(err.toString(), "NotOwner", "Expected NotOwner error");
+    }
+    try {
+      await program.methods
+        .setRootBundleCloseGracePeriod(0)
+        .accounts({ signer: owner, state, program: program.programId })
+        .rpc();
+      assert.fail("Zero grace period should not be set");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidRootBundleCloseGracePeriod", "Expected InvalidRootBundleCloseGracePeriod");
+    }
+    await program.methods
+      .setRootBundleCloseGracePeriod(gracePeriod)
+      .accounts({ signer: owner, state, program: program.programId })
+      .rpc();
+    assertSE((await program.account.state.fetch(state)).rootBundleCloseGracePeriod, gracePeriod, "Grace period");
+
+    const relayRootBundle = async () => {
+      const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+      const rootBundleIdBuffer = Buffer.alloc(4);
+      rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+      const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+      const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+      await program.methods
+        .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+        .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+        .rpc();
+      return { rootBundleId, rootBundle };
+    };
+    const closeRootBundle = (rootBundleId: number, rootBundle: PublicKey) =>
+      program.methods
+        .closeRootBundle(rootBundleId)
+        .accounts({ payer: owner, state, rootBundle, program: program.programId })
+        .rpc();
+
+    const { rootBundleId, rootBundle } = await relayRootBundle();
+    const createdAt = (await program.account.rootBundle.fetch(rootBundle)).createdAt;
+
+    // The latest root bundle cannot be closed even after the grace period.
+    await setCurrentTime(program, state, payer, new BN(createdAt + gracePeriod));
+    try {
+      await closeRootBundle(rootBundleId, rootBundle);
+      assert.fail("Latest root bundle should not be closed");
+    } catch (err: any) {
+      assert.include(err.toString(), "RootBundleNotClosable", "Expected RootBundleNotClosable error");
+    }
+
+    // Superseded root bundle cannot be closed before the grace period has passed.
+    await relayRootBundle();
+    await setCurrentTime(program, state, payer, new BN(createdAt + gracePeriod - 1));
+    try {
+      await closeRootBundle(rootBundleId, rootBundle);
+      assert.fail("Superseded root bundle should not be closed before the grace period");
+    } catch (err: any) {
+      assert.include(err.toString(), "RootBundleNotClosable", "Expected RootBundleNotClosable error");
+    }
+
+    await setCurrentTime(program, state, payer, new BN(createdAt + gracePeriod));
+    await closeRootBundle(rootBundleId, rootBundle);
+    assert.isNull(await connection.getAccountInfo(rootBundle), "Root bundle should be closed");
+  });
+
+  it("Closes typed root claims of deleted root bundle", async () => {
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+
+    // Rent for the typed root claims is funded by a different payer than the owner relaying the typed root.
+    const typedRootClaims = getTypedRootClaimsPda(seed, rootBundleId, ORIGIN_REFUND_ROOT_TYPE, program.programId);
+    await program.methods
+      .relayTypedRoot(rootBundleId, ORIGIN_REFUND_ROOT_TYPE, Array.from(crypto.randomBytes(32)))
+      .accounts({ state, rootBundle, typedRootClaims, signer: owner, payer: nonOwner.publicKey })
+      .signers([nonOwner])
+      .rpc();
+    const typedRootClaimsData = await program.account.typedRootClaims.fetch(typedRootClaims);
+    assertSE(typedRootClaimsData.payer, nonOwner.publicKey, "Payer should match");
+
+    const closeTypedRootClaimsAccounts = {
+      payer: nonOwner.publicKey,
+      state,
+      rootBundle,
+      typedRootClaims,
+      program: program.programId,
+    };
+    const closeTypedRootClaims = () =>
+      program.methods
+        .closeTypedRootClaims(rootBundleId, ORIGIN_REFUND_ROOT_TYPE)
+        .accounts(closeTypedRootClaimsAccounts)
+        .rpc();
+
+    // Typed root claims cannot be closed while their root bundle is open.
+    try {
+      await closeTypedRootClaims();
+      assert.fail("Typed root claims should not be closed while the root bundle is open");
+    } catch (err: any) {
+      assert.include(err.toString(), "TypedRootClaimsNotClosable", "Expected TypedRootClaimsNotClosable error");
+    }
+
+    await program.methods
+      .emergencyDeleteRootBundle(rootBundleId)
+      .accounts({ state, rootBundle, signer: owner, closer: owner, program: program.programId })
+      .rpc();
+
+    // Rent can only be returned to the payer of the typed root claims.
+    try {
+      await program.methods
+        .closeTypedRootClaims(rootBundleId, ORIGIN_REFUND_ROOT_TYPE)
+        .accounts({ ...closeTypedRootClaimsAccounts, payer: owner })
+        .rpc();
+      assert.fail("Typed root claims should not be closed to a different payer");
+    } catch (err: any) {
+      assert.include(err.toString(), "ConstraintAddress", "Expected ConstraintAddress error");
+    }
+
+    const typedRootClaimsLamports = (await connection.getAccountInfo(typedRootClaims))!.lamports;
+    const iPayerLamports = await connection.getBalance(nonOwner.publicKey);
+    const tx = await closeTypedRootClaims();
+    const fPayerLamports = await connection.getBalance(nonOwner.publicKey);
+    assert.isNull(await connection.getAccountInfo(typedRootClaims), "Typed root claims should be closed");
+    assertSE(fPayerLamports - iPayerLamports, typedRootClaimsLamports, "Payer should receive the rent");
+
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "closedTypedRootClaims")?.data;
+    assertSE(event.rootBundleId, rootBundleId, "Root bundle ID should match");
+    assertSE(event.rootType, ORIGIN_REFUND_ROOT_TYPE, "Root type should match");
+    assertSE(event.payer, nonOwner.publicKey, "Payer should match");
+  });
+
+  it("Preallocates claimed bitmap for the leaf count", async () => {
+    // Relayed leaf count excludes the last leaf so that it can be used to test leaf ID validation.
+    const relayerRefundLeaves: RelayerRefundLeafType[] = Array.from({ length: 10 }, (_, i) => ({
+      isSolana: true,
+      leafId: new BN(i),
+      chainId: chainId,
+      amountToReturn: new BN(0),
+      mintPublicKey: mint,
+      refundAddresses: [relayerA.publicKey],
+      refundAmounts: [new BN(1000)],
+    }));
+    const leafCount = relayerRefundLeaves.length - 1;
+    const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(merkleTree.getRoot()), Array.from(merkleTree.getRoot()), leafCount)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+    const rootBundleData = await program.account.rootBundle.fetch(rootBundle);
+    assertSE(rootBundleData.claimedBitmap.length, 2, "Claimed bitmap should be allocated for the leaf count");
+    const rootBundleSize = (await connection.getAccountInfo(rootBundle))!.data.length;
+
+    const executeLeaf = async (leaf: RelayerRefundLeafSolana) => {
+      const proof = merkleTree.getProof(leaf).map((p) => Array.from(p));
+      await loadExecuteRelayerRefundLeafParams(program, owner, rootBundleId, leaf, proof);
+      await program.methods
+        .executeRelayerRefundLeaf()
+        .accounts({ signer: owner, state, rootBundle, vault, mint, transferLiability, program: program.programId })
+        .remainingAccounts([{ pubkey: relayerTA, isWritable: true, isSigner: false }])
+        .rpc();
+    };
+
+    // Execute leaves across both bitmap bytes. The root bundle should not grow.
+    await executeLeaf(relayerRefundLeaves[1] as RelayerRefundLeafSolana);
+    await executeLeaf(relayerRefundLeaves[8] as RelayerRefundLeafSolana);
+    assertSE((await connection.getAccountInfo(rootBundle))!.data.length, rootBundleSize, "Root bundle should not grow");
+
+    // Leaf IDs at or above the leaf count are rejected.
+    try {
+      await executeLeaf(relayerRefundLeaves[leafCount] as RelayerRefundLeafSolana);
+      assert.fail("Leaf ID above the leaf count should not be executed");
+    } catch (err: any) {
+      assert.include(err.toString(), "LeafIdExceedsLeafCount", "Expected LeafIdExceedsLeafCount error");
+    }
+
+    const leaves = await program.methods.getRootBundleLeaves(rootBundleId).accounts({ state, rootBundle }).view();
+    assertSE(leaves.leafCount, leafCount, "Leaf count should match");
+    assertSE(leaves.claimedLeafCount, 2, "Claimed leaf count should match");
+    assert.deepEqual(Array.from(leaves.claimedBitmap), [0b10, 0b1], "Claimed bitmap should match");
+  });
+
+  it("Executes multiple relayer refund leaves with a multiproof", async () => {
+    const relayerRefundLeaves: RelayerRefundLeafType[] = Array.from({ length: 4 }, (_, i) => ({
+      isSolana: true,
+      leafId: new BN(i),
+      chainId: chainId,
+      amountToReturn: new BN(i === 3 ? 1000 : 0),
+      mintPublicKey: mint,
+      refundAddresses: [relayerA.publicKey, relayerB.publicKey],
+      refundAmounts: [new BN(1000 + i), new BN(2000 + i)],
+    }));
+    const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(merkleTree.getRoot()), Array(32).fill(0), relayerRefundLeaves.length)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+
+    // Execute all but the first leaf.
+    const multiProof = merkleTree.getMultiProof(relayerRefundLeaves.slice(1));
+    const executeRelayerRefundLeaves = async (leaves: RelayerRefundLeafSolana[]) => {
+      await loadExecuteRelayerRefundLeavesParams(
+        program,
+        owner,
+        rootBundleId,
+        leaves,
+        multiProof.proof.map((p) => Array.from(p)),
+        multiProof.proofFlags
+      );
+      return program.methods
+        .executeRelayerRefundLeaves()
+        .accounts({ signer: owner, state, rootBundle, vault, mint, transferLiability, program: program.programId })
+        .remainingAccounts(
+          leaves.flatMap(() => [
+            { pubkey: relayerTA, isWritable: true, isSigner: false },
+            { pubkey: relayerTB, isWritable: true, isSigner: false },
+          ])
+        )
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })])
+        .rpc();
+    };
+    const leaves = multiProof.leaves as RelayerRefundLeafSolana[];
+
+    // Leaves in a different order than in the multiproof do not rebuild the relayer refund root.
+    try {
+      await executeRelayerRefundLeaves([...leaves].reverse());
+      assert.fail("Execution should have failed for leaves out of multiproof order");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidMerkleProof", "Expected InvalidMerkleProof error");
+    }
+
+    const iRelayerABal = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+    const iRelayerBBal = (await connection.getTokenAccountBalance(relayerTB)).value.amount;
+    const iPendingToHubPool = (await program.account.transferLiability.fetch(transferLiability)).pendingToHubPool;
+    const tx = await executeRelayerRefundLeaves(leaves);
+    const fRelayerABal = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+    const fRelayerBBal = (await connection.getTokenAccountBalance(relayerTB)).value.amount;
+    const fPendingToHubPool = (await program.account.transferLiability.fetch(transferLiability)).pendingToHubPool;
+    const sumRefunds = (i: number) => leaves.reduce((sum, leaf) => sum + BigInt(leaf.refundAmounts[i].toString()), 0n);
+    assertSE(BigInt(fRelayerABal) - BigInt(iRelayerABal), sumRefunds(0), "Relayer A balance");
+    assertSE(BigInt(fRelayerBBal) - BigInt(iRelayerBBal), sumRefunds(1), "Relayer B balance");
+    assertSE(fPendingToHubPool.sub(iPendingToHubPool), new BN(1000), "Pending to HubPool should match");
+
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const executedEvents = events.filter((event) => event.name === "executedRelayerRefundRoot");
+    assertSE(executedEvents.length, leaves.length, "ExecutedRelayerRefundRoot should be emitted for each leaf");
+    for (const [i, leaf] of leaves.entries()) {
+      assertSE(executedEvents[i].data.leafId, leaf.leafId, "Leaf ID should match");
+      assertSE(executedEvents[i].data.rootBundleId, rootBundleId, "Root bundle ID should match");
+    }
+    assertSE(events.filter((event) => event.name === "tokensBridged").length, 1, "TokensBridged should be emitted");
+
+    const rootBundleLeaves = await program.methods
+      .getRootBundleLeaves(rootBundleId)
+      .accounts({ state, rootBundle })
+      .view();
+    assertSE(rootBundleLeaves.claimedLeafCount, leaves.length, "Claimed leaf count should match");
+    assert.deepEqual(Array.from(rootBundleLeaves.claimedBitmap), [0b1110], "Claimed bitmap should match");
+
+    // Executed leaves cannot be executed again.
+    try {
+      await executeRelayerRefundLeaves(leaves);
+      assert.fail("Leaves should not be executed twice");
+    } catch (err: any) {
+      assert.include(err.toString(), "ClaimedMerkleLeaf", "Expected ClaimedMerkleLeaf error");
+    }
+  });
+
+  describe("Execute Max Refunds", () => {
+    const executeMaxRefunds = async (testConfig: {
+      solanaDistributions: number;
+      deferredRefunds: boolean;
+      atomicAccountCreation: boolean;
+    }) => {
+      assert.isTrue(
+        !(testConfig.deferredRefunds && testConfig.atomicAccountCreation),
+        "Incompatible test configuration"
+      );
+      // Add leaves for other EVM chains to have non-empty proofs array to ensure we don't run out of memory when processing.
+      const evmDistributions = 100; // This would fit in 7 proof array elements.
+
+      const refundAccounts: web3.PublicKey[] = []; // These would hold either token accounts or claim accounts.
+      const refundAddresses: web3.PublicKey[] = []; // These are relayer authority addresses used in leaf building.
+      const refundAmounts: BN[] = [];
+
+      for (let i = 0; i < testConfig.solanaDistributions; i++) {
+        // Will create token account later if needed.
+        const tokenOwner = Keypair.generate().publicKey;
+        const tokenAccount = getAssociatedTokenAddressSync(mint, tokenOwner);
+        refundAddresses.push(tokenOwner);
+
+        const [claimAccount] = PublicKey.findProgramAddressSync(
+          [Buffer.from("claim_account"), mint.toBuffer(), tokenOwner.toBuffer()],
+          program.programId
+        );
+
+        if (!testConfig.deferredRefunds && !testConfig.atomicAccountCreation) {
+          await getOrCreateAssociatedTokenAccount(connection, payer, mint, tokenOwner);
+          refundAccounts.push(tokenAccount);
+        } else if (!testConfig.deferredRefunds && testConfig.atomicAccountCreation) {
+          refundAccounts.push(tokenAccount);
+        } else {
+          await program.methods.initializeClaimAccount().accounts({ mint, refundAddress: tokenOwner }).rpc();
+          refundAccounts.push(claimAccount);
+        }
+
+        refundAmounts.push(new BN(randomBigInt(2).toString()));
+      }
+
+      const { relayerRefundLeaves, merkleTree } = buildRelayerRefundMerkleTree({
+        totalEvmDistributions: evmDistributions,
+        totalSolanaDistributions: testConfig.solanaDistributions,
+        mixLeaves: false,
+        chainId: chainId.toNumber(),
+        mint,
+        svmRelayers: refundAddresses,
+        svmRefundAmounts: refundAmounts,
+      });
+
+      const root = merkleTree.getRoot();
+      const proof = merkleTree.getProof(relayerRefundLeaves[0]);
+      const leaf = relayerRefundLeaves[0] as RelayerRefundLeafSolana;
+
+      const stateAccountData = await program.account.state.fetch(state);
+      const rootBundleId = stateAccountData.rootBundleId;
+
+      const rootBundleIdBuffer = Buffer.alloc(4);
+      rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+      const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+      const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+
+      // Relay root bundle
+      const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
+      await program.methods
+        .relayRootBundle(Array.from(root), Array.from(root), null)
+        .accounts(relayRootBundleAccounts)
+        .rpc();
+
+      // Verify valid leaf
+      const proofAsNumbers = proof.map((p) => Array.from(p));
+
+      const [instructionParams] = PublicKey.findProgramAddressSync(
+        [Buffer.from("instruction_params"), owner.toBuffer()],
+        program.programId
+      );
+
+      // We will be using Address Lookup Table (ALT), so to test maximum refunds we better add, not only refund accounts,
+      // but also all static accounts.
+      const staticAccounts = {
+        instructionParams,
+        state: state,
+        rootBundle: rootBundle,
+        signer: owner,
+        vault: vault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        mint: mint,
+        transferLiability,
+        systemProgram: web3.SystemProgram.programId,
+        // Appended by Acnhor `event_cpi` macro:
+        eventAuthority: PublicKey.findProgramAddressSync([Buffer.from("__event_authority")], program.programId)[0],
+        program: program.programId,
+      };
+
+      const executeRemainingAccounts = refundAccounts.map((account) => ({
+        pubkey: account,
+        isWritable: true,
+        isSigner: false,
+      }));
+
+      const createTokenAccountsRemainingAccounts = testConfig.atomicAccountCreation
+        ? refundAddresses.flatMap((authority, index) => [
+            { pubkey: authority, isWritable: false, isSigner: false },
+            { pubkey: refundAccounts[index], isWritable: true, isSigner: false },
+          ])
+        : [];
+
+      // Build the instruction to execute relayer refund leaf and write its instruction args to the data account.
+      await loadExecuteRelayerRefundLeafParams(program, owner, stateAccountData.rootBundleId, leaf, proofAsNumbers);
+
+      const executeInstruction = !testConfig.deferredRefunds
+        ? await program.methods
+            .executeRelayerRefundLeaf()
+            .accounts(staticAccounts)
+            .remainingAccounts(executeRemainingAccounts)
+            .instruction()
+        : await program.methods
+            .executeRelayerRefundLeafDeferred()
+            .accounts(staticAccounts)
+            .remainingAccounts(executeRemainingAccounts)
+            .instruction();
+
+      // Build the instruction to increase the CU limit as the default 200k is not sufficient.
+      const computeBudgetInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
+
+      // Insert atomic ATA creation if needed.
+      const instructions = [computeBudgetInstruction];
+      if (testConfig.atomicAccountCreation)
+        instructions.push(
+          await program.methods
+            .createTokenAccounts()
+            .accounts({ mint, tokenProgram: TOKEN_PROGRAM_ID })
+            .remainingAccounts(createTokenAccountsRemainingAccounts)
+            .instruction()
+        );
+
+      // Add relay refund leaf execution instruction.
+      instructions.push(executeInstruction);
+
+      // Execute using ALT.
+      await sendTransactionWithLookupTable(
+        connection,
+        instructions,
+        (anchor.AnchorProvider.env().wallet as anchor.Wallet).payer
+      );
+
+      // Verify all refund account balances (either token or claim accounts).
+      await new Promise((resolve) => setTimeout(resolve, 1000)); // Make sure account balances have been synced.
+      const refundBalances = await Promise.all(
+        refundAccounts.map(async (account) => {
+          if (!testConfig.deferredRefunds) {
+            return (await connection.getTokenAccountBalance(account)).value.amount;
+          } else {
+            return (await program.account.claimAccount.fetch(account)).amount.toString();
+          }
+        })
+      );
+      refundBalances.forEach((balance, i) => {
         assertSE(balance, refundAmounts[i].toString(), `Refund account ${i} balance should match refund amount`);
       });
     };
@@ -1200,7 +1805,10 @@ describe("svm_spoke.bundle", () => {
       const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
       const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
       let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-      await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+      await program.methods
+        .relayRootBundle(Array.from(root), Array.from(root), null)
+        .accounts(relayRootBundleAccounts)
+        .rpc();
       const proofAsNumbers = proof.map((p) => Array.from(p));
       const executeRelayerRefundLeafAccounts = {
         state: state,
@@ -1276,7 +1884,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [{ pubkey: relayerTA, isWritable: true, isSigner: false }];
 
@@ -1350,7 +1961,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -1440,7 +2054,10 @@ describe("svm_spoke.bundle", () => {
 
       // Relay root bundle
       const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-      await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+      await program.methods
+        .relayRootBundle(Array.from(root), Array.from(root), null)
+        .accounts(relayRootBundleAccounts)
+        .rpc();
 
       // Pass refund addresses in remaining accounts.
       const remainingAccounts = [
@@ -1484,6 +2101,7 @@ describe("svm_spoke.bundle", () => {
       const events = await readEventsUntilFound(connection, tx, [program]);
       const event = events.find((event) => event.name === "executedRelayerRefundRoot")?.data;
       assert.isFalse(event.deferredRefunds, "deferredRefunds should be false");
+      assert.deepEqual(event.refundDeferrals, [false, false], "refundDeferrals should be all false");
     });
 
     it("Deferred refunds in all Claim Accounts", async () => {
@@ -1491,7 +2109,315 @@ describe("svm_spoke.bundle", () => {
       const events = await readEventsUntilFound(connection, tx, [program]);
       const event = events.find((event) => event.name === "executedRelayerRefundRoot")?.data;
       assert.isTrue(event.deferredRefunds, "deferredRefunds should be true");
+      assert.deepEqual(event.refundDeferrals, [true, true], "refundDeferrals should be all true");
+    });
+  });
+
+  it("Hybrid refunds fall back to Claim Accounts for missing Token Accounts", async () => {
+    // Relayer A has a token account, while relayer B only has initialized claim account.
+    const relayerA = Keypair.generate();
+    const relayerB = Keypair.generate();
+    const relayerARefund = new BN(400000);
+    const relayerBRefund = new BN(100000);
+
+    const tokenAccountA = (await getOrCreateAssociatedTokenAccount(connection, payer, mint, relayerA.publicKey))
+      .address;
+    const tokenAccountB = getAssociatedTokenAddressSync(mint, relayerB.publicKey);
+    const [claimAccountA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_account"), mint.toBuffer(), relayerA.publicKey.toBuffer()],
+      program.programId
+    );
+    const [claimAccountB] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_account"), mint.toBuffer(), relayerB.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods.initializeClaimAccount().accounts({ mint, refundAddress: relayerB.publicKey }).rpc();
+
+    const relayerRefundLeaves: RelayerRefundLeafType[] = [];
+    relayerRefundLeaves.push({
+      isSolana: true,
+      leafId: new BN(0),
+      chainId: chainId,
+      amountToReturn: new BN(0),
+      mintPublicKey: mint,
+      refundAddresses: [relayerA.publicKey, relayerB.publicKey],
+      refundAmounts: [relayerARefund, relayerBRefund],
+    });
+
+    const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+
+    const root = merkleTree.getRoot();
+    const proof = merkleTree.getProof(relayerRefundLeaves[0]);
+    const leaf = relayerRefundLeaves[0] as RelayerRefundLeafSolana;
+
+    const stateAccountData = await program.account.state.fetch(state);
+    const rootBundleId = stateAccountData.rootBundleId;
+
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+
+    // Relay root bundle
+    const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
+
+    // Pass token accounts followed by claim accounts in remaining accounts.
+    const remainingAccounts = [
+      { pubkey: tokenAccountA, isWritable: true, isSigner: false },
+      { pubkey: tokenAccountB, isWritable: true, isSigner: false },
+      { pubkey: claimAccountA, isWritable: true, isSigner: false },
+      { pubkey: claimAccountB, isWritable: true, isSigner: false },
+    ];
+
+    const executeRelayerRefundLeafAccounts = {
+      state,
+      rootBundle: rootBundle,
+      signer: owner,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      mint,
+      transferLiability,
+      systemProgram: web3.SystemProgram.programId,
+      program: program.programId,
+    };
+    const proofAsNumbers = proof.map((p) => Array.from(p));
+    await loadExecuteRelayerRefundLeafParams(program, owner, stateAccountData.rootBundleId, leaf, proofAsNumbers);
+
+    const tx = await program.methods
+      .executeRelayerRefundLeafHybrid()
+      .accounts(executeRelayerRefundLeafAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    // Relayer A should be refunded directly and relayer B should get the refund in the claim account.
+    const tokenAccountABal = (await connection.getTokenAccountBalance(tokenAccountA)).value.amount;
+    assertSE(tokenAccountABal, relayerARefund, "Relayer A should be refunded to token account");
+    const claimAccountBData = await program.account.claimAccount.fetch(claimAccountB);
+    assertSE(claimAccountBData.amount, relayerBRefund, "Relayer B refund should be deferred to claim account");
+    const claimAccountAData = await program.account.claimAccount.fetchNullable(claimAccountA);
+    assert.isNull(claimAccountAData, "Relayer A claim account should not be created");
+
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "executedRelayerRefundRoot")?.data;
+    assert.isTrue(event.deferredRefunds, "deferredRefunds should be true");
+    assert.deepEqual(event.refundDeferrals, [false, true], "refundDeferrals should match per refund address");
+  });
+
+  it("Hybrid refunds fall back to Claim Accounts for Token-2022 accounts requiring memos", async () => {
+    // Required memo transfers are available only for the 2022 token program.
+    const mint2022 = await createMint(connection, payer, owner, owner, 6, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+    const getTokenAccount = async (tokenOwner: PublicKey, allowOwnerOffCurve = false) =>
+      (
+        await getOrCreateAssociatedTokenAccount(
+          connection,
+          payer,
+          mint2022,
+          tokenOwner,
+          allowOwnerOffCurve,
+          undefined,
+          undefined,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).address;
+    const vault2022 = await getTokenAccount(state, true);
+    await mintTo(
+      connection,
+      payer,
+      mint2022,
+      vault2022,
+      provider.publicKey,
+      initialMintAmount,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    // Relayer A has a plain token account, while relayer B requires memos on incoming transfers.
+    const relayerA = Keypair.generate();
+    const relayerB = Keypair.generate();
+    const relayerARefund = new BN(400000);
+    const relayerBRefund = new BN(100000);
+
+    const tokenAccountA = await getTokenAccount(relayerA.publicKey);
+    const tokenAccountB = await getTokenAccount(relayerB.publicKey);
+    const enableRequiredMemoTransfersTx = new Transaction().add(
+      createReallocateInstruction(
+        tokenAccountB,
+        payer.publicKey,
+        [ExtensionType.MemoTransfer],
+        relayerB.publicKey,
+        [],
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createEnableRequiredMemoTransfersInstruction(tokenAccountB, relayerB.publicKey, [], TOKEN_2022_PROGRAM_ID)
+    );
+    await sendAndConfirmTransaction(connection, enableRequiredMemoTransfersTx, [payer, relayerB]);
+
+    const [claimAccountA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_account"), mint2022.toBuffer(), relayerA.publicKey.toBuffer()],
+      program.programId
+    );
+    const [claimAccountB] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim_account"), mint2022.toBuffer(), relayerB.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .initializeClaimAccount()
+      .accounts({ mint: mint2022, refundAddress: relayerB.publicKey })
+      .rpc();
+
+    const relayerRefundLeaves: RelayerRefundLeafType[] = [];
+    relayerRefundLeaves.push({
+      isSolana: true,
+      leafId: new BN(0),
+      chainId: chainId,
+      amountToReturn: new BN(0),
+      mintPublicKey: mint2022,
+      refundAddresses: [relayerA.publicKey, relayerB.publicKey],
+      refundAmounts: [relayerARefund, relayerBRefund],
     });
+
+    const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+
+    const root = merkleTree.getRoot();
+    const proof = merkleTree.getProof(relayerRefundLeaves[0]);
+    const leaf = relayerRefundLeaves[0] as RelayerRefundLeafSolana;
+
+    const stateAccountData = await program.account.state.fetch(state);
+    const rootBundleId = stateAccountData.rootBundleId;
+
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+
+    // Relay root bundle
+    const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
+
+    // Pass token accounts followed by claim accounts in remaining accounts.
+    const remainingAccounts = [
+      { pubkey: tokenAccountA, isWritable: true, isSigner: false },
+      { pubkey: tokenAccountB, isWritable: true, isSigner: false },
+      { pubkey: claimAccountA, isWritable: true, isSigner: false },
+      { pubkey: claimAccountB, isWritable: true, isSigner: false },
+    ];
+
+    const [transferLiability2022] = PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer_liability"), mint2022.toBuffer()],
+      program.programId
+    );
+    const executeRelayerRefundLeafAccounts = {
+      state,
+      rootBundle: rootBundle,
+      signer: owner,
+      vault: vault2022,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      mint: mint2022,
+      transferLiability: transferLiability2022,
+      systemProgram: web3.SystemProgram.programId,
+      program: program.programId,
+    };
+    const proofAsNumbers = proof.map((p) => Array.from(p));
+    await loadExecuteRelayerRefundLeafParams(program, owner, stateAccountData.rootBundleId, leaf, proofAsNumbers);
+
+    const tx = await program.methods
+      .executeRelayerRefundLeafHybrid()
+      .accounts(executeRelayerRefundLeafAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    // Relayer A should be refunded directly and relayer B should get the refund in the claim account.
+    const tokenAccountABal = (await connection.getTokenAccountBalance(tokenAccountA)).value.amount;
+    assertSE(tokenAccountABal, relayerARefund, "Relayer A should be refunded to token account");
+    const tokenAccountBBal = (await connection.getTokenAccountBalance(tokenAccountB)).value.amount;
+    assertSE(tokenAccountBBal, 0, "Relayer B should not be refunded to token account requiring memos");
+    const claimAccountBData = await program.account.claimAccount.fetch(claimAccountB);
+    assertSE(claimAccountBData.amount, relayerBRefund, "Relayer B refund should be deferred to claim account");
+
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "executedRelayerRefundRoot")?.data;
+    assert.deepEqual(event.refundDeferrals, [false, true], "refundDeferrals should match per refund address");
+  });
+
+  it("Executes refund leaves larger than the outflow volume once per window", async () => {
+    const setMaxOutflowVolume = async (maxOutflowVolume: BN) =>
+      program.methods
+        .setTokenLimits(mint, new BN(0), new BN(0), maxOutflowVolume, 3600)
+        .accounts({ signer: owner, payer: owner, state, program: program.programId })
+        .rpc();
+
+    // Both leaves refund more than the max outflow volume in the window.
+    const refundAmount = new BN(500000);
+    const relayerRefundLeaves: RelayerRefundLeafType[] = [0, 1].map((leafId) => ({
+      isSolana: true,
+      leafId: new BN(leafId),
+      chainId: chainId,
+      amountToReturn: new BN(0),
+      mintPublicKey: mint,
+      refundAddresses: [relayerA.publicKey],
+      refundAmounts: [refundAmount],
+    }));
+    const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+    const root = merkleTree.getRoot();
+
+    const stateAccountData = await program.account.state.fetch(state);
+    const rootBundleId = stateAccountData.rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+
+    const executeLeaf = async (leafIndex: number) => {
+      const leaf = relayerRefundLeaves[leafIndex] as RelayerRefundLeafSolana;
+      const proofAsNumbers = merkleTree.getProof(leaf).map((p) => Array.from(p));
+      await loadExecuteRelayerRefundLeafParams(program, owner, rootBundleId, leaf, proofAsNumbers);
+      return program.methods
+        .executeRelayerRefundLeaf()
+        .accounts({
+          signer: owner,
+          state,
+          rootBundle,
+          vault,
+          mint,
+          transferLiability,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
+          program: program.programId,
+        })
+        .remainingAccounts([{ pubkey: relayerTA, isWritable: true, isSigner: false }])
+        .rpc();
+    };
+
+    await setMaxOutflowVolume(refundAmount.divn(2));
+
+    // First leaf is admitted at the start of the window, but it exhausts the outflow volume for the second leaf.
+    await executeLeaf(0);
+    try {
+      await executeLeaf(1);
+      assert.fail("Second leaf should exceed the outflow volume in the window");
+    } catch (err: any) {
+      assert.include(err.toString(), "OutflowVolumeLimitExceeded", "Expected OutflowVolumeLimitExceeded error");
+    }
+
+    // Second leaf is admitted once the window resets.
+    const currentTime = (await program.account.state.fetch(state)).currentTime;
+    await setCurrentTime(program, state, payer, new BN(currentTime + 3600));
+    await executeLeaf(1);
+
+    // Disable the outflow volume limit for other tests.
+    await setMaxOutflowVolume(new BN(0));
   });
 
   it("Cannot execute relayer refund leaf with insufficient pool balance", async () => {
@@ -1527,7 +2453,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [{ pubkey: relayerTA, isWritable: true, isSigner: false }];
 
@@ -1619,7 +2548,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -1710,7 +2642,10 @@ describe("svm_spoke.bundle", () => {
 
     // Relay root bundle
     let relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     const remainingAccounts = [
       { pubkey: relayerTA, isWritable: true, isSigner: false },
@@ -1822,7 +2757,10 @@ describe("svm_spoke.bundle", () => {
 
       // Relay root bundle
       const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-      await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+      await program.methods
+        .relayRootBundle(Array.from(root), Array.from(root), null)
+        .accounts(relayRootBundleAccounts)
+        .rpc();
 
       // Verify valid leaf
       const proofAsNumbers = proof.map((p) => Array.from(p));
@@ -1959,4 +2897,165 @@ describe("svm_spoke.bundle", () => {
       await executeMaxRefundClaims({ solanaDistributions, useAddressLookup: true, separatePhases: true });
     });
   });
+
+  describe("Timelocked root bundles", () => {
+    const timelock = 3600;
+    let timelockState: PublicKey, timelockSeed: BN, timelockVault: PublicKey;
+
+    beforeEach(async () => {
+      // Use separate state as the local owner cannot lower the timelock for the remaining tests.
+      ({ state: timelockState, seed: timelockSeed } = await initializeState());
+      timelockVault = (await getOrCreateAssociatedTokenAccount(connection, payer, mint, timelockState, true)).address;
+      await mintTo(connection, payer, mint, timelockVault, provider.publicKey, initialMintAmount);
+
+      await program.methods
+        .setRootBundleTimelock(timelock)
+        .accounts({ signer: owner, state: timelockState, program: program.programId })
+        .rpc();
+    });
+
+    const relayTimelockedRootBundle = async (relayerRefundRoot: number[]) => {
+      const rootBundleId = (await program.account.state.fetch(timelockState)).rootBundleId;
+      const rootBundleIdBuffer = Buffer.alloc(4);
+      rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+      const seeds = [Buffer.from("root_bundle"), timelockSeed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+      const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+      const tx = await program.methods
+        .relayRootBundle(relayerRefundRoot, Array(32).fill(0), 1)
+        .accounts({ state: timelockState, rootBundle, signer: owner, payer: owner, program: program.programId })
+        .rpc();
+      return { rootBundleId, rootBundle, tx };
+    };
+
+    it("Local owner can only extend the timelock", async () => {
+      try {
+        await program.methods
+          .setRootBundleTimelock(timelock - 1)
+          .accounts({ signer: owner, state: timelockState, program: program.programId })
+          .rpc();
+        assert.fail("Local owner should not be able to shorten the timelock");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidRootBundleTimelock", "Expected InvalidRootBundleTimelock error");
+      }
+      await program.methods
+        .setRootBundleTimelock(timelock + 1)
+        .accounts({ signer: owner, state: timelockState, program: program.programId })
+        .rpc();
+      assertSE((await program.account.state.fetch(timelockState)).rootBundleTimelock, timelock + 1, "Timelock");
+    });
+
+    it("Executes leaves only after the root bundle is activated", async () => {
+      const relayerRefundLeaves: RelayerRefundLeafType[] = [
+        {
+          isSolana: true,
+          leafId: new BN(0),
+          chainId: chainId,
+          amountToReturn: new BN(0),
+          mintPublicKey: mint,
+          refundAddresses: [relayerA.publicKey],
+          refundAmounts: [new BN(100000)],
+        },
+      ];
+      const merkleTree = new MerkleTree<RelayerRefundLeafType>(relayerRefundLeaves, relayerRefundHashFn);
+      const leaf = relayerRefundLeaves[0] as RelayerRefundLeafSolana;
+
+      const { rootBundleId, rootBundle, tx } = await relayTimelockedRootBundle(Array.from(merkleTree.getRoot()));
+      const { createdAt, pendingUntil } = await program.account.rootBundle.fetch(rootBundle);
+      assertSE(pendingUntil, createdAt + timelock, "Root bundle should be pending until the timelock passes");
+
+      let events = await readEventsUntilFound(connection, tx, [program]);
+      const pendingEvent = events.find((event) => event.name === "pendingRootBundle")?.data;
+      assertSE(pendingEvent.rootBundleId, rootBundleId, "Root bundle ID should match");
+      assertSE(pendingEvent.pendingUntil, createdAt + timelock, "Pending until should match");
+
+      // Leaves cannot be executed against a pending root bundle.
+      await loadExecuteRelayerRefundLeafParams(
+        program,
+        owner,
+        rootBundleId,
+        leaf,
+        merkleTree.getProof(leaf).map((p) => Array.from(p))
+      );
+      const executeAccounts = {
+        signer: owner,
+        state: timelockState,
+        rootBundle,
+        vault: timelockVault,
+        mint,
+        transferLiability,
+        program: program.programId,
+      };
+      const executeRelayerRefundLeaf = () =>
+        program.methods
+          .executeRelayerRefundLeaf()
+          .accounts(executeAccounts)
+          .remainingAccounts([{ pubkey: relayerTA, isWritable: true, isSigner: false }])
+          .rpc();
+      try {
+        await executeRelayerRefundLeaf();
+        assert.fail("Leaf should not be executed against a pending root bundle");
+      } catch (err: any) {
+        assert.include(err.toString(), "RootBundlePending", "Expected RootBundlePending error");
+      }
+
+      // Root bundle cannot be activated before the timelock has passed.
+      const activateAccounts = { state: timelockState, rootBundle, program: program.programId };
+      await setCurrentTime(program, timelockState, payer, new BN(createdAt + timelock - 1));
+      try {
+        await program.methods.activateRootBundle(rootBundleId).accounts(activateAccounts).rpc();
+        assert.fail("Root bundle should not be activated before the timelock has passed");
+      } catch (err: any) {
+        assert.include(err.toString(), "RootBundleTimelockNotPassed", "Expected RootBundleTimelockNotPassed error");
+      }
+
+      // Activation does not require any signer once the timelock has passed.
+      await setCurrentTime(program, timelockState, payer, new BN(createdAt + timelock));
+      const activateTx = await program.methods.activateRootBundle(rootBundleId).accounts(activateAccounts).rpc();
+      events = await readEventsUntilFound(connection, activateTx, [program]);
+      const activatedEvent = events.find((event) => event.name === "activatedRootBundle")?.data;
+      assertSE(activatedEvent.rootBundleId, rootBundleId, "Root bundle ID should match");
+      assertSE((await program.account.rootBundle.fetch(rootBundle)).pendingUntil, 0, "Root bundle should be active");
+
+      const iRelayerBalance = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+      await executeRelayerRefundLeaf();
+      const fRelayerBalance = (await connection.getTokenAccountBalance(relayerTA)).value.amount;
+      assertSE(BigInt(fRelayerBalance) - BigInt(iRelayerBalance), BigInt(100000), "Relayer should be refunded");
+    });
+
+    it("Relays typed roots locally only to pending root bundles", async () => {
+      const { rootBundleId, rootBundle } = await relayTimelockedRootBundle(Array.from(crypto.randomBytes(32)));
+      const { createdAt } = await program.account.rootBundle.fetch(rootBundle);
+      const relayTypedRoot = (rootType: number) =>
+        program.methods
+          .relayTypedRoot(rootBundleId, rootType, Array.from(crypto.randomBytes(32)))
+          .accounts({
+            state: timelockState,
+            rootBundle,
+            typedRootClaims: getTypedRootClaimsPda(timelockSeed, rootBundleId, rootType, program.programId),
+            signer: owner,
+            payer: owner,
+            program: program.programId,
+          })
+          .rpc();
+
+      await relayTypedRoot(2);
+      const { pendingUntil } = await program.account.rootBundle.fetch(rootBundle);
+      assert.isAtLeast(pendingUntil, createdAt + timelock, "Root bundle should stay pending");
+
+      await setCurrentTime(program, timelockState, payer, new BN(pendingUntil));
+      await program.methods
+        .activateRootBundle(rootBundleId)
+        .accounts({ state: timelockState, rootBundle, program: program.programId })
+        .rpc();
+
+      // Typed roots relayed locally to active root bundles would bypass the timelock.
+      try {
+        await relayTypedRoot(3);
+        assert.fail("Typed root should not be relayed locally to an active root bundle");
+      } catch (err: any) {
+        assert.include(err.toString(), "RootBundleNotPending", "Expected RootBundleNotPending error");
+      }
+      assertSE((await program.account.rootBundle.fetch(rootBundle)).pendingUntil, 0, "Root bundle should stay active");
+    });
+  });
 });
@@ -21,16 +21,27 @@ import {
   createAssociatedTokenAccountIdempotentInstruction,
   createCloseAccountInstruction,
   createEnableCpiGuardInstruction,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
   createMint,
   createReallocateInstruction,
   createSyncNativeInstruction,
   getAccount,
   getAssociatedTokenAddressSync,
   getMinimumBalanceForRentExemptAccount,
+  getMintLen,
   getOrCreateAssociatedTokenAccount,
   mintTo,
 } from "@solana/spl-token";
-import { Keypair, PublicKey, SystemProgram, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
 import { BigNumber, ethers } from "ethers";
 import { SvmSpokeClient, createDefaultTransaction, signAndSendTransaction } from "../../src/svm";
 import { DepositInput } from "../../src/svm/clients/SvmSpoke";
@@ -39,6 +50,8 @@ import {
   getDepositNowSeedHash,
   getDepositPda,
   getDepositSeedHash,
+  getRoutePda,
+  getUpdateDepositDetailsHash,
   intToU8Array32,
   readEventsUntilFound,
   u8Array32ToBigNumber,
@@ -59,6 +72,7 @@ const {
   assertSE,
   assert,
   getCurrentTime,
+  chainId,
   depositQuoteTimeBuffer,
   fillDeadlineBuffer,
   getOrCreateVaultAta,
@@ -82,6 +96,7 @@ describe("svm_spoke.deposit", () => {
   // Re-used between tests to simplify props.
   type DepositAccounts = {
     state: PublicKey;
+    route: PublicKey;
     delegate: PublicKey;
     signer: PublicKey;
     depositorTokenAccount: PublicKey;
@@ -110,8 +125,18 @@ describe("svm_spoke.deposit", () => {
     await mintTo(connection, payer, inputToken, depositorTA, owner, seedBalance, undefined, undefined, tokenProgram);
   };
 
+  const setEnableRoute = async (originToken: PublicKey, enabled: boolean) => {
+    const route = getRoutePda(originToken, seed, depositData.destinationChainId, program.programId);
+    const tx = await program.methods
+      .setEnableRoute(originToken, depositData.destinationChainId, enabled)
+      .accounts({ signer: owner, payer: owner, state, route, program: program.programId })
+      .rpc();
+    return { route, tx };
+  };
+
   const createVault = async () => {
     vault = await getOrCreateVaultAta(payer, inputToken, state);
+    const { route } = await setEnableRoute(inputToken, true);
 
     // Set known fields in the depositData.
     depositData.depositor = depositor.publicKey;
@@ -119,6 +144,7 @@ describe("svm_spoke.deposit", () => {
 
     depositAccounts = {
       state,
+      route,
       delegate: getDepositPda(depositData as DepositDataSeed, program.programId),
       signer: depositor.publicKey,
       depositorTokenAccount: depositorTA,
@@ -135,6 +161,12 @@ describe("svm_spoke.deposit", () => {
   ) => {
     const delegatePda = getDepositPda(depositData as DepositDataSeed, program.programId);
     calledDepositAccounts.delegate = delegatePda;
+    calledDepositAccounts.route = getRoutePda(
+      depositData.inputToken!,
+      seed,
+      depositData.destinationChainId,
+      program.programId
+    );
 
     // Delegate delegate PDA to pull depositor tokens.
     const approveIx = await createApproveCheckedInstruction(
@@ -607,6 +639,47 @@ describe("svm_spoke.deposit", () => {
     assertSE(vaultAccount.amount, depositData.inputAmount, "Vault balance should be increased by the deposited amount");
   });
 
+  it("Fails to deposit mint with transfer fee extension", async () => {
+    tokenProgram = TOKEN_2022_PROGRAM_ID;
+    const mintKeypair = Keypair.generate();
+    inputToken = mintKeypair.publicKey;
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const createMintTx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: inputToken,
+        space: mintLen,
+        lamports: await connection.getMinimumBalanceForRentExemption(mintLen),
+        programId: tokenProgram,
+      }),
+      createInitializeTransferFeeConfigInstruction(inputToken, owner, owner, 100, BigInt(1_000_000), tokenProgram),
+      createInitializeMintInstruction(inputToken, tokenDecimals, owner, null, tokenProgram)
+    );
+    await sendAndConfirmTransaction(connection, createMintTx, [payer, mintKeypair]);
+
+    depositorTA = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        payer,
+        inputToken,
+        depositor.publicKey,
+        undefined,
+        undefined,
+        undefined,
+        tokenProgram
+      )
+    ).address;
+    await mintTo(connection, payer, inputToken, depositorTA, owner, seedBalance, undefined, undefined, tokenProgram);
+    await createVault();
+
+    try {
+      await approvedDeposit(depositData);
+      assert.fail("Should not be able to deposit mint with transfer fee extension");
+    } catch (err: any) {
+      assert.include(err.toString(), "UnsupportedTransferFeeMint", "Expected UnsupportedTransferFeeMint error");
+    }
+  });
+
   it("Deposit without approval fails", async () => {
     const depositDataValues = Object.values(depositData) as DepositDataValues;
 
@@ -744,6 +817,80 @@ describe("svm_spoke.deposit", () => {
     );
   });
 
+  it("Deposits native SOL via deposit_native", async () => {
+    // Fund depositor account with SOL.
+    const nativeAmount = 1_000_000_000; // 1 SOL
+    await connection.requestAirdrop(depositor.publicKey, nativeAmount * 2); // Add buffer for transaction fees.
+
+    // Setup wSOL as the input token without creating the depositor's wSOL account.
+    inputToken = NATIVE_MINT;
+    await createVault();
+
+    const nativeDepositData = {
+      ...depositData,
+      inputAmount: new BN(nativeAmount),
+      outputAmount: intToU8Array32(nativeAmount),
+    };
+    const depositDataValues = Object.values(nativeDepositData) as DepositDataValues;
+
+    const iVaultAmount = (await getAccount(connection, vault, undefined, tokenProgram)).amount;
+
+    const tx = await program.methods
+      .depositNative(...depositDataValues)
+      .accounts({
+        signer: depositor.publicKey,
+        state,
+        route: depositAccounts.route,
+        vault,
+        mint: inputToken,
+        tokenProgram,
+        program: program.programId,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const fVaultAmount = (await getAccount(connection, vault, undefined, tokenProgram)).amount;
+    assertSE(
+      fVaultAmount,
+      iVaultAmount + BigInt(nativeAmount),
+      "Vault balance should be increased by the deposited amount"
+    );
+
+    // The emitted event should be the same as for a wSOL deposit.
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "fundsDeposited")?.data;
+    assertSE(event.inputToken, NATIVE_MINT, "Input token should be the native mint");
+    assertSE(event.inputAmount, nativeDepositData.inputAmount, "Input amount should match");
+    assertSE(event.depositor, depositor.publicKey, "Depositor should match");
+  });
+
+  it("Fails to deposit native SOL on behalf of another depositor", async () => {
+    inputToken = NATIVE_MINT;
+    await createVault();
+
+    const nativeDepositData = { ...depositData, depositor: Keypair.generate().publicKey };
+    const depositDataValues = Object.values(nativeDepositData) as DepositDataValues;
+
+    try {
+      await program.methods
+        .depositNative(...depositDataValues)
+        .accounts({
+          signer: depositor.publicKey,
+          state,
+          route: depositAccounts.route,
+          vault,
+          mint: inputToken,
+          tokenProgram,
+          program: program.programId,
+        })
+        .signers([depositor])
+        .rpc();
+      assert.fail("Should not be able to deposit native SOL on behalf of another depositor");
+    } catch (err: any) {
+      assert.include(err.toString(), "Error Code: InvalidNativeDepositor", "Expected InvalidNativeDepositor error");
+    }
+  });
+
   it("Deposits tokens to a new vault", async () => {
     // Create new input token without creating a new vault for it.
     await setupInputToken();
@@ -758,6 +905,7 @@ describe("svm_spoke.deposit", () => {
     );
 
     // Update global variables using the new input token.
+    await setEnableRoute(inputToken, true);
     depositData.inputToken = inputToken;
     depositAccounts.depositorTokenAccount = depositorTA;
     depositAccounts.vault = vault;
@@ -793,6 +941,194 @@ describe("svm_spoke.deposit", () => {
     }
   });
 
+  it("Enables and disables deposit route", async () => {
+    // Disable the route and verify the EnabledDepositRoute event
+    const { route, tx } = await setEnableRoute(inputToken, false);
+    assert.isFalse((await program.account.route.fetch(route)).enabled, "Route should be disabled");
+    const events = await readEventsUntilFound(connection, tx, [program]);
+    const event = events.find((event) => event.name === "enabledDepositRoute")?.data;
+    assertSE(event.originToken, inputToken, "Origin token should match");
+    assertSE(event.destinationChainId, depositData.destinationChainId, "Destination chain ID should match");
+    assert.isFalse(event.enabled, "Enabled should be false");
+
+    // Deposits on the disabled route fail
+    try {
+      await approvedDeposit(depositData);
+      assert.fail("Should not be able to deposit on a disabled route");
+    } catch (err: any) {
+      assert.include(err.toString(), "Error Code: DisabledRoute", "Expected DisabledRoute error");
+    }
+
+    // Deposits on the route succeed after enabling it again
+    await setEnableRoute(inputToken, true);
+    await approvedDeposit(depositData);
+    const vaultAccount = await getAccount(connection, vault);
+    assertSE(vaultAccount.amount, depositData.inputAmount, "Vault balance should equal the deposited amount");
+  });
+
+  it("Fails to deposit on a route that was never enabled", async () => {
+    const disabledDepositData = { ...depositData, destinationChainId: depositData.destinationChainId.addn(1) };
+
+    try {
+      await approvedDeposit(disabledDepositData);
+      assert.fail("Should not be able to deposit on a route that was never enabled");
+    } catch (err: any) {
+      assert.include(err.toString(), "AccountNotInitialized", "Expected AccountNotInitialized error");
+    }
+  });
+
+  it("Only owner can enable deposit route", async () => {
+    const nonOwner = Keypair.generate();
+    const route = getRoutePda(inputToken, seed, depositData.destinationChainId, program.programId);
+    try {
+      await program.methods
+        .setEnableRoute(inputToken, depositData.destinationChainId, true)
+        .accounts({ signer: nonOwner.publicKey, payer: owner, state, route, program: program.programId })
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Non-owner should not be able to enable deposit route");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
+
+  describe("token limits", () => {
+    const setTokenLimits = async (maxDepositAmount: BN, maxDepositVolume: BN, windowLength: number) =>
+      program.methods
+        .setTokenLimits(inputToken, maxDepositAmount, maxDepositVolume, new BN(0), windowLength)
+        .accounts({ signer: owner, payer: owner, state, program: program.programId })
+        .rpc();
+
+    it("Fails to deposit more than the max deposit amount", async () => {
+      await setTokenLimits(depositData.inputAmount.subn(1), new BN(0), 3600);
+
+      try {
+        await approvedDeposit(depositData);
+        assert.fail("Should not be able to deposit more than the max deposit amount");
+      } catch (err: any) {
+        assert.include(err.toString(), "DepositAmountLimitExceeded", "Expected DepositAmountLimitExceeded error");
+      }
+    });
+
+    it("Fails to deposit more than the deposit volume in the window", async () => {
+      await setTokenLimits(new BN(0), depositData.inputAmount.muln(2), 3600);
+
+      // Two deposits fit within the window volume and the third one trips the limit.
+      await approvedDeposit(depositData);
+      await approvedDeposit(depositData);
+      try {
+        await approvedDeposit(depositData);
+        assert.fail("Should not be able to deposit more than the deposit volume in the window");
+      } catch (err: any) {
+        assert.include(err.toString(), "DepositVolumeLimitExceeded", "Expected DepositVolumeLimitExceeded error");
+      }
+    });
+
+    it("Reports remaining capacity", async () => {
+      const maxDepositVolume = depositData.inputAmount.muln(3);
+      await setTokenLimits(depositData.inputAmount, maxDepositVolume, 3600);
+      await approvedDeposit(depositData);
+
+      const capacity = await program.methods.getTokenLimitsCapacity(inputToken).accounts({ state }).view();
+      assertSE(capacity.maxDepositAmount, depositData.inputAmount, "Max deposit amount should match");
+      assertSE(
+        capacity.remainingDepositVolume,
+        maxDepositVolume.sub(depositData.inputAmount),
+        "Remaining deposit volume should be reduced by the deposit"
+      );
+      assertSE(capacity.remainingOutflowVolume, "18446744073709551615", "Disabled outflow limit should be u64::MAX");
+      assertSE(capacity.remainingFillVolume, "18446744073709551615", "Disabled fill limit should be u64::MAX");
+    });
+
+    it("Only owner can set token limits", async () => {
+      const nonOwner = Keypair.generate();
+      try {
+        await program.methods
+          .setTokenLimits(inputToken, new BN(1), new BN(1), new BN(1), 3600)
+          .accounts({ signer: nonOwner.publicKey, payer: owner, state, program: program.programId })
+          .signers([nonOwner])
+          .rpc();
+        assert.fail("Non-owner should not be able to set token limits");
+      } catch (err: any) {
+        assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+      }
+    });
+  });
+
+  describe("speed up deposit", () => {
+    const depositId = intToU8Array32(1);
+    const updatedOutputAmount = intToU8Array32(depositData.inputAmount.subn(1));
+    const updatedRecipient = Keypair.generate().publicKey;
+    const updatedMessage = Buffer.from("updated message");
+
+    const speedUpDeposit = async (signingKeypair: Keypair, withEd25519Ix = true) => {
+      const updateHash = getUpdateDepositDetailsHash(
+        depositId,
+        chainId,
+        updatedOutputAmount,
+        updatedRecipient,
+        updatedMessage
+      );
+      const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signingKeypair.secretKey,
+        message: updateHash,
+      });
+      // Signature is located after the offsets (2 bytes header + 14 bytes offsets) and the public key (32 bytes).
+      const signature = Buffer.from(ed25519Ix.data.subarray(48, 112));
+
+      const speedUpIx = await program.methods
+        .speedUpDeposit(
+          depositor.publicKey,
+          depositId,
+          updatedOutputAmount,
+          updatedRecipient,
+          updatedMessage,
+          signature
+        )
+        .accounts({ state, instructions: SYSVAR_INSTRUCTIONS_PUBKEY, program: program.programId })
+        .instruction();
+
+      const tx = withEd25519Ix ? new Transaction().add(ed25519Ix, speedUpIx) : new Transaction().add(speedUpIx);
+      return { txSignature: await sendAndConfirmTransaction(connection, tx, [payer]), signature };
+    };
+
+    it("Speeds up deposit with depositor's Ed25519 signature", async () => {
+      const { txSignature, signature } = await speedUpDeposit(depositor);
+
+      const events = await readEventsUntilFound(connection, txSignature, [program]);
+      const event = events.find((event) => event.name === "requestedSpeedUpDeposit")?.data;
+      assert.isNotNull(event, "RequestedSpeedUpDeposit event should be emitted");
+      assertSE(event.depositor, depositor.publicKey, "Depositor should match");
+      assertSE(event.depositId, depositId, "DepositId should match");
+      assertSE(event.updatedOutputAmount, updatedOutputAmount, "UpdatedOutputAmount should match");
+      assertSE(event.updatedRecipient, updatedRecipient, "UpdatedRecipient should match");
+      assertSE(Buffer.from(event.updatedMessage), updatedMessage, "UpdatedMessage should match");
+      assertSE(Buffer.from(event.depositorSignature), signature, "DepositorSignature should match");
+    });
+
+    it("Fails to speed up deposit with a signature from another key", async () => {
+      try {
+        await speedUpDeposit(Keypair.generate());
+        assert.fail("Should not be able to speed up deposit with a non-depositor signature");
+      } catch (err: any) {
+        assert.include(err.toString(), "Invalid depositor signature!", "Expected InvalidDepositorSignature error");
+      }
+    });
+
+    it("Fails to speed up deposit without Ed25519 verification instruction", async () => {
+      try {
+        await speedUpDeposit(depositor, false);
+        assert.fail("Should not be able to speed up deposit without Ed25519 verification instruction");
+      } catch (err: any) {
+        assert.include(
+          err.toString(),
+          "Invalid Ed25519 signature verification instruction!",
+          "Expected InvalidEd25519Instruction error"
+        );
+      }
+    });
+  });
+
   describe("codama client and solana kit", () => {
     it("Deposit with solana kit and codama client", async () => {
       // typescript is not happy with the depositData object
@@ -841,6 +1177,7 @@ describe("svm_spoke.deposit", () => {
 
       const formattedAccounts = {
         state: address(depositAccounts.state.toString()),
+        route: address(depositAccounts.route.toString()),
         delegate: address(getDepositPda(depositData as DepositDataSeed, program.programId).toString()),
         depositorTokenAccount: address(depositAccounts.depositorTokenAccount.toString()),
         mint: address(depositAccounts.mint.toString()),
@@ -24,10 +24,20 @@ import {
   getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  NATIVE_MINT,
   TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction, TransactionInstruction } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { ethers } from "ethers";
 import {
   createLookupTable,
   createDefaultTransaction,
@@ -39,9 +49,12 @@ import {
 import { FillRelayAsyncInput } from "../../src/svm/clients/SvmSpoke";
 import {
   calculateRelayHashUint8Array,
+  evmAddressToPublicKey,
   hashNonEmptyMessage,
   intToU8Array32,
   getFillRelayDelegatePda,
+  getFillRelaysBatchDelegatePda,
+  getUpdateDepositDetailsHash,
   readEventsUntilFound,
   sendTransactionWithLookupTable as sendTransactionWithLookupTableV1,
 } from "../../src/svm/web3-v1";
@@ -90,6 +103,7 @@ describe("svm_spoke.fill", () => {
     relayerTokenAccount: PublicKey;
     recipientTokenAccount: PublicKey;
     fillStatus: PublicKey;
+    fillStatusBucket?: PublicKey;
     tokenProgram: PublicKey;
     associatedTokenProgram: PublicKey;
     systemProgram: PublicKey;
@@ -367,6 +381,58 @@ describe("svm_spoke.fill", () => {
     assert.isNull(fillStatusAccountAfter, "Fill PDA should be closed after closing");
   });
 
+  it("Closes multiple expired fill PDAs by anyone", async () => {
+    const fillStatuses: PublicKey[] = [];
+    for (let i = 0; i < 2; i++) {
+      updateRelayData({ ...relayData, depositId: intToU8Array32(Math.floor(Math.random() * 1000000)) });
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await approvedFillRelay([relayHash, relayData, new BN(1), relayer.publicKey]);
+      fillStatuses.push(accounts.fillStatus);
+    }
+
+    const closeFillPdas = (rentReceiver: PublicKey) =>
+      program.methods
+        .closeFillPdas()
+        .accounts({ signer: otherRelayer.publicKey, state })
+        .remainingAccounts(
+          fillStatuses.flatMap((fillStatus) => [
+            { pubkey: fillStatus, isSigner: false, isWritable: true },
+            { pubkey: rentReceiver, isSigner: false, isWritable: true },
+          ])
+        )
+        .signers([otherRelayer])
+        .rpc();
+
+    try {
+      await closeFillPdas(relayer.publicKey);
+      assert.fail("Closing fill PDAs should have failed before fill deadline");
+    } catch (err: any) {
+      assert.include(
+        err.toString(),
+        "CanOnlyCloseFillStatusPdaIfFillDeadlinePassed",
+        "Expected CanOnlyCloseFillStatusPdaIfFillDeadlinePassed error"
+      );
+    }
+
+    await setCurrentTime(program, state, relayer, new BN(relayData.fillDeadline + 1));
+
+    // Rent can only be sent to the relayer recorded in the fill PDAs.
+    try {
+      await closeFillPdas(otherRelayer.publicKey);
+      assert.fail("Closing fill PDAs should have failed for wrong rent receiver");
+    } catch (err: any) {
+      assert.include(err.toString(), "NotRelayer", "Expected NotRelayer error");
+    }
+
+    const relayerBalanceBefore = await connection.getBalance(relayer.publicKey);
+    await closeFillPdas(relayer.publicKey);
+
+    for (const fillStatus of fillStatuses) {
+      assert.isNull(await connection.getAccountInfo(fillStatus), "Fill PDA should be closed");
+    }
+    assert.isAbove(await connection.getBalance(relayer.publicKey), relayerBalanceBefore, "Relayer should get rent");
+  });
+
   it("Fetches FillStatusAccount before and after fillRelay", async () => {
     const relayHash = calculateRelayHashUint8Array(relayData, chainId);
     const [fillStatusPDA] = PublicKey.findProgramAddressSync([Buffer.from("fills"), relayHash], program.programId);
@@ -702,6 +768,869 @@ describe("svm_spoke.fill", () => {
     assertSE(event.relayExecutionInfo.updatedMessageHash, new Uint8Array(32), `UpdatedMessageHash should be zeroed`);
   });
 
+  it("Fails to fill more than the fill volume in the window", async () => {
+    await program.methods
+      .setTokenLimits(mint, new BN(0), new BN(0), new BN(relayAmount * 1.5), 3600)
+      .accounts({ signer: owner, payer: owner, state, program: program.programId })
+      .rpc();
+
+    // First fill is within the fill volume.
+    let relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+    await approvedFillRelay([relayHash, relayData, new BN(1), relayer.publicKey]);
+
+    // Second fill for another deposit exceeds the fill volume in the window.
+    updateRelayData({ ...relayData, depositId: intToU8Array32(Math.floor(Math.random() * 1000000)) });
+    relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+    try {
+      await approvedFillRelay([relayHash, relayData, new BN(1), relayer.publicKey]);
+      assert.fail("Fill should have failed due to fill volume limit");
+    } catch (err: any) {
+      assert.include(err.toString(), "FillVolumeLimitExceeded", "Expected FillVolumeLimitExceeded error");
+    }
+
+    // Fills do not consume the outflow volume of refunds and slow fills paid from the vault.
+    const capacity = await program.methods.getTokenLimitsCapacity(mint).accounts({ state }).view();
+    assertSE(capacity.remainingFillVolume, relayAmount * 0.5, "Remaining fill volume should be reduced by the fill");
+    assertSE(capacity.remainingOutflowVolume, relayAmount * 1.5, "Remaining outflow volume should not be reduced");
+  });
+
+  describe("fill relay with profile", () => {
+    const [relayerProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("relayer_profile"), relayer.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const fillRelayWithProfile = async (signer: Keypair) => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      return program.methods
+        .fillRelayWithProfile(relayHash, relayData)
+        .accounts({
+          signer: signer.publicKey,
+          state,
+          relayerProfile,
+          mint,
+          relayerTokenAccount: relayerTA,
+          recipientTokenAccount: recipientTA,
+          fillStatus: accounts.fillStatus,
+          tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          program: program.programId,
+        })
+        .remainingAccounts(fillRemainingAccounts)
+        .signers([signer])
+        .rpc();
+    };
+
+    beforeEach(async () => {
+      // Relayer sets its profile with otherRelayer as the delegate and approves the profile PDA once for all fills.
+      await program.methods
+        .setRelayerProfile(new BN(420), otherRelayer.publicKey, otherRelayer.publicKey)
+        .accounts({ signer: relayer.publicKey, program: program.programId })
+        .signers([relayer])
+        .rpc();
+      const approveIx = createApproveCheckedInstruction(
+        relayerTA,
+        mint,
+        relayerProfile,
+        relayer.publicKey,
+        BigInt(seedBalance),
+        tokenDecimals,
+        undefined,
+        tokenProgram
+      );
+      await sendAndConfirmTransaction(connection, new Transaction().add(approveIx), [payer, relayer]);
+    });
+
+    it("Fills relays with repayment from the relayer profile", async () => {
+      const tx = await fillRelayWithProfile(relayer);
+
+      const events = await readEventsUntilFound(connection, tx, [program]);
+      const event = events.find((event) => event.name === "filledRelay")?.data;
+      assert.isNotNull(event, "FilledRelay event should be emitted");
+      assertSE(event.repaymentChainId, new BN(420), "Repayment chain id should match the profile");
+      assertSE(event.relayer, otherRelayer.publicKey, "Repayment address should match the profile");
+
+      // The profile delegate can fill another relay from the relayer's token account without new approvals.
+      updateRelayData({ ...relayData, depositId: intToU8Array32(Math.floor(Math.random() * 1000000)) });
+      await fillRelayWithProfile(otherRelayer);
+
+      // Fill status records the profile relayer rather than its delegate signer.
+      const fillStatusAccount = await program.account.fillStatusAccount.fetch(accounts.fillStatus);
+      assertSE(fillStatusAccount.relayer, relayer.publicKey, "Fill status relayer should be the profile relayer");
+
+      const relayerAccount = await getAccount(connection, relayerTA);
+      assertSE(relayerAccount.amount, seedBalance - 2 * relayAmount, "Relayer's balance should fund both fills");
+      const recipientAccount = await getAccount(connection, recipientTA);
+      assertSE(recipientAccount.amount, 2 * relayAmount, "Recipient's balance should be increased by both fills");
+    });
+
+    it("Fails to fill with the relayer profile from other signers", async () => {
+      const unauthorized = Keypair.generate();
+      const sig = await connection.requestAirdrop(unauthorized.publicKey, 10_000_000_000); // 10 SOL
+      await provider.connection.confirmTransaction(sig);
+      try {
+        await fillRelayWithProfile(unauthorized);
+        assert.fail("Fill should have failed for signer not allowed in the relayer profile");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidRelayerProfileSigner", "Expected InvalidRelayerProfileSigner error");
+      }
+    });
+  });
+
+  describe("fill relay from inventory", () => {
+    const [relayerInventory] = PublicKey.findProgramAddressSync(
+      [Buffer.from("relayer_inventory"), relayer.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const getInventoryTA = () => getAssociatedTokenAddressSync(mint, relayerInventory, true, tokenProgram);
+
+    const inventoryAccounts = () => ({
+      signer: relayer.publicKey,
+      mint,
+      relayerTokenAccount: relayerTA,
+      tokenProgram,
+      program: program.programId,
+    });
+
+    const fillRelayFromInventory = async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      return program.methods
+        .fillRelayFromInventory(relayHash, relayData, new BN(1), relayer.publicKey)
+        .accounts({
+          signer: relayer.publicKey,
+          state,
+          relayerInventory,
+          mint,
+          inventoryTokenAccount: getInventoryTA(),
+          recipientTokenAccount: recipientTA,
+          fillStatus: accounts.fillStatus,
+          tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          program: program.programId,
+        })
+        .remainingAccounts(fillRemainingAccounts)
+        .signers([relayer])
+        .rpc();
+    };
+
+    it("Fills relays from the relayer inventory and withdraws the rest", async () => {
+      await program.methods
+        .fundRelayerInventory(new BN(relayAmount * 3))
+        .accounts(inventoryAccounts())
+        .signers([relayer])
+        .rpc();
+
+      // Fills debit the inventory without any approve instructions.
+      await fillRelayFromInventory();
+      updateRelayData({ ...relayData, depositId: intToU8Array32(Math.floor(Math.random() * 1000000)) });
+      await fillRelayFromInventory();
+
+      let inventoryAccount = await getAccount(connection, getInventoryTA());
+      assertSE(inventoryAccount.amount, relayAmount, "Inventory should be debited by both fills");
+      const recipientAccount = await getAccount(connection, recipientTA);
+      assertSE(recipientAccount.amount, 2 * relayAmount, "Recipient's balance should be increased by both fills");
+
+      await program.methods
+        .withdrawRelayerInventory(new BN(relayAmount))
+        .accounts(inventoryAccounts())
+        .signers([relayer])
+        .rpc();
+
+      inventoryAccount = await getAccount(connection, getInventoryTA());
+      assertSE(inventoryAccount.amount, 0, "Inventory should be empty after withdrawal");
+      const relayerAccount = await getAccount(connection, relayerTA);
+      assertSE(relayerAccount.amount, seedBalance - 2 * relayAmount, "Relayer should get back the unused inventory");
+
+      // Fills cannot exceed the inventory balance.
+      updateRelayData({ ...relayData, depositId: intToU8Array32(Math.floor(Math.random() * 1000000)) });
+      try {
+        await fillRelayFromInventory();
+        assert.fail("Fill should have failed due to insufficient inventory");
+      } catch (err: any) {
+        // Token program InsufficientFunds error.
+        assert.include(err.toString(), "custom program error: 0x1", "Expected insufficient funds error");
+      }
+    });
+  });
+
+  describe("compressed fill status", () => {
+    const getFillStatusBucket = (relayHash: number[]) => {
+      const bucketId = Buffer.alloc(4);
+      bucketId.writeUInt32LE(Math.floor(relayData.fillDeadline / 3600));
+      return PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("fill_status_bucket"),
+          relayData.originChainId.toArrayLike(Buffer, "le", 8),
+          bucketId,
+          Buffer.from([relayHash[0] % 16]),
+        ],
+        program.programId
+      )[0];
+    };
+
+    const setCompressedFillStatusCutover = async (cutover: number) =>
+      program.methods
+        .setCompressedFillStatusCutover(cutover)
+        .accounts({ signer: owner, state, program: program.programId })
+        .rpc();
+
+    const approvedFillRelayCompressed = async (relayHash: number[]) => {
+      const { pda: delegatePda } = getFillRelayDelegatePda(
+        Uint8Array.from(relayHash),
+        new BN(1),
+        relayer.publicKey,
+        program.programId
+      );
+      const approveIx = await createApproveCheckedInstruction(
+        relayerTA,
+        mint,
+        delegatePda,
+        relayer.publicKey,
+        BigInt(relayData.outputAmount.toString()),
+        tokenDecimals,
+        undefined,
+        tokenProgram
+      );
+      const fillIx = await program.methods
+        .fillRelayCompressed(relayHash, relayData, new BN(1), relayer.publicKey)
+        .accounts({
+          state,
+          delegate: delegatePda,
+          signer: relayer.publicKey,
+          mint,
+          relayerTokenAccount: relayerTA,
+          recipientTokenAccount: recipientTA,
+          legacyFillStatus: accounts.fillStatus,
+          fillStatusBucket: getFillStatusBucket(relayHash),
+          tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          program: program.programId,
+        })
+        .remainingAccounts(fillRemainingAccounts)
+        .instruction();
+
+      return sendAndConfirmTransaction(connection, new Transaction().add(approveIx, fillIx), [payer, relayer]);
+    };
+
+    it("Fills a relay with compressed fill status", async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await setCompressedFillStatusCutover(relayData.fillDeadline);
+      await approvedFillRelayCompressed(relayHash);
+
+      const recipientAccount = await getAccount(connection, recipientTA);
+      assertSE(recipientAccount.amount, relayAmount, "Recipient's balance should be increased by the relay amount");
+      assert.isNull(await connection.getAccountInfo(accounts.fillStatus), "Fill status PDA should not be created");
+      const bucket = await program.account.fillStatusBucket.fetch(getFillStatusBucket(relayHash));
+      assertSE(bucket.payer, relayer.publicKey, "Bucket payer should be the relayer");
+      assert.strictEqual(bucket.entries.length, 1, "Bucket should track one relay");
+      assertSE(bucket.entries[0].relayHash, relayHash, "Bucket entry should match the relay hash");
+      assert.deepEqual(bucket.entries[0].status, { filled: {} }, "Bucket entry should be filled");
+      assertSE(bucket.entries[0].funder, relayer.publicKey, "Bucket entry funder should be the relayer");
+
+      try {
+        await approvedFillRelayCompressed(relayHash);
+        assert.fail("Fill should have failed due to RelayFilled error");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayFilled", "Expected RelayFilled error");
+      }
+    });
+
+    it("Fills a relay past the cutover with fill status bucket", async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await setCompressedFillStatusCutover(relayData.fillDeadline);
+
+      // Relays past the cutover must provide their fill status bucket.
+      try {
+        await approvedFillRelay([relayHash, relayData, new BN(1), relayer.publicKey]);
+        assert.fail("Fill should have failed without the fill status bucket");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayUsesCompressedFillStatus", "Expected RelayUsesCompressedFillStatus error");
+      }
+
+      await approvedFillRelay([relayHash, relayData, new BN(1), relayer.publicKey], {
+        ...accounts,
+        fillStatusBucket: getFillStatusBucket(relayHash),
+      });
+
+      assert.isNull(await connection.getAccountInfo(accounts.fillStatus), "Fill status PDA should not be created");
+      const bucket = await program.account.fillStatusBucket.fetch(getFillStatusBucket(relayHash));
+      assertSE(bucket.entries[0].relayHash, relayHash, "Bucket entry should match the relay hash");
+      assert.deepEqual(bucket.entries[0].status, { filled: {} }, "Bucket entry should be filled");
+
+      try {
+        await approvedFillRelayCompressed(relayHash);
+        assert.fail("Fill should have failed due to RelayFilled error");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayFilled", "Expected RelayFilled error");
+      }
+    });
+
+    it("Enforces the compressed fill status cutover", async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await setCompressedFillStatusCutover(relayData.fillDeadline + 1);
+
+      try {
+        await approvedFillRelayCompressed(relayHash);
+        assert.fail("Compressed fill should have failed for relay before the cutover");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayUsesLegacyFillStatus", "Expected RelayUsesLegacyFillStatus error");
+      }
+
+      // Moving the cutover later could allow filling relays tracked in buckets again through fill status PDAs.
+      try {
+        await setCompressedFillStatusCutover(relayData.fillDeadline + 2);
+        assert.fail("Cutover should not be moved later");
+      } catch (err: any) {
+        assert.include(
+          err.toString(),
+          "InvalidCompressedFillStatusCutover",
+          "Expected InvalidCompressedFillStatusCutover error"
+        );
+      }
+
+      await setCompressedFillStatusCutover(relayData.fillDeadline);
+      await approvedFillRelayCompressed(relayHash);
+    });
+
+    it("Migrates fill status PDA to compressed fill status bucket", async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await approvedFillRelay([relayHash, relayData, new BN(1), relayer.publicKey]);
+      await setCompressedFillStatusCutover(relayData.fillDeadline);
+
+      try {
+        await approvedFillRelayCompressed(relayHash);
+        assert.fail("Compressed fill should have failed before migrating the fill status PDA");
+      } catch (err: any) {
+        assert.include(err.toString(), "LegacyFillStatusExists", "Expected LegacyFillStatusExists error");
+      }
+
+      // Anyone can migrate the fill status PDA, while its rent is returned to the relayer.
+      const relayerBalanceBefore = await connection.getBalance(relayer.publicKey);
+      await program.methods
+        .migrateFillStatus(relayHash, relayData)
+        .accounts({
+          signer: otherRelayer.publicKey,
+          state,
+          relayer: relayer.publicKey,
+          fillStatus: accounts.fillStatus,
+          fillStatusBucket: getFillStatusBucket(relayHash),
+          program: program.programId,
+        })
+        .signers([otherRelayer])
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(accounts.fillStatus), "Fill status PDA should be closed");
+      assert.isAbove(await connection.getBalance(relayer.publicKey), relayerBalanceBefore, "Relayer should get rent");
+      const bucket = await program.account.fillStatusBucket.fetch(getFillStatusBucket(relayHash));
+      assert.deepEqual(bucket.entries[0].status, { filled: {} }, "Migrated bucket entry should be filled");
+
+      try {
+        await approvedFillRelayCompressed(relayHash);
+        assert.fail("Fill should have failed due to RelayFilled error");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayFilled", "Expected RelayFilled error");
+      }
+    });
+
+    it("Closes expired fill status bucket", async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await setCompressedFillStatusCutover(relayData.fillDeadline);
+      await approvedFillRelayCompressed(relayHash);
+
+      const closeAccounts = {
+        signer: otherRelayer.publicKey,
+        state,
+        payer: relayer.publicKey,
+        fillStatusBucket: getFillStatusBucket(relayHash),
+      };
+      const funderAccounts = [{ pubkey: relayer.publicKey, isSigner: false, isWritable: true }];
+      try {
+        await program.methods
+          .closeFillStatusBucket()
+          .accounts(closeAccounts)
+          .remainingAccounts(funderAccounts)
+          .signers([otherRelayer])
+          .rpc();
+        assert.fail("Closing bucket should have failed before all its fill deadlines passed");
+      } catch (err: any) {
+        assert.include(err.toString(), "FillStatusBucketNotExpired", "Expected FillStatusBucketNotExpired error");
+      }
+
+      const bucketEnd = (Math.floor(relayData.fillDeadline / 3600) + 1) * 3600;
+      await setCurrentTime(program, state, relayer, new BN(bucketEnd));
+
+      // Bucket rent can only be returned to its payer.
+      try {
+        await program.methods
+          .closeFillStatusBucket()
+          .accounts({ ...closeAccounts, payer: otherRelayer.publicKey })
+          .remainingAccounts(funderAccounts)
+          .signers([otherRelayer])
+          .rpc();
+        assert.fail("Closing bucket should have failed for wrong payer");
+      } catch (err: any) {
+        assert.include(err.toString(), "ConstraintAddress", "Expected ConstraintAddress error");
+      }
+
+      // Anyone can close the expired bucket, while all its rent is returned to the relayer that funded it.
+      const bucketRent = (await connection.getAccountInfo(getFillStatusBucket(relayHash)))!.lamports;
+      const relayerBalanceBefore = await connection.getBalance(relayer.publicKey);
+      await program.methods
+        .closeFillStatusBucket()
+        .accounts(closeAccounts)
+        .remainingAccounts(funderAccounts)
+        .signers([otherRelayer])
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(getFillStatusBucket(relayHash)), "Bucket should be closed");
+      assert.strictEqual(
+        await connection.getBalance(relayer.publicKey),
+        relayerBalanceBefore + bucketRent,
+        "Relayer should get all bucket rent"
+      );
+    });
+  });
+
+  describe("fill relay native", () => {
+    const nativeRecipient = Keypair.generate();
+    const [nativeRecipientPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("native_recipient"), nativeRecipient.publicKey.toBuffer()],
+      program.programId
+    );
+    const getNativeTokenLimits = () =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_limits"), seed.toArrayLike(Buffer, "le", 8), NATIVE_MINT.toBuffer()],
+        program.programId
+      )[0];
+
+    const setNativeRecipient = async (enabled: boolean) =>
+      program.methods
+        .setNativeRecipient(enabled)
+        .accounts({ signer: nativeRecipient.publicKey, program: program.programId })
+        .signers([nativeRecipient])
+        .rpc();
+
+    const fillRelayNative = async () => {
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      return program.methods
+        .fillRelayNative(relayHash, relayData, new BN(1), relayer.publicKey)
+        .accounts({
+          signer: relayer.publicKey,
+          state,
+          tokenLimits: getNativeTokenLimits(),
+          recipient: nativeRecipient.publicKey,
+          nativeRecipient: nativeRecipientPda,
+          fillStatus: accounts.fillStatus,
+          program: program.programId,
+        })
+        .signers([relayer])
+        .rpc();
+    };
+
+    before(async () => {
+      await connection.requestAirdrop(nativeRecipient.publicKey, 1_000_000_000); // 1 SOL
+    });
+
+    beforeEach(async () => {
+      updateRelayData({
+        ...relayData,
+        recipient: nativeRecipient.publicKey,
+        outputToken: NATIVE_MINT,
+        message: Buffer.alloc(0),
+      });
+    });
+
+    it("Fills a relay with native SOL to an opted in recipient", async () => {
+      await setNativeRecipient(true);
+
+      const iRecipientBalance = await connection.getBalance(nativeRecipient.publicKey);
+      const tx = await fillRelayNative();
+      const fRecipientBalance = await connection.getBalance(nativeRecipient.publicKey);
+      assertSE(fRecipientBalance - iRecipientBalance, relayAmount, "Recipient should receive output amount lamports");
+
+      const fillStatusAccount = await program.account.fillStatusAccount.fetch(accounts.fillStatus);
+      assert.isTrue("filled" in fillStatusAccount.status, "Fill status should be filled");
+
+      // The emitted event should be the same as for a wSOL fill.
+      const events = await readEventsUntilFound(connection, tx, [program]);
+      const event = events.find((event) => event.name === "filledRelay")?.data;
+      assertSE(event.outputToken, NATIVE_MINT, "Output token should be the native mint");
+      assertSE(event.recipient, nativeRecipient.publicKey, "Recipient should match");
+      assertSE(event.outputAmount, relayData.outputAmount, "Output amount should match");
+    });
+
+    it("Fails to fill native SOL to a recipient that has not opted in", async () => {
+      await setNativeRecipient(false);
+
+      try {
+        await fillRelayNative();
+        assert.fail("Fill should have failed for recipient that has not opted in");
+      } catch (err: any) {
+        assert.include(err.toString(), "NativeRecipientNotEnabled", "Expected NativeRecipientNotEnabled error");
+      }
+    });
+
+    it("Fails to fill native SOL for non-wSOL output token", async () => {
+      await setNativeRecipient(true);
+      updateRelayData({ ...relayData, outputToken: mint });
+
+      try {
+        await fillRelayNative();
+        assert.fail("Fill should have failed for non-wSOL output token");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidNativeOutputToken", "Expected InvalidNativeOutputToken error");
+      }
+    });
+  });
+
+  describe("fill relay with updated deposit", () => {
+    const depositor = Keypair.generate();
+    const updatedRecipient = Keypair.generate().publicKey;
+    const updatedOutputAmount = relayAmount - 1000;
+    const updatedMessage = Buffer.alloc(0);
+    let updatedRecipientTA: PublicKey;
+
+    const approvedFillRelayWithUpdatedDeposit = async (
+      signingDepositor: Keypair | ethers.Wallet = depositor
+    ): Promise<string> => {
+      const relayHash = calculateRelayHashUint8Array(relayData, chainId);
+      const { pda: delegatePda } = getFillRelayDelegatePda(relayHash, chainId, relayer.publicKey, program.programId);
+
+      // Solana depositors sign with Ed25519 key verified by the precompile, EVM depositors sign EIP-712 typed data.
+      const signatureIxs: TransactionInstruction[] = [];
+      let depositorSignature: Buffer;
+      if (signingDepositor instanceof ethers.Wallet) {
+        depositorSignature = Buffer.from(
+          ethers.utils.arrayify(
+            await signingDepositor._signTypedData(
+              { name: "ACROSS-V2", version: "1.0.0", chainId: relayData.originChainId.toString() },
+              {
+                UpdateDepositDetails: [
+                  { name: "depositId", type: "uint256" },
+                  { name: "originChainId", type: "uint256" },
+                  { name: "updatedOutputAmount", type: "uint256" },
+                  { name: "updatedRecipient", type: "bytes32" },
+                  { name: "updatedMessage", type: "bytes" },
+                ],
+              },
+              {
+                depositId: ethers.BigNumber.from(relayData.depositId),
+                originChainId: relayData.originChainId.toString(),
+                updatedOutputAmount,
+                updatedRecipient: updatedRecipient.toBuffer(),
+                updatedMessage,
+              }
+            )
+          )
+        );
+      } else {
+        const updateHash = getUpdateDepositDetailsHash(
+          relayData.depositId,
+          relayData.originChainId,
+          intToU8Array32(updatedOutputAmount),
+          updatedRecipient,
+          updatedMessage
+        );
+        const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: signingDepositor.secretKey,
+          message: updateHash,
+        });
+        depositorSignature = Buffer.from(ed25519Ix.data.subarray(48, 112));
+        signatureIxs.push(ed25519Ix);
+      }
+
+      const approveIx = await createApproveCheckedInstruction(
+        relayerTA,
+        mint,
+        delegatePda,
+        relayer.publicKey,
+        BigInt(updatedOutputAmount),
+        tokenDecimals,
+        undefined,
+        tokenProgram
+      );
+
+      const fillIx = await program.methods
+        .fillRelayWithUpdatedDeposit(Array.from(relayHash), relayData, chainId, relayer.publicKey, {
+          updatedOutputAmount: new BN(updatedOutputAmount),
+          updatedRecipient,
+          updatedMessage,
+          depositorSignature,
+        })
+        .accounts({
+          ...accounts,
+          delegate: delegatePda,
+          recipientTokenAccount: updatedRecipientTA,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .instruction();
+
+      return sendAndConfirmTransaction(connection, new Transaction().add(approveIx, ...signatureIxs, fillIx), [
+        payer,
+        relayer,
+      ]);
+    };
+
+    beforeEach(async () => {
+      updatedRecipientTA = (await getOrCreateAssociatedTokenAccount(connection, payer, mint, updatedRecipient)).address;
+      updateRelayData({ ...relayData, depositor: depositor.publicKey });
+    });
+
+    it("Fills a relay with depositor signed updates", async () => {
+      const tx = await approvedFillRelayWithUpdatedDeposit();
+
+      const relayerAccount = await getAccount(connection, relayerTA);
+      assertSE(
+        relayerAccount.amount,
+        seedBalance - updatedOutputAmount,
+        "Relayer's balance should be reduced by the updated output amount"
+      );
+      const updatedRecipientAccount = await getAccount(connection, updatedRecipientTA);
+      assertSE(
+        updatedRecipientAccount.amount,
+        updatedOutputAmount,
+        "Updated recipient's balance should be increased by the updated output amount"
+      );
+      const recipientAccount = await getAccount(connection, recipientTA);
+      assertSE(recipientAccount.amount, "0", "Original recipient's balance should not change");
+
+      const events = await readEventsUntilFound(connection, tx, [program]);
+      const event = events.find((event) => event.name === "filledRelay")?.data;
+      assert.isNotNull(event, "FilledRelay event should be emitted");
+      assertSE(event.messageHash, hashNonEmptyMessage(relayData.message), "MessageHash should match original message");
+      assertSE(event.relayExecutionInfo.updatedRecipient, updatedRecipient, "UpdatedRecipient should match");
+      assertSE(event.relayExecutionInfo.updatedMessageHash, new Uint8Array(32), "UpdatedMessageHash should be zeroed");
+      assertSE(
+        event.relayExecutionInfo.updatedOutputAmount,
+        new BN(updatedOutputAmount),
+        "UpdatedOutputAmount should match"
+      );
+    });
+
+    it("Fails to fill with updates not signed by the depositor", async () => {
+      try {
+        await approvedFillRelayWithUpdatedDeposit(Keypair.generate());
+        assert.fail("Fill with updates signed by another key should have failed");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidDepositorSignature", "Expected InvalidDepositorSignature error");
+      }
+    });
+
+    it("Fills a relay with EVM depositor signed updates", async () => {
+      const evmDepositor = ethers.Wallet.createRandom();
+      updateRelayData({ ...relayData, depositor: evmAddressToPublicKey(evmDepositor.address) });
+
+      const tx = await approvedFillRelayWithUpdatedDeposit(evmDepositor);
+
+      const updatedRecipientAccount = await getAccount(connection, updatedRecipientTA);
+      assertSE(
+        updatedRecipientAccount.amount,
+        updatedOutputAmount,
+        "Updated recipient's balance should be increased by the updated output amount"
+      );
+
+      const events = await readEventsUntilFound(connection, tx, [program]);
+      const event = events.find((event) => event.name === "filledRelay")?.data;
+      assert.isNotNull(event, "FilledRelay event should be emitted");
+      assertSE(event.relayExecutionInfo.updatedRecipient, updatedRecipient, "UpdatedRecipient should match");
+      assertSE(
+        event.relayExecutionInfo.updatedOutputAmount,
+        new BN(updatedOutputAmount),
+        "UpdatedOutputAmount should match"
+      );
+    });
+
+    it("Fails to fill with updates not signed by the EVM depositor", async () => {
+      updateRelayData({ ...relayData, depositor: evmAddressToPublicKey(ethers.Wallet.createRandom().address) });
+
+      try {
+        await approvedFillRelayWithUpdatedDeposit(ethers.Wallet.createRandom());
+        assert.fail("Fill with updates signed by another EVM key should have failed");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidDepositorSignature", "Expected InvalidDepositorSignature error");
+      }
+    });
+  });
+
+  describe("fill relays batch", () => {
+    const batchSize = 3;
+    let batchRecipients: PublicKey[];
+    let batchRecipientTAs: PublicKey[];
+    let batchRelayData: RelayData[];
+
+    const approvedFillRelaysBatch = async (relays: RelayData[] = batchRelayData): Promise<string> => {
+      const relayHashes = relays.map((relay) => calculateRelayHashUint8Array(relay, chainId));
+      const { pda: delegatePda } = getFillRelaysBatchDelegatePda(
+        relayHashes,
+        chainId,
+        relayer.publicKey,
+        program.programId
+      );
+      const totalOutputAmount = relays.reduce((sum, relay) => sum + BigInt(relay.outputAmount.toString()), BigInt(0));
+
+      const approveIx = await createApproveCheckedInstruction(
+        relayerTA,
+        mint,
+        delegatePda,
+        relayer.publicKey,
+        totalOutputAmount,
+        tokenDecimals,
+        undefined,
+        tokenProgram
+      );
+
+      // Each relay passes its recipient ATA followed by its fill status PDA.
+      const remainingAccounts = relays.flatMap((relay, i) => [
+        {
+          pubkey: getAssociatedTokenAddressSync(mint, relay.recipient, true, tokenProgram),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: PublicKey.findProgramAddressSync([Buffer.from("fills"), relayHashes[i]], program.programId)[0],
+          isSigner: false,
+          isWritable: true,
+        },
+      ]);
+
+      const fillIx = await program.methods
+        .fillRelaysBatch(relayHashes.map((relayHash) => Array.from(relayHash)), relays, chainId, relayer.publicKey)
+        .accounts({
+          state,
+          delegate: delegatePda,
+          signer: relayer.publicKey,
+          instructionParams: program.programId,
+          mint,
+          relayerTokenAccount: relayerTA,
+          tokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          program: program.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+
+      return sendAndConfirmTransaction(connection, new Transaction().add(approveIx, fillIx), [payer, relayer]);
+    };
+
+    beforeEach(async () => {
+      batchRecipients = Array.from({ length: batchSize }, () => Keypair.generate().publicKey);
+      batchRecipientTAs = [];
+      for (const batchRecipient of batchRecipients) {
+        const batchRecipientTA = await getOrCreateAssociatedTokenAccount(connection, payer, mint, batchRecipient);
+        batchRecipientTAs.push(batchRecipientTA.address);
+      }
+      batchRelayData = batchRecipients.map((batchRecipient, i) => ({
+        ...relayData,
+        recipient: batchRecipient,
+        outputAmount: new BN(relayAmount + i),
+        depositId: intToU8Array32(Math.floor(Math.random() * 1000000)),
+        message: Buffer.alloc(0),
+      }));
+    });
+
+    it("Fills a batch of relays and verifies balances", async () => {
+      await approvedFillRelaysBatch();
+
+      const totalOutputAmount = batchRelayData.reduce((sum, relay) => sum + relay.outputAmount.toNumber(), 0);
+      const relayerAccount = await getAccount(connection, relayerTA);
+      assertSE(
+        relayerAccount.amount,
+        seedBalance - totalOutputAmount,
+        "Relayer's balance should be reduced by the sum of output amounts"
+      );
+
+      for (let i = 0; i < batchSize; i++) {
+        const recipientAccount = await getAccount(connection, batchRecipientTAs[i]);
+        assertSE(recipientAccount.amount, batchRelayData[i].outputAmount, `Recipient ${i} balance should increase`);
+
+        const relayHash = calculateRelayHashUint8Array(batchRelayData[i], chainId);
+        const [fillStatusPDA] = PublicKey.findProgramAddressSync([Buffer.from("fills"), relayHash], program.programId);
+        const fillStatusAccount = await program.account.fillStatusAccount.fetch(fillStatusPDA);
+        assert.equal(JSON.stringify(fillStatusAccount.status), `{"filled":{}}`, `Fill status ${i} should be Filled`);
+        assertSE(fillStatusAccount.relayer, relayer.publicKey, `Fill status ${i} relayer should match`);
+      }
+    });
+
+    it("Emits FilledRelay event for each relay in the batch", async () => {
+      const tx = await approvedFillRelaysBatch();
+
+      const events = (await readEventsUntilFound(connection, tx, [program])).filter(
+        (event) => event.name === "filledRelay"
+      );
+      assert.strictEqual(events.length, batchSize, "FilledRelay event should be emitted for each relay");
+      events.forEach((event, i) => {
+        assertSE(event.data.depositId, batchRelayData[i].depositId, "DepositId should match");
+        assertSE(event.data.recipient, batchRelayData[i].recipient, "Recipient should match");
+        assertSE(event.data.outputAmount, batchRelayData[i].outputAmount, "OutputAmount should match");
+        assertSE(event.data.repaymentChainId, chainId, "Repayment chain id should match");
+        assertSE(event.data.relayer, relayer.publicKey, "Repayment address should match");
+      });
+    });
+
+    it("Fails to fill a batch that contains an already filled relay", async () => {
+      updateRelayData(batchRelayData[1]);
+      const relayHash = Array.from(calculateRelayHashUint8Array(relayData, chainId));
+      await approvedFillRelay([relayHash, relayData, chainId, relayer.publicKey], {
+        ...accounts,
+        recipientTokenAccount: batchRecipientTAs[1],
+      });
+
+      try {
+        await approvedFillRelaysBatch();
+        assert.fail("Batch with already filled relay should have failed");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayFilled", "Expected RelayFilled error");
+      }
+    });
+
+    it("Fails to fill a batch with duplicate relays", async () => {
+      try {
+        await approvedFillRelaysBatch([batchRelayData[0], batchRelayData[0]]);
+        assert.fail("Batch with duplicate relays should have failed");
+      } catch (err: any) {
+        assert.include(err.toString(), "RelayFilled", "Expected RelayFilled error");
+      }
+    });
+
+    it("Fails to fill a batch without instruction data or instruction params", async () => {
+      try {
+        await program.methods
+          .fillRelaysBatch(null, null, null, null)
+          .accounts({
+            state,
+            delegate: relayer.publicKey,
+            signer: relayer.publicKey,
+            instructionParams: program.programId,
+            mint,
+            relayerTokenAccount: relayerTA,
+            tokenProgram,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            program: program.programId,
+          })
+          .signers([relayer])
+          .rpc();
+        assert.fail("Batch without params should have failed");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidInstructionParams", "Expected InvalidInstructionParams error");
+      }
+    });
+
+    it("Fails to fill a batch with messages", async () => {
+      batchRelayData[0] = { ...batchRelayData[0], message: encodedMessage };
+
+      try {
+        await approvedFillRelaysBatch();
+        assert.fail("Batch with message should have failed");
+      } catch (err: any) {
+        assert.include(err.toString(), "BatchFillMessageNotSupported", "Expected BatchFillMessageNotSupported error");
+      }
+    });
+  });
+
   describe("codama client and solana kit", () => {
     it("Fills a V3 relay and verifies balances with codama client and solana kit", async () => {
       const rpcClient = createDefaultSolanaClient();
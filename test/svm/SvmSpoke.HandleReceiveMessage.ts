@@ -4,7 +4,14 @@ import { Keypair } from "@solana/web3.js";
 import { assert } from "chai";
 import * as crypto from "crypto";
 import { ethers } from "ethers";
-import { encodeMessageHeader, evmAddressToPublicKey } from "../../src/svm/web3-v1";
+import {
+  encodeMessageHeader,
+  evmAddressToPublicKey,
+  getRemoteNoncePda,
+  getRoutePda,
+  getTypedRootClaimsPda,
+  readEventsUntilFound,
+} from "../../src/svm/web3-v1";
 import { MessageTransmitter } from "../../target/types/message_transmitter";
 import { SvmSpoke } from "../../target/types/svm_spoke";
 import { common } from "./SvmSpoke.common";
@@ -24,6 +31,8 @@ describe("svm_spoke.handle_receive_message", () => {
   let messageTransmitterState: web3.PublicKey;
   let usedNonces: web3.PublicKey;
   let selfAuthority: web3.PublicKey;
+  let remoteAdminAuthority: web3.PublicKey;
+  let remoteNonce: web3.PublicKey;
   let eventAuthority: web3.PublicKey;
   const attestation = Buffer.alloc(0);
   let nonce = 0;
@@ -33,11 +42,28 @@ describe("svm_spoke.handle_receive_message", () => {
   let receiveMessageAccounts: any;
 
   const ethereumIface = new ethers.utils.Interface([
+    "function relaySequencedMessage(uint64 nonce, bytes message)",
     "function pauseDeposits(bool pause)",
     "function pauseFills(bool pause)",
+    "function pauseRefunds(bool pause)",
+    "function pauseClaims(bool pause)",
+    "function pauseBridge(bool pause)",
     "function setCrossDomainAdmin(address newCrossDomainAdmin)",
     "function relayRootBundle(bytes32 relayerRefundRoot, bytes32 slowRelayRoot)",
     "function emergencyDeleteRootBundle(uint256 rootBundleId)",
+    "function vetoRootBundle(uint256 rootBundleId)",
+    "function relayTypedRoot(uint32 rootBundleId, uint8 rootType, bytes32 root)",
+    "function transferOwnership(bytes32 newOwner)",
+    "function cancelOwnershipTransfer()",
+    "function setEnableRoute(bytes32 originToken, uint64 destinationChainId, bool enabled)",
+    "function setDepositQuoteTimeBuffer(uint32 depositQuoteTimeBuffer)",
+    "function setFillDeadlineBuffer(uint32 fillDeadlineBuffer)",
+    "function setRootBundleCloseGracePeriod(uint32 rootBundleCloseGracePeriod)",
+    "function setCompressedFillStatusCutover(uint32 compressedFillStatusCutover)",
+    "function setRootBundleTimelock(uint32 rootBundleTimelock)",
+    "function setRemoteAdmin(uint32 remoteDomain, bytes32 sender, bytes4[] selectors)",
+    "function removeRemoteAdmin(uint32 remoteDomain, bytes32 sender)",
+    "function grantRole(uint8 role, bytes32 member)",
   ]);
 
   beforeEach(async () => {
@@ -64,6 +90,11 @@ describe("svm_spoke.handle_receive_message", () => {
       })
       .view();
     [selfAuthority] = web3.PublicKey.findProgramAddressSync([Buffer.from("self_authority")], program.programId);
+    [remoteAdminAuthority] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("remote_admin_authority")],
+      program.programId
+    );
+    remoteNonce = getRemoteNoncePda(seed, remoteDomain.toNumber(), program.programId);
     [eventAuthority] = web3.PublicKey.findProgramAddressSync([Buffer.from("__event_authority")], program.programId);
 
     // Accounts in CCTP message_transmitter receive_message instruction.
@@ -84,13 +115,31 @@ describe("svm_spoke.handle_receive_message", () => {
       isWritable: false,
       pubkey: state,
     });
-    // self_authority in HandleReceiveMessage accounts, also signer in self-invoked CPIs.
+    // remote_nonce in HandleReceiveMessage accounts (tracks nonces of sequenced messages from the remote domain).
+    remainingAccounts.push({
+      isSigner: false,
+      isWritable: true,
+      pubkey: remoteNonce,
+    });
+    // self_authority in HandleReceiveMessage accounts, also signer in self-invoked CPIs from the cross-domain admin.
     remainingAccounts.push({
       isSigner: false,
       isWritable: false,
       pubkey: selfAuthority,
     });
-    // program in HandleReceiveMessage accounts.
+    // remote_admin_authority in HandleReceiveMessage accounts, also signer in self-invoked CPIs from remote admins.
+    remainingAccounts.push({
+      isSigner: false,
+      isWritable: false,
+      pubkey: remoteAdminAuthority,
+    });
+    // event_authority in HandleReceiveMessage accounts (appended by Anchor with event_cpi macro).
+    remainingAccounts.push({
+      isSigner: false,
+      isWritable: false,
+      pubkey: eventAuthority,
+    });
+    // program in HandleReceiveMessage accounts (appended by Anchor with event_cpi macro).
     remainingAccounts.push({
       isSigner: false,
       isWritable: false,
@@ -274,6 +323,32 @@ describe("svm_spoke.handle_receive_message", () => {
     assert.isFalse(stateData.pausedFills, "Fills should not be paused");
   });
 
+  it("Pauses refunds, claims and bridging remotely", async () => {
+    for (const functionName of ["pauseRefunds", "pauseClaims", "pauseBridge"]) {
+      const calldata = ethereumIface.encodeFunctionData(functionName, [true]);
+      const message = encodeMessageHeader({
+        version: cctpMessageversion,
+        sourceDomain: remoteDomain.toNumber(),
+        destinationDomain: localDomain,
+        nonce: BigInt(nonce),
+        sender: crossDomainAdmin,
+        recipient: program.programId,
+        destinationCaller,
+        messageBody: Buffer.from(calldata.slice(2), "hex"),
+      });
+      await messageTransmitterProgram.methods
+        .receiveMessage({ message, attestation })
+        .accounts(receiveMessageAccounts)
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      nonce += 1;
+    }
+    const stateData = await program.account.state.fetch(state);
+    assert.isTrue(stateData.pausedRefunds, "Refunds should be paused");
+    assert.isTrue(stateData.pausedClaims, "Claims should be paused");
+    assert.isTrue(stateData.pausedBridge, "Bridging should be paused");
+  });
+
   it("Sets cross-domain admin remotely", async () => {
     const newCrossDomainAdminAddress = ethers.Wallet.createRandom().address;
     const newCrossDomainAdminPubkey = evmAddressToPublicKey(newCrossDomainAdminAddress);
@@ -302,6 +377,328 @@ describe("svm_spoke.handle_receive_message", () => {
     );
   });
 
+  it("Transfers ownership remotely", async () => {
+    const newOwner = web3.Keypair.generate().publicKey;
+    const calldata = ethereumIface.encodeFunctionData("transferOwnership", [newOwner.toBuffer()]);
+    const messageBody = Buffer.from(calldata.slice(2), "hex");
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody,
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    const stateData = await program.account.state.fetch(state);
+    assert.strictEqual(stateData.owner.toString(), owner.toString(), "Owner should not change before acceptance");
+    assert.strictEqual(stateData.pendingOwner.toString(), newOwner.toString(), "Pending owner should be set");
+  });
+
+  it("Cancels ownership transfer remotely", async () => {
+    const newOwner = web3.Keypair.generate().publicKey;
+    const transferOwnershipAccounts = { state, signer: owner, program: program.programId };
+    await program.methods.transferOwnership(newOwner).accounts(transferOwnershipAccounts).rpc();
+
+    const calldata = ethereumIface.encodeFunctionData("cancelOwnershipTransfer");
+    const messageBody = Buffer.from(calldata.slice(2), "hex");
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody,
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    const stateData = await program.account.state.fetch(state);
+    assert.strictEqual(
+      stateData.pendingOwner.toString(),
+      web3.PublicKey.default.toString(),
+      "Pending ownership transfer should be cancelled"
+    );
+  });
+
+  it("Enables deposit route remotely", async () => {
+    const originToken = Keypair.generate().publicKey;
+    const destinationChainId = new BN(10);
+    const route = getRoutePda(originToken, seed, destinationChainId, program.programId);
+    const calldata = ethereumIface.encodeFunctionData("setEnableRoute", [
+      originToken.toBuffer(),
+      destinationChainId.toString(),
+      true,
+    ]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+
+    // Remaining accounts for the self-invoked CPI follow the first 6 accounts in HandleReceiveMessage.
+    const setEnableRouteRemainingAccounts = [
+      ...remainingAccounts.slice(0, 6),
+      // payer in self-invoked CPI (pays for the route account creation).
+      { isSigner: true, isWritable: true, pubkey: provider.wallet.publicKey },
+      // state in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: state },
+      // route in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: route },
+      // system_program in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: web3.SystemProgram.programId },
+      ...remainingAccounts.slice(7),
+    ];
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(setEnableRouteRemainingAccounts)
+      .rpc();
+    assert.isTrue((await program.account.route.fetch(route)).enabled, "Route should be enabled");
+  });
+
+  it("Sets deposit time buffers remotely", async () => {
+    const newDepositQuoteTimeBuffer = 1800;
+    let calldata = ethereumIface.encodeFunctionData("setDepositQuoteTimeBuffer", [newDepositQuoteTimeBuffer]);
+    let message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    let stateData = await program.account.state.fetch(state);
+    assert.strictEqual(stateData.depositQuoteTimeBuffer, newDepositQuoteTimeBuffer, "Quote buffer should be set");
+
+    // Set fill deadline buffer with the next CCTP nonce.
+    nonce += 1;
+    const newFillDeadlineBuffer = 3600 * 2;
+    receiveMessageAccounts.usedNonces = await messageTransmitterProgram.methods
+      .getNoncePda({ nonce: new BN(nonce.toString()), sourceDomain: remoteDomain.toNumber() })
+      .accounts({ messageTransmitter: messageTransmitterState })
+      .view();
+    calldata = ethereumIface.encodeFunctionData("setFillDeadlineBuffer", [newFillDeadlineBuffer]);
+    message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    stateData = await program.account.state.fetch(state);
+    assert.strictEqual(stateData.fillDeadlineBuffer, newFillDeadlineBuffer, "Fill deadline buffer should be set");
+  });
+
+  it("Sets root bundle close grace period remotely", async () => {
+    const newGracePeriod = 86400;
+    const calldata = ethereumIface.encodeFunctionData("setRootBundleCloseGracePeriod", [newGracePeriod]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    const stateData = await program.account.state.fetch(state);
+    assert.strictEqual(stateData.rootBundleCloseGracePeriod, newGracePeriod, "Grace period should be set");
+  });
+
+  it("Sets compressed fill status cutover remotely", async () => {
+    const newCutover = Math.floor(Date.now() / 1000) + 3600;
+    const calldata = ethereumIface.encodeFunctionData("setCompressedFillStatusCutover", [newCutover]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    const stateData = await program.account.state.fetch(state);
+    assert.strictEqual(stateData.compressedFillStatusCutover, newCutover, "Cutover should be set");
+  });
+
+  it("Sets and removes remote admin remotely", async () => {
+    const newRemoteDomain = 7;
+    const newRemoteSender = evmAddressToPublicKey(ethers.Wallet.createRandom().address);
+    const selectors = [ethereumIface.getSighash("pauseDeposits")];
+    const domainBuffer = Buffer.alloc(4);
+    domainBuffer.writeUInt32LE(newRemoteDomain);
+    const [remoteAdmin] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("remote_admin"), seed.toArrayLike(Buffer, "le", 8), domainBuffer, newRemoteSender.toBuffer()],
+      program.programId
+    );
+
+    // Remaining accounts for the self-invoked CPI follow the first 6 accounts in HandleReceiveMessage.
+    const setRemoteAdminRemainingAccounts = [
+      ...remainingAccounts.slice(0, 6),
+      // payer in self-invoked CPI (pays for the remote_admin account creation).
+      { isSigner: true, isWritable: true, pubkey: provider.wallet.publicKey },
+      // state in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: state },
+      // remote_admin in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: remoteAdmin },
+      // remote_nonce of the new remote domain in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: getRemoteNoncePda(seed, newRemoteDomain, program.programId) },
+      // system_program in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: web3.SystemProgram.programId },
+      ...remainingAccounts.slice(7),
+    ];
+    let calldata = ethereumIface.encodeFunctionData("setRemoteAdmin", [
+      newRemoteDomain,
+      newRemoteSender.toBuffer(),
+      selectors,
+    ]);
+    let message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(setRemoteAdminRemainingAccounts)
+      .rpc();
+    const remoteAdminData = await program.account.remoteAdmin.fetch(remoteAdmin);
+    assert.strictEqual(remoteAdminData.remoteDomain, newRemoteDomain, "Remote domain should match");
+    assert.strictEqual(remoteAdminData.sender.toString(), newRemoteSender.toString(), "Sender should match");
+    assert.deepEqual(
+      remoteAdminData.selectors,
+      selectors.map((selector) => Array.from(Buffer.from(selector.slice(2), "hex"))),
+      "Selectors should match"
+    );
+
+    // Remove the remote admin with the next CCTP nonce.
+    nonce += 1;
+    const removeRemoteAdminReceiveMessageAccounts = {
+      ...receiveMessageAccounts,
+      usedNonces: await messageTransmitterProgram.methods
+        .getNoncePda({ nonce: new BN(nonce.toString()), sourceDomain: remoteDomain.toNumber() })
+        .accounts({ messageTransmitter: messageTransmitterState })
+        .view(),
+    };
+    const removeRemoteAdminRemainingAccounts = [
+      ...remainingAccounts.slice(0, 6),
+      // closer in self-invoked CPI (receives the rent of the closed remote_admin account).
+      { isSigner: false, isWritable: true, pubkey: provider.wallet.publicKey },
+      // state in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: state },
+      // remote_admin in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: remoteAdmin },
+      ...remainingAccounts.slice(7),
+    ];
+    calldata = ethereumIface.encodeFunctionData("removeRemoteAdmin", [newRemoteDomain, newRemoteSender.toBuffer()]);
+    message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(removeRemoteAdminReceiveMessageAccounts)
+      .remainingAccounts(removeRemoteAdminRemainingAccounts)
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(remoteAdmin), "Remote admin should be closed");
+  });
+
+  it("Grants role remotely", async () => {
+    const role = 0; // Pauser role.
+    const member = Keypair.generate().publicKey;
+    const [roleMember] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("role_member"), seed.toArrayLike(Buffer, "le", 8), Buffer.from([role]), member.toBuffer()],
+      program.programId
+    );
+
+    // Remaining accounts for the self-invoked CPI follow the first 6 accounts in HandleReceiveMessage.
+    const grantRoleRemainingAccounts = [
+      ...remainingAccounts.slice(0, 6),
+      // payer in self-invoked CPI (pays for the role_member account creation).
+      { isSigner: true, isWritable: true, pubkey: provider.wallet.publicKey },
+      // state in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: state },
+      // role_member in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: roleMember },
+      // system_program in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: web3.SystemProgram.programId },
+      ...remainingAccounts.slice(7),
+    ];
+    const calldata = ethereumIface.encodeFunctionData("grantRole", [role, member.toBuffer()]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(grantRoleRemainingAccounts)
+      .rpc();
+    const roleMemberData = await program.account.roleMember.fetch(roleMember);
+    assert.strictEqual(roleMemberData.role, role, "Role should match");
+    assert.strictEqual(roleMemberData.member.toString(), member.toString(), "Member should match");
+  });
+
   it("Relays root bundle remotely", async () => {
     // Encode relayRootBundle message.
     const relayerRefundRoot = crypto.randomBytes(32);
@@ -325,8 +722,8 @@ describe("svm_spoke.handle_receive_message", () => {
     rootBundleIdBuffer.writeUInt32LE(rootBundleId);
     const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
     const [rootBundle] = web3.PublicKey.findProgramAddressSync(seeds, program.programId);
-    // Same 3 remaining accounts passed for HandleReceiveMessage context.
-    const relayRootBundleRemainingAccounts = remainingAccounts.slice(0, 3);
+    // Same 6 remaining accounts passed for HandleReceiveMessage context.
+    const relayRootBundleRemainingAccounts = remainingAccounts.slice(0, 6);
     // payer in self-invoked RelayRootBundle.
     relayRootBundleRemainingAccounts.push({
       isSigner: true,
@@ -390,7 +787,7 @@ describe("svm_spoke.handle_receive_message", () => {
     const [rootBundle] = web3.PublicKey.findProgramAddressSync(seeds, program.programId);
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
     await program.methods
-      .relayRootBundle(Array.from(relayerRefundRoot), Array.from(slowRelayRoot))
+      .relayRootBundle(Array.from(relayerRefundRoot), Array.from(slowRelayRoot), null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -413,8 +810,8 @@ describe("svm_spoke.handle_receive_message", () => {
     });
 
     // Remaining accounts specific to EmergencyDeletedRootBundle.
-    // Same 3 remaining accounts passed for HandleReceiveMessage context.
-    const emergencyDeleteRootBundleRemainingAccounts = remainingAccounts.slice(0, 3);
+    // Same 6 remaining accounts passed for HandleReceiveMessage context.
+    const emergencyDeleteRootBundleRemainingAccounts = remainingAccounts.slice(0, 6);
     // closer in self-invoked EmergencyDeletedRootBundle.
     emergencyDeleteRootBundleRemainingAccounts.push({
       isSigner: true,
@@ -466,6 +863,145 @@ describe("svm_spoke.handle_receive_message", () => {
     }
   });
 
+  it("Vetoes pending root bundle remotely", async () => {
+    // Locally relayed root bundle is pending once the timelock is set.
+    const setRootBundleTimelockAccounts = { signer: owner, state, program: program.programId };
+    await program.methods.setRootBundleTimelock(3600).accounts(setRootBundleTimelockAccounts).rpc();
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = web3.PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+    const { pendingUntil } = await program.account.rootBundle.fetch(rootBundle);
+    assert.notEqual(pendingUntil, 0, "Root bundle should be pending");
+
+    // The local owner cannot veto root bundles.
+    try {
+      await program.methods
+        .vetoRootBundle(rootBundleId)
+        .accounts({ signer: owner, payer: owner, state, rootBundle, program: program.programId })
+        .rpc();
+      assert.fail("Local owner should not be able to veto root bundles");
+    } catch (error: any) {
+      assert.instanceOf(error, AnchorError);
+      assert.strictEqual(error.error.errorCode.code, "NotCrossDomainAdmin", "Expected error code NotCrossDomainAdmin");
+    }
+
+    const calldata = ethereumIface.encodeFunctionData("vetoRootBundle", [rootBundleId]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    const vetoRootBundleRemainingAccounts = [
+      ...remainingAccounts.slice(0, 6),
+      // payer in self-invoked CPI (receives the rent of the vetoed root_bundle account).
+      { isSigner: false, isWritable: true, pubkey: owner },
+      // state in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: state },
+      // root_bundle in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: rootBundle },
+      ...remainingAccounts.slice(7),
+    ];
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(vetoRootBundleRemainingAccounts)
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(rootBundle), "Root bundle should be vetoed");
+  });
+
+  it("Disables root bundle timelock remotely", async () => {
+    const setRootBundleTimelockAccounts = { signer: owner, state, program: program.programId };
+    await program.methods.setRootBundleTimelock(3600).accounts(setRootBundleTimelockAccounts).rpc();
+
+    // Unlike the local owner, the cross-domain admin can lower the timelock.
+    const calldata = ethereumIface.encodeFunctionData("setRootBundleTimelock", [0]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+    const stateData = await program.account.state.fetch(state);
+    assert.strictEqual(stateData.rootBundleTimelock, 0, "Root bundle timelock should be disabled");
+  });
+
+  it("Relays typed root remotely", async () => {
+    // Relay root bundle.
+    const rootBundleId = (await program.account.state.fetch(state)).rootBundleId;
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = web3.PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+
+    // Encode relayTypedRoot message.
+    const rootType = 2;
+    const root = crypto.randomBytes(32);
+    const calldata = ethereumIface.encodeFunctionData("relayTypedRoot", [rootBundleId, rootType, root]);
+    const message = encodeMessageHeader({
+      version: cctpMessageversion,
+      sourceDomain: remoteDomain.toNumber(),
+      destinationDomain: localDomain,
+      nonce: BigInt(nonce),
+      sender: crossDomainAdmin,
+      recipient: program.programId,
+      destinationCaller,
+      messageBody: Buffer.from(calldata.slice(2), "hex"),
+    });
+
+    // Remaining accounts for the self-invoked CPI follow the first 6 accounts in HandleReceiveMessage.
+    const relayTypedRootRemainingAccounts = [
+      ...remainingAccounts.slice(0, 6),
+      // payer in self-invoked CPI (pays for the typed_root_claims account creation).
+      { isSigner: true, isWritable: true, pubkey: provider.wallet.publicKey },
+      // state in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: state },
+      // root_bundle in self-invoked CPI.
+      { isSigner: false, isWritable: true, pubkey: rootBundle },
+      // typed_root_claims in self-invoked CPI.
+      {
+        isSigner: false,
+        isWritable: true,
+        pubkey: getTypedRootClaimsPda(seed, rootBundleId, rootType, program.programId),
+      },
+      // system_program in self-invoked CPI.
+      { isSigner: false, isWritable: false, pubkey: web3.SystemProgram.programId },
+      ...remainingAccounts.slice(7),
+    ];
+    await messageTransmitterProgram.methods
+      .receiveMessage({ message, attestation })
+      .accounts(receiveMessageAccounts)
+      .remainingAccounts(relayTypedRootRemainingAccounts)
+      .rpc();
+
+    const { typedRoots } = await program.account.rootBundle.fetch(rootBundle);
+    assert.strictEqual(typedRoots[0].rootType, rootType, "Root type should be set");
+    assert.isTrue(Buffer.from(typedRoots[0].root).equals(root), "Root should be set");
+  });
+
   it("Replaying an old message is not possible", async () => {
     // Pause fills.
     const calldata = ethereumIface.encodeFunctionData("pauseFills", [true]);
@@ -536,4 +1072,329 @@ describe("svm_spoke.handle_receive_message", () => {
       assert.strictEqual(error.error.errorCode.code, "NonceAlreadyUsed", "Expected error code NonceAlreadyUsed");
     }
   });
+
+  it("Ignores sequenced admin messages with stale nonces", async () => {
+    const encodeSequencedMessage = (sequenceNonce: number, calldata: string): string =>
+      ethereumIface.encodeFunctionData("relaySequencedMessage", [sequenceNonce, calldata]);
+
+    const receivePauseFillsMessage = async (messageNonce: number, sequenceNonce: number | null, pause: boolean) => {
+      const pauseCalldata = ethereumIface.encodeFunctionData("pauseFills", [pause]);
+      const calldata = sequenceNonce === null ? pauseCalldata : encodeSequencedMessage(sequenceNonce, pauseCalldata);
+      const message = encodeMessageHeader({
+        version: cctpMessageversion,
+        sourceDomain: remoteDomain.toNumber(),
+        destinationDomain: localDomain,
+        nonce: BigInt(messageNonce),
+        sender: crossDomainAdmin,
+        recipient: program.programId,
+        destinationCaller,
+        messageBody: Buffer.from(calldata.slice(2), "hex"),
+      });
+      const usedNonces = await messageTransmitterProgram.methods
+        .getNoncePda({ nonce: new BN(messageNonce.toString()), sourceDomain: remoteDomain.toNumber() })
+        .accounts({ messageTransmitter: messageTransmitterState })
+        .view();
+      return messageTransmitterProgram.methods
+        .receiveMessage({ message, attestation })
+        .accounts({ ...receiveMessageAccounts, usedNonces })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+    };
+
+    // The more recent sequenced pause message is received first.
+    let tx = await receivePauseFillsMessage(nonce + 1, 2, true);
+    let stateData = await program.account.state.fetch(state);
+    assert.isTrue(stateData.pausedFills, "Fills should be paused");
+    let remoteNonceData = await program.account.remoteNonce.fetch(remoteNonce);
+    assert.strictEqual(remoteNonceData.lastNonce.toString(), "2", "Last nonce should be updated");
+
+    // Verify the AppliedRemoteMessage event.
+    let events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const appliedRemoteMessageEvent = events.find((event) => event.name === "appliedRemoteMessage")?.data;
+    assert.strictEqual(appliedRemoteMessageEvent.remoteDomain, remoteDomain.toNumber(), "Remote domain should match");
+    assert.strictEqual(appliedRemoteMessageEvent.sender.toString(), crossDomainAdmin.toString(), "Sender should match");
+    assert.deepEqual(
+      appliedRemoteMessageEvent.selector,
+      Array.from(Buffer.from(ethereumIface.getSighash("pauseFills").slice(2), "hex")),
+      "Selector should match"
+    );
+    assert.strictEqual(appliedRemoteMessageEvent.nonce.toString(), "2", "Nonce should match");
+
+    // The delayed sequenced unpause message with an older nonce is ignored.
+    tx = await receivePauseFillsMessage(nonce + 2, 1, false);
+    stateData = await program.account.state.fetch(state);
+    assert.isTrue(stateData.pausedFills, "Fills should still be paused");
+    remoteNonceData = await program.account.remoteNonce.fetch(remoteNonce);
+    assert.strictEqual(remoteNonceData.lastNonce.toString(), "2", "Last nonce should not be updated");
+
+    // Verify the IgnoredRemoteMessage event.
+    events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const ignoredRemoteMessageEvent = events.find((event) => event.name === "ignoredRemoteMessage")?.data;
+    assert.strictEqual(ignoredRemoteMessageEvent.remoteDomain, remoteDomain.toNumber(), "Remote domain should match");
+    assert.strictEqual(ignoredRemoteMessageEvent.sender.toString(), crossDomainAdmin.toString(), "Sender should match");
+    assert.deepEqual(
+      ignoredRemoteMessageEvent.selector,
+      Array.from(Buffer.from(ethereumIface.getSighash("pauseFills").slice(2), "hex")),
+      "Selector should match"
+    );
+    assert.strictEqual(ignoredRemoteMessageEvent.nonce.toString(), "1", "Nonce should match");
+    assert.isUndefined(
+      events.find((event) => event.name === "appliedRemoteMessage"),
+      "Stale message should not be applied"
+    );
+
+    // Unsequenced messages are applied without touching the last nonce.
+    tx = await receivePauseFillsMessage(nonce + 3, null, false);
+    stateData = await program.account.state.fetch(state);
+    assert.isFalse(stateData.pausedFills, "Fills should not be paused");
+    remoteNonceData = await program.account.remoteNonce.fetch(remoteNonce);
+    assert.strictEqual(remoteNonceData.lastNonce.toString(), "2", "Last nonce should not be updated");
+    events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const unsequencedEvent = events.find((event) => event.name === "appliedRemoteMessage")?.data;
+    assert.isNull(unsequencedEvent.nonce, "Unsequenced message should not have a nonce");
+
+    // Sequenced messages with a greater nonce are applied.
+    await receivePauseFillsMessage(nonce + 4, 3, true);
+    stateData = await program.account.state.fetch(state);
+    assert.isTrue(stateData.pausedFills, "Fills should be paused");
+    remoteNonceData = await program.account.remoteNonce.fetch(remoteNonce);
+    assert.strictEqual(remoteNonceData.lastNonce.toString(), "3", "Last nonce should be updated");
+    nonce += 4;
+  });
+
+  describe("Additional remote admins", () => {
+    const secondaryDomain = 6;
+    const secondarySender = evmAddressToPublicKey(ethers.Wallet.createRandom().address);
+    let remoteAdmin: web3.PublicKey;
+    let secondaryRemoteNonce: web3.PublicKey;
+
+    const getSelector = (functionName: string): number[] =>
+      Array.from(Buffer.from(ethereumIface.getSighash(functionName).slice(2), "hex"));
+
+    const getRemoteAdminPda = (sender: web3.PublicKey): web3.PublicKey => {
+      const domainBuffer = Buffer.alloc(4);
+      domainBuffer.writeUInt32LE(secondaryDomain);
+      return web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("remote_admin"), seed.toArrayLike(Buffer, "le", 8), domainBuffer, sender.toBuffer()],
+        program.programId
+      )[0];
+    };
+
+    const receiveSecondaryMessage = async (
+      calldata: string,
+      { sender = secondarySender, messageNonce = nonce, messageRemoteNonce = secondaryRemoteNonce } = {}
+    ) => {
+      const secondaryReceiveMessageAccounts = {
+        ...receiveMessageAccounts,
+        usedNonces: await messageTransmitterProgram.methods
+          .getNoncePda({ nonce: new BN(messageNonce.toString()), sourceDomain: secondaryDomain })
+          .accounts({ messageTransmitter: messageTransmitterState })
+          .view(),
+      };
+      const message = encodeMessageHeader({
+        version: cctpMessageversion,
+        sourceDomain: secondaryDomain,
+        destinationDomain: localDomain,
+        nonce: BigInt(messageNonce),
+        sender,
+        recipient: program.programId,
+        destinationCaller,
+        messageBody: Buffer.from(calldata.slice(2), "hex"),
+      });
+
+      // Nonces of the secondary domain are tracked in its own remote_nonce PDA and remote_admin PDA must precede the
+      // accounts passed to the self-invoked CPI.
+      const secondaryRemainingAccounts = [
+        remainingAccounts[0],
+        { isSigner: false, isWritable: true, pubkey: messageRemoteNonce },
+        ...remainingAccounts.slice(2, 6),
+        { isSigner: false, isWritable: true, pubkey: getRemoteAdminPda(sender) },
+        ...remainingAccounts.slice(6),
+      ];
+
+      return messageTransmitterProgram.methods
+        .receiveMessage({ message, attestation })
+        .accounts(secondaryReceiveMessageAccounts)
+        .remainingAccounts(secondaryRemainingAccounts)
+        .rpc();
+    };
+
+    beforeEach(async () => {
+      remoteAdmin = getRemoteAdminPda(secondarySender);
+      secondaryRemoteNonce = getRemoteNoncePda(seed, secondaryDomain, program.programId);
+      await program.methods
+        .setRemoteAdmin(secondaryDomain, secondarySender, [getSelector("pauseDeposits")])
+        .accounts({
+          signer: owner,
+          payer: owner,
+          state,
+          remoteAdmin,
+          remoteNonce: secondaryRemoteNonce,
+          program: program.programId,
+        })
+        .rpc();
+    });
+
+    it("Stores remote admin with permitted selectors", async () => {
+      const remoteAdminData = await program.account.remoteAdmin.fetch(remoteAdmin);
+      assert.strictEqual(remoteAdminData.remoteDomain, secondaryDomain, "Remote domain should match");
+      assert.strictEqual(remoteAdminData.sender.toString(), secondarySender.toString(), "Sender should match");
+      assert.deepEqual(remoteAdminData.selectors, [getSelector("pauseDeposits")], "Selectors should match");
+
+      // Nonces of the remote domain start being tracked once its first remote admin is set.
+      const remoteNonceData = await program.account.remoteNonce.fetch(secondaryRemoteNonce);
+      assert.strictEqual(remoteNonceData.remoteDomain, secondaryDomain, "Remote domain should match");
+      assert.strictEqual(remoteNonceData.lastNonce.toString(), "0", "Last nonce should not be set");
+    });
+
+    it("Pauses deposits remotely from additional remote admin", async () => {
+      await receiveSecondaryMessage(ethereumIface.encodeFunctionData("pauseDeposits", [true]));
+
+      const stateData = await program.account.state.fetch(state);
+      assert.isTrue(stateData.pausedDeposits, "Deposits should be paused");
+    });
+
+    it("Orders nonces of all remote admins on the same remote domain", async () => {
+      // Another remote admin on the secondary domain shares its nonces with the first one.
+      const otherSender = evmAddressToPublicKey(ethers.Wallet.createRandom().address);
+      await program.methods
+        .setRemoteAdmin(secondaryDomain, otherSender, [getSelector("pauseDeposits")])
+        .accounts({
+          signer: owner,
+          payer: owner,
+          state,
+          remoteAdmin: getRemoteAdminPda(otherSender),
+          remoteNonce: secondaryRemoteNonce,
+          program: program.programId,
+        })
+        .rpc();
+
+      const encodeSequencedPause = (sequenceNonce: number, pause: boolean): string =>
+        ethereumIface.encodeFunctionData("relaySequencedMessage", [
+          sequenceNonce,
+          ethereumIface.encodeFunctionData("pauseDeposits", [pause]),
+        ]);
+
+      await receiveSecondaryMessage(encodeSequencedPause(2, true), { messageNonce: nonce + 1 });
+      assert.isTrue((await program.account.state.fetch(state)).pausedDeposits, "Deposits should be paused");
+      let remoteNonceData = await program.account.remoteNonce.fetch(secondaryRemoteNonce);
+      assert.strictEqual(remoteNonceData.lastNonce.toString(), "2", "Last nonce should be updated");
+
+      // Older sequenced message from the other remote admin on the same domain is ignored.
+      const tx = await receiveSecondaryMessage(encodeSequencedPause(1, false), { sender: otherSender });
+      assert.isTrue((await program.account.state.fetch(state)).pausedDeposits, "Deposits should still be paused");
+      remoteNonceData = await program.account.remoteNonce.fetch(secondaryRemoteNonce);
+      assert.strictEqual(remoteNonceData.lastNonce.toString(), "2", "Last nonce should not be updated");
+      const events = await readEventsUntilFound(provider.connection, tx, [program]);
+      const ignoredRemoteMessageEvent = events.find((event) => event.name === "ignoredRemoteMessage")?.data;
+      assert.strictEqual(ignoredRemoteMessageEvent.remoteDomain, secondaryDomain, "Remote domain should match");
+      assert.strictEqual(ignoredRemoteMessageEvent.sender.toString(), otherSender.toString(), "Sender should match");
+      assert.strictEqual(ignoredRemoteMessageEvent.nonce.toString(), "1", "Nonce should match");
+
+      // Nonces of the primary remote domain are tracked separately.
+      const primaryNonceData = await program.account.remoteNonce.fetch(remoteNonce);
+      assert.strictEqual(primaryNonceData.lastNonce.toString(), "0", "Primary domain nonce should not be updated");
+      nonce += 1;
+    });
+
+    it("Rejects remote nonce of another remote domain", async () => {
+      try {
+        const calldata = ethereumIface.encodeFunctionData("relaySequencedMessage", [
+          1,
+          ethereumIface.encodeFunctionData("pauseDeposits", [true]),
+        ]);
+        await receiveSecondaryMessage(calldata, { messageRemoteNonce: remoteNonce });
+        assert.fail("Should not be able to use remote nonce of another domain");
+      } catch (error: any) {
+        assert.instanceOf(error, AnchorError);
+        assert.strictEqual(error.error.errorCode.code, "ConstraintSeeds", "Expected error code ConstraintSeeds");
+      }
+    });
+
+    it("Blocks additional remote admin from lowering the root bundle timelock", async () => {
+      await program.methods
+        .setRemoteAdmin(secondaryDomain, secondarySender, [getSelector("setRootBundleTimelock")])
+        .accounts({
+          signer: owner,
+          payer: owner,
+          state,
+          remoteAdmin,
+          remoteNonce: secondaryRemoteNonce,
+          program: program.programId,
+        })
+        .rpc();
+      await program.methods
+        .setRootBundleTimelock(3600)
+        .accounts({ signer: owner, state, program: program.programId })
+        .rpc();
+
+      // Self-invoked CPIs from additional remote admins are not signed by self_authority, so unlike the cross-domain
+      // admin they can only extend the timelock.
+      try {
+        await receiveSecondaryMessage(ethereumIface.encodeFunctionData("setRootBundleTimelock", [0]));
+        assert.fail("Additional remote admin should not be able to lower the timelock");
+      } catch (error: any) {
+        assert.include(error.toString(), "InvalidRootBundleTimelock", "Expected InvalidRootBundleTimelock error");
+      }
+      assert.strictEqual((await program.account.state.fetch(state)).rootBundleTimelock, 3600, "Timelock should stay");
+
+      await receiveSecondaryMessage(ethereumIface.encodeFunctionData("setRootBundleTimelock", [7200]));
+      assert.strictEqual((await program.account.state.fetch(state)).rootBundleTimelock, 7200, "Timelock should extend");
+    });
+
+    it("Blocks selector not permitted for additional remote admin", async () => {
+      try {
+        await receiveSecondaryMessage(ethereumIface.encodeFunctionData("pauseFills", [true]));
+        assert.fail("Should not be able to call selector not permitted for remote admin");
+      } catch (error: any) {
+        assert.instanceOf(error, AnchorError);
+        assert.strictEqual(
+          error.error.errorCode.code,
+          "RemoteSelectorNotPermitted",
+          "Expected error code RemoteSelectorNotPermitted"
+        );
+      }
+    });
+
+    it("Blocks removed remote admin", async () => {
+      await program.methods
+        .removeRemoteAdmin(secondaryDomain, secondarySender)
+        .accounts({ signer: owner, closer: owner, state, remoteAdmin, program: program.programId })
+        .rpc();
+
+      try {
+        await receiveSecondaryMessage(ethereumIface.encodeFunctionData("pauseDeposits", [true]));
+        assert.fail("Should not be able to receive message from removed remote admin");
+      } catch (error: any) {
+        assert.instanceOf(error, AnchorError);
+        assert.strictEqual(
+          error.error.errorCode.code,
+          "InvalidRemoteDomain",
+          "Expected error code InvalidRemoteDomain"
+        );
+      }
+    });
+
+    it("Only owner can set remote admin", async () => {
+      const nonOwner = Keypair.generate();
+      try {
+        await program.methods
+          .setRemoteAdmin(secondaryDomain, secondarySender, [getSelector("pauseFills")])
+          .accounts({
+            signer: nonOwner.publicKey,
+            payer: owner,
+            state,
+            remoteAdmin,
+            remoteNonce: secondaryRemoteNonce,
+            program: program.programId,
+          })
+          .signers([nonOwner])
+          .rpc();
+        assert.fail("Non-owner should not be able to set remote admin");
+      } catch (error: any) {
+        assert.instanceOf(error, AnchorError);
+        assert.strictEqual(error.error.errorCode.code, "NotOwner", "Expected error code NotOwner");
+      }
+    });
+  });
 });
@@ -0,0 +1,204 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { common } from "./SvmSpoke.common";
+import { getRemoteNoncePda } from "../../src/svm/web3-v1";
+
+const { provider, program, owner, assertSE } = common;
+
+describe("svm_spoke.migration", () => {
+  anchor.setProvider(provider);
+
+  // Legacy accounts are loaded from test/svm/accounts fixtures that hold them in the initially deployed layout.
+  const legacySeed = new BN(4242);
+  const legacyRemoteDomain = 0;
+  const legacyRootBundleId = 0;
+  const [state] = PublicKey.findProgramAddressSync(
+    [Buffer.from("state"), legacySeed.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const rootBundleIdBuffer = Buffer.alloc(4);
+  rootBundleIdBuffer.writeUInt32LE(legacyRootBundleId);
+  const [rootBundle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("root_bundle"), legacySeed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer],
+    program.programId
+  );
+
+  const remoteNonce = getRemoteNoncePda(legacySeed, legacyRemoteDomain, program.programId);
+
+  const migrateState = (remoteDomain = legacyRemoteDomain) =>
+    program.methods
+      .migrateState(legacySeed, remoteDomain)
+      .accounts({
+        payer: owner,
+        state,
+        remoteNonce: getRemoteNoncePda(legacySeed, remoteDomain, program.programId),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      } as any)
+      .rpc();
+
+  const migrateRootBundle = (payer?: Keypair) =>
+    program.methods
+      .migrateRootBundle(legacyRootBundleId)
+      .accounts({
+        payer: payer?.publicKey ?? owner,
+        state,
+        rootBundle,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      } as any)
+      .signers(payer ? [payer] : [])
+      .rpc();
+
+  it("Rejects migrating root bundles before their state", async () => {
+    try {
+      await migrateRootBundle();
+      assert.fail("Root bundle migration should fail before state migration");
+    } catch (err: any) {
+      assert.include(err.toString(), "AccountDidNotDeserialize", "Expected AccountDidNotDeserialize error");
+    }
+  });
+
+  it("Rejects migrating state with another remote domain", async () => {
+    try {
+      await migrateState(legacyRemoteDomain + 1);
+      assert.fail("State migration should fail with another remote domain");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidRemoteDomain", "Expected InvalidRemoteDomain error");
+    }
+  });
+
+  it("Migrates legacy state", async () => {
+    await migrateState();
+
+    const stateData = await program.account.state.fetch(state);
+    assert.isFalse(stateData.pausedDeposits, "Deposits should not be paused");
+    assert.isFalse(stateData.pausedFills, "Fills should not be paused");
+    assertSE(stateData.owner, owner, "Owner should be preserved");
+    assertSE(stateData.seed, legacySeed, "Seed should be preserved");
+    assertSE(stateData.numberOfDeposits, 3, "Number of deposits should be preserved");
+    assertSE(stateData.chainId, 420, "Chain ID should be preserved");
+    assertSE(stateData.currentTime, 1700000000, "Current time should be preserved");
+    assertSE(stateData.rootBundleId, 1, "Root bundle ID should be preserved");
+    assertSE(stateData.depositQuoteTimeBuffer, 3600, "Deposit quote time buffer should be preserved");
+    assertSE(stateData.fillDeadlineBuffer, 14400, "Fill deadline buffer should be preserved");
+    assertSE(stateData.rootBundleCloseGracePeriod, 2592000, "Grace period should be backfilled with the default");
+    assertSE(stateData.compressedFillStatusCutover, 0, "Compressed fill statuses should be disabled");
+    assertSE(stateData.pendingOwner, PublicKey.default, "No ownership transfer should be pending");
+    assertSE(stateData.rootBundleTimelock, 0, "Root bundle timelock should be disabled");
+    assert.isFalse(stateData.pausedRefunds, "Refunds should not be paused");
+    assert.isFalse(stateData.pausedClaims, "Claims should not be paused");
+    assert.isFalse(stateData.pausedBridge, "Bridging should not be paused");
+
+    const stateAccount = await provider.connection.getAccountInfo(state);
+    assertSE(stateAccount?.data.length, program.account.state.size, "State should be reallocated");
+
+    // Admin messages from the remote domain are ordered from the first one received after the migration.
+    const remoteNonceData = await program.account.remoteNonce.fetch(remoteNonce);
+    assertSE(remoteNonceData.remoteDomain, legacyRemoteDomain, "Remote domain should match");
+    assertSE(remoteNonceData.lastNonce, 0, "Last nonce should not be set");
+  });
+
+  it("Rejects migrating already migrated state", async () => {
+    try {
+      await migrateState();
+      assert.fail("Second state migration should fail");
+    } catch (err: any) {
+      assert.include(err.toString(), "AccountAlreadyMigrated", "Expected AccountAlreadyMigrated error");
+    }
+  });
+
+  it("Sets compressed fill status cutover on migrated state", async () => {
+    const cutover = 1700003600;
+    await program.methods
+      .setCompressedFillStatusCutover(cutover)
+      .accounts({ signer: owner, state, program: program.programId })
+      .rpc();
+
+    const stateData = await program.account.state.fetch(state);
+    assertSE(stateData.compressedFillStatusCutover, cutover, "Compressed fill status cutover should be set");
+  });
+
+  it("Transfers ownership of migrated state", async () => {
+    const ownershipAccounts = { state, signer: owner, program: program.programId };
+    try {
+      await program.methods.cancelOwnershipTransfer().accounts(ownershipAccounts).rpc();
+      assert.fail("Cancelling should fail without a pending ownership transfer");
+    } catch (err: any) {
+      assert.include(err.toString(), "NoPendingOwnershipTransfer", "Expected NoPendingOwnershipTransfer error");
+    }
+
+    const newOwner = Keypair.generate();
+    await program.methods.transferOwnership(newOwner.publicKey).accounts(ownershipAccounts).rpc();
+    assertSE((await program.account.state.fetch(state)).pendingOwner, newOwner.publicKey, "Pending owner");
+
+    // Cancel the transfer so that the legacy owner keeps administering the migrated state.
+    await program.methods.cancelOwnershipTransfer().accounts(ownershipAccounts).rpc();
+    const stateData = await program.account.state.fetch(state);
+    assertSE(stateData.owner, owner, "Owner should be unchanged");
+    assertSE(stateData.pendingOwner, PublicKey.default, "Pending owner should be cleared");
+  });
+
+  it("Pauses refunds, claims and bridging on migrated state", async () => {
+    const pauseAccounts = { state, signer: owner, program: program.programId };
+    const pauseMethods = [
+      { pause: program.methods.pauseRefunds, flag: "pausedRefunds" },
+      { pause: program.methods.pauseClaims, flag: "pausedClaims" },
+      { pause: program.methods.pauseBridge, flag: "pausedBridge" },
+    ] as const;
+
+    for (const { pause, flag } of pauseMethods) {
+      await pause(true).accounts(pauseAccounts).rpc();
+      assert.isTrue((await program.account.state.fetch(state))[flag], `${flag} should be set`);
+
+      await pause(false).accounts(pauseAccounts).rpc();
+      assert.isFalse((await program.account.state.fetch(state))[flag], `${flag} should not be set`);
+    }
+  });
+
+  it("Migrates legacy root bundle", async () => {
+    // Migration is permissionless, but the rent of migrated root bundles is returned to the owner on close.
+    const migrationPayer = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(migrationPayer.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(sig);
+    await migrateRootBundle(migrationPayer);
+
+    const rootBundleData = await program.account.rootBundle.fetch(rootBundle);
+    assertSE(Buffer.from(rootBundleData.relayerRefundRoot).toString("hex"), "22".repeat(32), "Refund root");
+    assertSE(Buffer.from(rootBundleData.slowRelayRoot).toString("hex"), "00".repeat(32), "Slow relay root");
+    assertSE(Buffer.from(rootBundleData.claimedBitmap).toString("hex"), "05", "Claimed bitmap should be preserved");
+    assertSE(rootBundleData.payer, owner, "Owner should receive the rent on close");
+    assertSE(rootBundleData.createdAt, 1700000000, "Created at should be set to the migration time");
+    assert.isNull(rootBundleData.leafCount, "Leaf count should be unknown");
+    assertSE(rootBundleData.claimedLeafCount, 2, "Claimed leaf count should match the bitmap");
+    assert.isEmpty(rootBundleData.typedRoots, "Typed roots should be empty");
+    assertSE(rootBundleData.pendingUntil, 0, "Migrated root bundle should be active");
+  });
+
+  it("Keeps migrated root bundle active after the timelock is set", async () => {
+    await program.methods
+      .setRootBundleTimelock(3600)
+      .accounts({ signer: owner, state, program: program.programId })
+      .rpc();
+
+    try {
+      await program.methods
+        .activateRootBundle(legacyRootBundleId)
+        .accounts({ state, rootBundle, program: program.programId })
+        .rpc();
+      assert.fail("Migrated root bundle should not be pending");
+    } catch (err: any) {
+      assert.include(err.toString(), "RootBundleNotPending", "Expected RootBundleNotPending error");
+    }
+    assertSE((await program.account.rootBundle.fetch(rootBundle)).pendingUntil, 0, "Root bundle should stay active");
+  });
+
+  it("Rejects migrating already migrated root bundle", async () => {
+    try {
+      await migrateRootBundle();
+      assert.fail("Second root bundle migration should fail");
+    } catch (err: any) {
+      assert.include(err.toString(), "AccountAlreadyMigrated", "Expected AccountAlreadyMigrated error");
+    }
+  });
+});
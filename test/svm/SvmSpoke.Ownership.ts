@@ -122,14 +122,65 @@ describe("svm_spoke.ownership", () => {
     }
   });
 
+  it("Pauses and unpauses refunds, claims and bridging", async () => {
+    const pauseAccounts = { state, signer: owner, program: program.programId };
+    const pauseMethods = [
+      { pause: program.methods.pauseRefunds, flag: "pausedRefunds", event: "pausedRefunds" },
+      { pause: program.methods.pauseClaims, flag: "pausedClaims", event: "pausedClaims" },
+      { pause: program.methods.pauseBridge, flag: "pausedBridge", event: "pausedBridge" },
+    ] as const;
+
+    for (const { pause, flag, event } of pauseMethods) {
+      assert.isFalse((await program.account.state.fetch(state))[flag], `${flag} should not be set`);
+
+      // Pause as owner
+      let tx = await pause(true).accounts(pauseAccounts).rpc();
+      assert.isTrue((await program.account.state.fetch(state))[flag], `${flag} should be set`);
+      let events = await readEventsUntilFound(provider.connection, tx, [program]);
+      assert.isTrue(events.find((e) => e.name === event)?.data.isPaused, `${event} event should indicate pause`);
+
+      // Unpause as owner
+      tx = await pause(false).accounts(pauseAccounts).rpc();
+      assert.isFalse((await program.account.state.fetch(state))[flag], `${flag} should not be set`);
+      events = await readEventsUntilFound(provider.connection, tx, [program]);
+      assert.isFalse(events.find((e) => e.name === event)?.data.isPaused, `${event} event should indicate unpause`);
+
+      // Try to pause as non-owner
+      try {
+        await pause(true).accounts({ ...pauseAccounts, signer: nonOwner.publicKey }).signers([nonOwner]).rpc();
+        assert.fail(`Non-owner should not be able to set ${flag}`);
+      } catch (err: any) {
+        assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+      }
+    }
+  });
+
   it("Transfers ownership", async () => {
-    // Transfer ownership to newOwner
+    // Propose newOwner as the new owner
     const transferOwnershipAccounts = { state, signer: owner, program: program.programId };
-    const tx = await program.methods.transferOwnership(newOwner.publicKey).accounts(transferOwnershipAccounts).rpc();
+    let tx = await program.methods.transferOwnership(newOwner.publicKey).accounts(transferOwnershipAccounts).rpc();
 
-    // Verify the TransferredOwnership event
+    // Verify the StartedOwnershipTransfer event
     let events = await readEventsUntilFound(provider.connection, tx, [program]);
-    let transferredOwnershipEvents = events.filter((event) => event.name === "transferredOwnership");
+    const startedOwnershipTransferEvents = events.filter((event) => event.name === "startedOwnershipTransfer");
+    assert.equal(
+      startedOwnershipTransferEvents[0].data.pendingOwner.toString(),
+      newOwner.publicKey.toString(),
+      "StartedOwnershipTransfer event should indicate the pending owner"
+    );
+
+    // Ownership should not change until the pending owner accepts it
+    let stateAccountData = await program.account.state.fetch(state);
+    assert.equal(stateAccountData.owner.toString(), owner.toString(), "Owner should not change before acceptance");
+    assert.equal(stateAccountData.pendingOwner.toString(), newOwner.publicKey.toString(), "Wrong pending owner");
+
+    // Accept ownership as newOwner
+    const acceptOwnershipAccounts = { state, signer: newOwner.publicKey, program: program.programId };
+    tx = await program.methods.acceptOwnership().accounts(acceptOwnershipAccounts).signers([newOwner]).rpc();
+
+    // Verify the TransferredOwnership event
+    events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const transferredOwnershipEvents = events.filter((event) => event.name === "transferredOwnership");
     assert.equal(
       transferredOwnershipEvents[0].data.newOwner.toString(),
       newOwner.publicKey.toString(),
@@ -137,8 +188,9 @@ describe("svm_spoke.ownership", () => {
     );
 
     // Verify the new owner
-    let stateAccountData = await program.account.state.fetch(state);
+    stateAccountData = await program.account.state.fetch(state);
     assert.equal(stateAccountData.owner.toString(), newOwner.publicKey.toString(), "Ownership should be transferred");
+    assert.equal(stateAccountData.pendingOwner.toString(), PublicKey.default.toString(), "Pending owner should clear");
 
     // Try to transfer ownership as non-owner
     try {
@@ -154,6 +206,79 @@ describe("svm_spoke.ownership", () => {
     }
   });
 
+  it("Only the pending owner can accept ownership", async () => {
+    // Accepting without a pending transfer should fail
+    try {
+      const acceptOwnershipAccounts = { state, signer: newOwner.publicKey, program: program.programId };
+      await program.methods.acceptOwnership().accounts(acceptOwnershipAccounts).signers([newOwner]).rpc();
+      assert.fail("Accepting ownership without a pending transfer should fail");
+    } catch (err: any) {
+      assert.include(err.toString(), "NotPendingOwner", "Expected pending owner check error");
+    }
+
+    const transferOwnershipAccounts = { state, signer: owner, program: program.programId };
+    await program.methods.transferOwnership(newOwner.publicKey).accounts(transferOwnershipAccounts).rpc();
+
+    // Try to accept ownership as someone other than the pending owner
+    try {
+      const acceptOwnershipAccounts = { state, signer: nonOwner.publicKey, program: program.programId };
+      await program.methods.acceptOwnership().accounts(acceptOwnershipAccounts).signers([nonOwner]).rpc();
+      assert.fail("Only the pending owner should be able to accept ownership");
+    } catch (err: any) {
+      assert.include(err.toString(), "NotPendingOwner", "Expected pending owner check error");
+    }
+
+    const stateAccountData = await program.account.state.fetch(state);
+    assert.equal(stateAccountData.owner.toString(), owner.toString(), "Owner should not change");
+  });
+
+  it("Cancels ownership transfer", async () => {
+    const transferOwnershipAccounts = { state, signer: owner, program: program.programId };
+    await program.methods.transferOwnership(newOwner.publicKey).accounts(transferOwnershipAccounts).rpc();
+
+    // Try to cancel the transfer as non-owner
+    try {
+      const cancelAccounts = { state, signer: nonOwner.publicKey, program: program.programId };
+      await program.methods.cancelOwnershipTransfer().accounts(cancelAccounts).signers([nonOwner]).rpc();
+      assert.fail("Non-owner should not be able to cancel ownership transfer");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+
+    // Cancel the transfer as owner
+    const cancelAccounts = { state, signer: owner, program: program.programId };
+    const tx = await program.methods.cancelOwnershipTransfer().accounts(cancelAccounts).rpc();
+
+    // Verify the CancelledOwnershipTransfer event
+    const events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const cancelledEvents = events.filter((event) => event.name === "cancelledOwnershipTransfer");
+    assert.equal(
+      cancelledEvents[0].data.pendingOwner.toString(),
+      newOwner.publicKey.toString(),
+      "CancelledOwnershipTransfer event should indicate the cancelled pending owner"
+    );
+
+    const stateAccountData = await program.account.state.fetch(state);
+    assert.equal(stateAccountData.pendingOwner.toString(), PublicKey.default.toString(), "Pending owner should clear");
+
+    // The previously pending owner can no longer accept
+    try {
+      const acceptOwnershipAccounts = { state, signer: newOwner.publicKey, program: program.programId };
+      await program.methods.acceptOwnership().accounts(acceptOwnershipAccounts).signers([newOwner]).rpc();
+      assert.fail("Cancelled pending owner should not be able to accept ownership");
+    } catch (err: any) {
+      assert.include(err.toString(), "NotPendingOwner", "Expected pending owner check error");
+    }
+
+    // Cancelling again should fail as there is no pending transfer
+    try {
+      await program.methods.cancelOwnershipTransfer().accounts(cancelAccounts).rpc();
+      assert.fail("Cancelling without a pending transfer should fail");
+    } catch (err: any) {
+      assert.include(err.toString(), "NoPendingOwnershipTransfer", "Expected no pending transfer error");
+    }
+  });
+
   it("Sets cross-domain admin", async () => {
     // Set cross-domain admin as owner
     const setCrossDomainAdminAccounts = { state, signer: owner, program: program.programId };
@@ -192,4 +317,85 @@ describe("svm_spoke.ownership", () => {
       assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
     }
   });
+
+  it("Sets deposit quote time buffer", async () => {
+    const newDepositQuoteTimeBuffer = 7200;
+    const setBufferAccounts = { state, signer: owner, program: program.programId };
+    const tx = await program.methods
+      .setDepositQuoteTimeBuffer(newDepositQuoteTimeBuffer)
+      .accounts(setBufferAccounts)
+      .rpc();
+
+    // Verify the new buffer and the SetDepositQuoteTimeBuffer event
+    const stateAccountData = await program.account.state.fetch(state);
+    assert.equal(stateAccountData.depositQuoteTimeBuffer, newDepositQuoteTimeBuffer, "Buffer should be set");
+    const events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const setBufferEvents = events.filter((event) => event.name === "setDepositQuoteTimeBuffer");
+    assert.equal(
+      setBufferEvents[0].data.newDepositQuoteTimeBuffer,
+      newDepositQuoteTimeBuffer,
+      "SetDepositQuoteTimeBuffer event should indicate the new buffer"
+    );
+
+    // Zero and out of bounds buffers are rejected
+    for (const invalidBuffer of [0, 86_401]) {
+      try {
+        await program.methods.setDepositQuoteTimeBuffer(invalidBuffer).accounts(setBufferAccounts).rpc();
+        assert.fail("Invalid buffer should not be accepted");
+      } catch (err: any) {
+        assert.include(err.toString(), "Invalid deposit quote time buffer!", "Expected buffer bounds error");
+      }
+    }
+
+    // Try to set buffer as non-owner
+    try {
+      await program.methods
+        .setDepositQuoteTimeBuffer(newDepositQuoteTimeBuffer)
+        .accounts({ state, signer: nonOwner.publicKey, program: program.programId })
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Non-owner should not be able to set deposit quote time buffer");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
+
+  it("Sets fill deadline buffer", async () => {
+    const newFillDeadlineBuffer = 3600 * 8;
+    const setBufferAccounts = { state, signer: owner, program: program.programId };
+    const tx = await program.methods.setFillDeadlineBuffer(newFillDeadlineBuffer).accounts(setBufferAccounts).rpc();
+
+    // Verify the new buffer and the SetFillDeadlineBuffer event
+    const stateAccountData = await program.account.state.fetch(state);
+    assert.equal(stateAccountData.fillDeadlineBuffer, newFillDeadlineBuffer, "Buffer should be set");
+    const events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const setBufferEvents = events.filter((event) => event.name === "setFillDeadlineBuffer");
+    assert.equal(
+      setBufferEvents[0].data.newFillDeadlineBuffer,
+      newFillDeadlineBuffer,
+      "SetFillDeadlineBuffer event should indicate the new buffer"
+    );
+
+    // Zero and out of bounds buffers are rejected
+    for (const invalidBuffer of [0, 604_801]) {
+      try {
+        await program.methods.setFillDeadlineBuffer(invalidBuffer).accounts(setBufferAccounts).rpc();
+        assert.fail("Invalid buffer should not be accepted");
+      } catch (err: any) {
+        assert.include(err.toString(), "Invalid fill deadline buffer!", "Expected buffer bounds error");
+      }
+    }
+
+    // Try to set buffer as non-owner
+    try {
+      await program.methods
+        .setFillDeadlineBuffer(newFillDeadlineBuffer)
+        .accounts({ state, signer: nonOwner.publicKey, program: program.programId })
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Non-owner should not be able to set fill deadline buffer");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
 });
@@ -95,7 +95,10 @@ describe("svm_spoke.refund_claims", () => {
 
     // Relay root bundle
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
-    await program.methods.relayRootBundle(Array.from(root), Array.from(root)).accounts(relayRootBundleAccounts).rpc();
+    await program.methods
+      .relayRootBundle(Array.from(root), Array.from(root), null)
+      .accounts(relayRootBundleAccounts)
+      .rpc();
 
     // Pass claim account as relayer refund address.
     const remainingAccounts = [{ pubkey: claimAccount, isWritable: true, isSigner: false }];
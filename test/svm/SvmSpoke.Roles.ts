@@ -0,0 +1,266 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { AccountMeta, Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import * as crypto from "crypto";
+import { common } from "./SvmSpoke.common";
+import { readEventsUntilFound } from "../../src/svm/web3-v1";
+
+const { provider, program, owner, initializeState } = common;
+
+const PAUSER_ROLE = 0;
+const BUNDLE_RELAYER_ROLE = 1;
+const EMERGENCY_ADMIN_ROLE = 2;
+const GUARDIAN_ROLE = 3;
+
+describe("svm_spoke.roles", () => {
+  anchor.setProvider(provider);
+
+  const nonOwner = Keypair.generate();
+  const member = Keypair.generate();
+  let state: PublicKey;
+  let seed: BN;
+
+  const getRoleMemberPda = (role: number, memberKey: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("role_member"), seed.toArrayLike(Buffer, "le", 8), Buffer.from([role]), memberKey.toBuffer()],
+      program.programId
+    )[0];
+
+  const roleMemberAccounts = (role: number, memberKey: PublicKey): AccountMeta[] => [
+    { pubkey: getRoleMemberPda(role, memberKey), isSigner: false, isWritable: false },
+  ];
+
+  const grantRole = async (role: number, memberKey: PublicKey) => {
+    const roleMember = getRoleMemberPda(role, memberKey);
+    const grantRoleAccounts = { signer: owner, payer: owner, state, roleMember, program: program.programId };
+    return program.methods.grantRole(role, memberKey).accounts(grantRoleAccounts).rpc();
+  };
+
+  const relayRootBundle = async (signer: Keypair, remainingAccounts: AccountMeta[]) => {
+    const { rootBundleId } = await program.account.state.fetch(state);
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(crypto.randomBytes(32)), null)
+      .accounts({ state, rootBundle, signer: signer.publicKey, payer: owner, program: program.programId })
+      .remainingAccounts(remainingAccounts)
+      .signers([signer])
+      .rpc();
+    return { rootBundleId, rootBundle };
+  };
+
+  beforeEach(async () => {
+    ({ state, seed } = await initializeState());
+  });
+
+  it("Grants and revokes roles", async () => {
+    const roleMember = getRoleMemberPda(PAUSER_ROLE, member.publicKey);
+    let tx = await grantRole(PAUSER_ROLE, member.publicKey);
+
+    // Verify the GrantedRole event and the role member account
+    let events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const grantedRoleEvent = events.find((event) => event.name === "grantedRole")?.data;
+    assert.strictEqual(grantedRoleEvent.role, PAUSER_ROLE, "GrantedRole event should indicate the role");
+    assert.strictEqual(grantedRoleEvent.member.toString(), member.publicKey.toString(), "Wrong GrantedRole member");
+    const roleMemberData = await program.account.roleMember.fetch(roleMember);
+    assert.strictEqual(roleMemberData.role, PAUSER_ROLE, "Role should match");
+    assert.strictEqual(roleMemberData.member.toString(), member.publicKey.toString(), "Member should match");
+
+    // Revoke the role
+    const revokeRoleAccounts = { signer: owner, closer: owner, state, roleMember, program: program.programId };
+    tx = await program.methods.revokeRole(PAUSER_ROLE, member.publicKey).accounts(revokeRoleAccounts).rpc();
+
+    // Verify the RevokedRole event and the closed role member account
+    events = await readEventsUntilFound(provider.connection, tx, [program]);
+    const revokedRoleEvent = events.find((event) => event.name === "revokedRole")?.data;
+    assert.strictEqual(revokedRoleEvent.role, PAUSER_ROLE, "RevokedRole event should indicate the role");
+    assert.strictEqual(revokedRoleEvent.member.toString(), member.publicKey.toString(), "Wrong RevokedRole member");
+    assert.isNull(await provider.connection.getAccountInfo(roleMember), "Role member should be closed");
+  });
+
+  it("Only owner can grant roles", async () => {
+    const roleMember = getRoleMemberPda(PAUSER_ROLE, nonOwner.publicKey);
+    try {
+      await program.methods
+        .grantRole(PAUSER_ROLE, nonOwner.publicKey)
+        .accounts({ signer: nonOwner.publicKey, payer: owner, state, roleMember, program: program.programId })
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Non-owner should not be able to grant roles");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
+
+  it("Rejects invalid roles", async () => {
+    try {
+      await grantRole(GUARDIAN_ROLE + 1, member.publicKey);
+      assert.fail("Should not be able to grant an invalid role");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidRole", "Expected invalid role error");
+    }
+  });
+
+  it("Pauser can pause but not unpause", async () => {
+    await grantRole(PAUSER_ROLE, member.publicKey);
+    const pauseAccounts = { state, signer: member.publicKey, program: program.programId };
+    const remainingAccounts = roleMemberAccounts(PAUSER_ROLE, member.publicKey);
+
+    // Pauser can pause deposits and fills
+    await program.methods
+      .pauseDeposits(true)
+      .accounts(pauseAccounts)
+      .remainingAccounts(remainingAccounts)
+      .signers([member])
+      .rpc();
+    await program.methods
+      .pauseFills(true)
+      .accounts(pauseAccounts)
+      .remainingAccounts(remainingAccounts)
+      .signers([member])
+      .rpc();
+    const stateData = await program.account.state.fetch(state);
+    assert.isTrue(stateData.pausedDeposits, "Deposits should be paused");
+    assert.isTrue(stateData.pausedFills, "Fills should be paused");
+
+    // Pauser cannot unpause
+    try {
+      await program.methods
+        .pauseDeposits(false)
+        .accounts(pauseAccounts)
+        .remainingAccounts(remainingAccounts)
+        .signers([member])
+        .rpc();
+      assert.fail("Pauser should not be able to unpause deposits");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+
+    // Owner can still unpause
+    await program.methods.pauseDeposits(false).accounts({ state, signer: owner, program: program.programId }).rpc();
+    assert.isFalse((await program.account.state.fetch(state)).pausedDeposits, "Deposits should be unpaused");
+  });
+
+  it("Guardian can pause refunds, claims and bridging", async () => {
+    await grantRole(GUARDIAN_ROLE, member.publicKey);
+    const pauseAccounts = { state, signer: member.publicKey, program: program.programId };
+    const remainingAccounts = roleMemberAccounts(GUARDIAN_ROLE, member.publicKey);
+
+    for (const pause of [program.methods.pauseRefunds, program.methods.pauseClaims, program.methods.pauseBridge]) {
+      await pause(true).accounts(pauseAccounts).remainingAccounts(remainingAccounts).signers([member]).rpc();
+    }
+    const stateData = await program.account.state.fetch(state);
+    assert.isTrue(stateData.pausedRefunds, "Refunds should be paused");
+    assert.isTrue(stateData.pausedClaims, "Claims should be paused");
+    assert.isTrue(stateData.pausedBridge, "Bridging should be paused");
+
+    // Guardian cannot unpause
+    try {
+      await program.methods
+        .pauseRefunds(false)
+        .accounts(pauseAccounts)
+        .remainingAccounts(remainingAccounts)
+        .signers([member])
+        .rpc();
+      assert.fail("Guardian should not be able to unpause refunds");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+
+    // Guardian cannot pause deposits reserved for the pauser role
+    try {
+      await program.methods
+        .pauseDeposits(true)
+        .accounts(pauseAccounts)
+        .remainingAccounts(remainingAccounts)
+        .signers([member])
+        .rpc();
+      assert.fail("Guardian should not be able to pause deposits");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
+
+  it("Roles only permit their own instructions", async () => {
+    await grantRole(PAUSER_ROLE, member.publicKey);
+
+    // Pauser cannot relay root bundles, even when passing its role member PDA
+    try {
+      await relayRootBundle(member, roleMemberAccounts(PAUSER_ROLE, member.publicKey));
+      assert.fail("Pauser should not be able to relay root bundles");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+
+    // Account without role cannot pause by passing another member's role PDA
+    try {
+      await program.methods
+        .pauseFills(true)
+        .accounts({ state, signer: nonOwner.publicKey, program: program.programId })
+        .remainingAccounts(roleMemberAccounts(PAUSER_ROLE, member.publicKey))
+        .signers([nonOwner])
+        .rpc();
+      assert.fail("Account without role should not be able to pause fills");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
+
+  it("Bundle relayer can relay root bundles and emergency admin can delete them", async () => {
+    const emergencyAdmin = Keypair.generate();
+    await grantRole(BUNDLE_RELAYER_ROLE, member.publicKey);
+    await grantRole(EMERGENCY_ADMIN_ROLE, emergencyAdmin.publicKey);
+
+    const { rootBundleId, rootBundle } = await relayRootBundle(
+      member,
+      roleMemberAccounts(BUNDLE_RELAYER_ROLE, member.publicKey)
+    );
+    assert.isNotNull(await provider.connection.getAccountInfo(rootBundle), "Root bundle should be relayed");
+
+    // Bundle relayer cannot emergency delete root bundles
+    const emergencyDeleteAccounts = { state, rootBundle, closer: owner, program: program.programId };
+    try {
+      await program.methods
+        .emergencyDeleteRootBundle(rootBundleId)
+        .accounts({ ...emergencyDeleteAccounts, signer: member.publicKey })
+        .remainingAccounts(roleMemberAccounts(BUNDLE_RELAYER_ROLE, member.publicKey))
+        .signers([member])
+        .rpc();
+      assert.fail("Bundle relayer should not be able to delete root bundles");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+
+    await program.methods
+      .emergencyDeleteRootBundle(rootBundleId)
+      .accounts({ ...emergencyDeleteAccounts, signer: emergencyAdmin.publicKey })
+      .remainingAccounts(roleMemberAccounts(EMERGENCY_ADMIN_ROLE, emergencyAdmin.publicKey))
+      .signers([emergencyAdmin])
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(rootBundle), "Root bundle should be deleted");
+  });
+
+  it("Revoked member loses the role", async () => {
+    await grantRole(PAUSER_ROLE, member.publicKey);
+    const roleMember = getRoleMemberPda(PAUSER_ROLE, member.publicKey);
+    await program.methods
+      .revokeRole(PAUSER_ROLE, member.publicKey)
+      .accounts({ signer: owner, closer: owner, state, roleMember, program: program.programId })
+      .rpc();
+
+    try {
+      await program.methods
+        .pauseDeposits(true)
+        .accounts({ state, signer: member.publicKey, program: program.programId })
+        .remainingAccounts(roleMemberAccounts(PAUSER_ROLE, member.publicKey))
+        .signers([member])
+        .rpc();
+      assert.fail("Revoked pauser should not be able to pause deposits");
+    } catch (err: any) {
+      assert.include(err.toString(), "Only the owner can call this function!", "Expected owner check error");
+    }
+  });
+});
@@ -122,7 +122,7 @@ describe("svm_spoke.slow_fill.across_plus", () => {
     // Relay root bundle
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
     await program.methods
-      .relayRootBundle(Array.from(relayerRefundRoot), Array.from(slowRelayRoot))
+      .relayRootBundle(Array.from(relayerRefundRoot), Array.from(slowRelayRoot), null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -18,6 +18,7 @@ import {
   getFillRelayDelegatePda,
   hashNonEmptyMessage,
   intToU8Array32,
+  loadExecuteSlowRelayLeavesParams,
   readEventsUntilFound,
   slowFillHashFn,
 } from "../../src/svm/web3-v1";
@@ -142,7 +143,7 @@ describe("svm_spoke.slow_fill", () => {
     // Relay root bundle
     const relayRootBundleAccounts = { state, rootBundle, signer: owner, payer: owner, program: program.programId };
     await program.methods
-      .relayRootBundle(Array.from(relayerRefundRoot), Array.from(slowRelayRoot))
+      .relayRootBundle(Array.from(relayerRefundRoot), Array.from(slowRelayRoot), null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -695,4 +696,131 @@ describe("svm_spoke.slow_fill", () => {
       `UpdatedMessageHash should be zeroed`
     );
   });
+
+  it("Executes multiple slow relay leaves with a multiproof", async () => {
+    // Relay root bundle with slow fill leaves of empty messages, as these are required for batched execution.
+    const slowRelayLeaves: SlowFillLeaf[] = Array.from({ length: 4 }, (_, i) => ({
+      relayData: {
+        depositor: recipient,
+        recipient: recipient,
+        exclusiveRelayer: relayer.publicKey,
+        inputToken: mint,
+        outputToken: mint,
+        inputAmount: intToU8Array32(relayAmount),
+        outputAmount: new BN(relayAmount),
+        originChainId: new BN(1),
+        depositId: intToU8Array32(Math.floor(Math.random() * 1000000) + i),
+        fillDeadline: Math.floor(Date.now() / 1000) + 60, // 1 minute from now
+        exclusivityDeadline: Math.floor(Date.now() / 1000) - 30, // In the past to avoid exclusivity deadline
+        message: Buffer.alloc(0),
+      },
+      chainId,
+      updatedOutputAmount: new BN(relayAmount + i),
+    }));
+    const merkleTree = new MerkleTree<SlowFillLeaf>(slowRelayLeaves, slowFillHashFn);
+
+    const { rootBundleId } = await program.account.state.fetch(state);
+    const rootBundleIdBuffer = Buffer.alloc(4);
+    rootBundleIdBuffer.writeUInt32LE(rootBundleId);
+    const seeds = [Buffer.from("root_bundle"), seed.toArrayLike(Buffer, "le", 8), rootBundleIdBuffer];
+    const [rootBundle] = PublicKey.findProgramAddressSync(seeds, program.programId);
+    await program.methods
+      .relayRootBundle(Array.from(crypto.randomBytes(32)), Array.from(merkleTree.getRoot()), null)
+      .accounts({ state, rootBundle, signer: owner, payer: owner, program: program.programId })
+      .rpc();
+
+    // Request slow fills for all but the first leaf.
+    const multiProof = merkleTree.getMultiProof(slowRelayLeaves.slice(1));
+    const fillStatuses = multiProof.leaves.map((leaf) => {
+      const relayHash = calculateRelayHashUint8Array(leaf.relayData, chainId);
+      return PublicKey.findProgramAddressSync([Buffer.from("fills"), relayHash], program.programId)[0];
+    });
+    for (const [i, leaf] of multiProof.leaves.entries()) {
+      await program.methods
+        .requestSlowFill(Array.from(calculateRelayHashUint8Array(leaf.relayData, chainId)), leaf.relayData)
+        .accounts({ ...requestAccounts, fillStatus: fillStatuses[i] })
+        .signers([relayer])
+        .rpc();
+    }
+
+    const [instructionParams] = PublicKey.findProgramAddressSync(
+      [Buffer.from("instruction_params"), relayer.publicKey.toBuffer()],
+      program.programId
+    );
+    const executeSlowRelayLeaves = async (leaves: SlowFillLeaf[]) => {
+      const loadInstructions = await loadExecuteSlowRelayLeavesParams(
+        program,
+        relayer,
+        rootBundleId,
+        leaves,
+        multiProof.proof.map((p) => Array.from(p)),
+        multiProof.proofFlags
+      );
+      for (const loadInstruction of loadInstructions) {
+        await sendAndConfirmTransaction(connection, new Transaction().add(loadInstruction), [relayer]);
+      }
+      const remainingAccounts = leaves.flatMap((leaf) => [
+        { pubkey: recipientTA, isWritable: true, isSigner: false },
+        { pubkey: fillStatuses[multiProof.leaves.indexOf(leaf)], isWritable: true, isSigner: false },
+      ]);
+      return program.methods
+        .executeSlowRelayLeaves(null, null, null, null)
+        .accounts({
+          state,
+          rootBundle,
+          signer: relayer.publicKey,
+          instructionParams,
+          mint,
+          vault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })])
+        .signers([relayer])
+        .rpc();
+    };
+
+    // Slow fill leaves must be passed either in instruction data or in the instruction params account.
+    try {
+      await program.methods
+        .executeSlowRelayLeaves(rootBundleId, null, null, null)
+        .accounts({
+          state,
+          rootBundle,
+          signer: relayer.publicKey,
+          instructionParams: program.programId,
+          mint,
+          vault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([relayer])
+        .rpc();
+      assert.fail("Execution should have failed without slow fill leaves");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidInstructionParams", "Expected InvalidInstructionParams error");
+    }
+
+    // Leaves in a different order than in the multiproof do not rebuild the slow relay root.
+    try {
+      await executeSlowRelayLeaves([...multiProof.leaves].reverse());
+      assert.fail("Execution should have failed for leaves out of multiproof order");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidMerkleProof", "Expected InvalidMerkleProof error");
+    }
+
+    const iRecipientBal = (await connection.getTokenAccountBalance(recipientTA)).value.amount;
+    const tx = await executeSlowRelayLeaves(multiProof.leaves);
+    const fRecipientBal = (await connection.getTokenAccountBalance(recipientTA)).value.amount;
+    const totalAmount = multiProof.leaves.reduce((sum, leaf) => sum + BigInt(leaf.updatedOutputAmount.toString()), 0n);
+    assert.strictEqual(BigInt(fRecipientBal) - BigInt(iRecipientBal), totalAmount, "Recipient balance");
+
+    const events = (await readEventsUntilFound(connection, tx, [program])).filter((e) => e.name === "filledRelay");
+    assert.strictEqual(events.length, multiProof.leaves.length, "FilledRelay event should be emitted for each leaf");
+    for (const [i, leaf] of multiProof.leaves.entries()) {
+      assertSE(events[i].data.depositId, leaf.relayData.depositId, "DepositId should match");
+      assert.equal(JSON.stringify(events[i].data.relayExecutionInfo.fillType), `{"slowFill":{}}`, "Should be SlowFill");
+      const fillStatusAccount = await program.account.fillStatusAccount.fetch(fillStatuses[i]);
+      assert.equal(JSON.stringify(fillStatusAccount.status), `{"filled":{}}`, "Fill status should be Filled");
+    }
+  });
 });
@@ -166,7 +166,7 @@ describe("svm_spoke.token_bridge", () => {
       program: program.programId,
     };
     await program.methods
-      .relayRootBundle(Array.from(root), Array.from(Buffer.alloc(32)))
+      .relayRootBundle(Array.from(root), Array.from(Buffer.alloc(32)), null)
       .accounts(relayRootBundleAccounts)
       .rpc();
 
@@ -239,6 +239,35 @@ describe("svm_spoke.token_bridge", () => {
     }
   });
 
+  it("Bridge to HubPool should fail when bridging is paused", async () => {
+    const pendingToHubPool = 1_000_000;
+
+    await initializeBridgeToHubPool(pendingToHubPool);
+
+    const pauseBridgeAccounts = { state, signer: owner, program: program.programId };
+    await program.methods.pauseBridge(true).accounts(pauseBridgeAccounts).rpc();
+
+    try {
+      await program.methods
+        .bridgeTokensToHubPool(new BN(pendingToHubPool))
+        .accounts(bridgeTokensToHubPoolAccounts)
+        .signers([messageSentEventData])
+        .rpc();
+      assert.fail("Should not be able to bridge tokens to HubPool while bridging is paused");
+    } catch (error: any) {
+      assert.instanceOf(error, AnchorError);
+      assert.strictEqual(error.error.errorCode.code, "BridgeIsPaused", "Expected error code BridgeIsPaused");
+    }
+
+    // Bridging succeeds once unpaused.
+    await program.methods.pauseBridge(false).accounts(pauseBridgeAccounts).rpc();
+    await program.methods
+      .bridgeTokensToHubPool(new BN(pendingToHubPool))
+      .accounts(bridgeTokensToHubPoolAccounts)
+      .signers([messageSentEventData])
+      .rpc();
+  });
+
   it("Bridge pending tokens to HubPool in multiple transactions", async () => {
     const pendingToHubPool = 10_000_000;
     const singleBridgeAmount = pendingToHubPool / 5;
@@ -5,7 +5,7 @@ import { Keypair, PublicKey, Signer } from "@solana/web3.js";
 import { assert } from "chai";
 import { randomBytes } from "crypto";
 import { ethers } from "ethers";
-import { evmAddressToPublicKey, intToU8Array32 } from "../../src/svm/web3-v1";
+import { evmAddressToPublicKey, getRemoteNoncePda, intToU8Array32 } from "../../src/svm/web3-v1";
 import { DepositData } from "../../src/types/svm";
 import { SvmSpoke } from "../../target/types/svm_spoke";
 
@@ -55,7 +55,13 @@ const initializeState = async (
       fillDeadlineBuffer,
     };
   }
-  const initializeAccounts = { state: state as any, signer: owner, systemProgram: anchor.web3.SystemProgram.programId };
+  const remoteNonce = getRemoteNoncePda(actualSeed, initialState.remoteDomain.toNumber(), program.programId);
+  const initializeAccounts = {
+    state: state as any,
+    remoteNonce,
+    signer: owner,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  };
   await program.methods
     .initialize(
       actualSeed,
@@ -0,0 +1,89 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Test } from "../../target/types/test";
+import { assert } from "chai";
+import { ethers } from "ethers";
+import { common } from "./SvmSpoke.common";
+const { assertSE } = common;
+
+describe("utils.calldata", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Test as Program<Test>;
+
+  const callDataInterface = new ethers.utils.Interface([
+    "function testCall(uint256 amount, int64 delta, bytes message, bytes4[] selectors)",
+    "function testInt256(int256 delta)",
+  ]);
+
+  const encodeCallData = (functionName: string, args: any[]) =>
+    Buffer.from(ethers.utils.arrayify(callDataInterface.encodeFunctionData(functionName, args)));
+
+  const encodeTestCall = (
+    amount: ethers.BigNumberish,
+    delta: ethers.BigNumberish,
+    message: string,
+    selectors: string[]
+  ) => encodeCallData("testCall", [amount, delta, message, selectors]);
+
+  const assertCallDataError = async (call: Promise<string>, expectedCode: string) => {
+    try {
+      await call;
+      assert.fail(`Should fail with ${expectedCode}`);
+    } catch (err: any) {
+      assert.instanceOf(err, anchor.AnchorError);
+      assertSE(err.error.errorCode.code, expectedCode, `Expected error code ${expectedCode}`);
+    }
+  };
+
+  it("Decodes uint256 arguments", async () => {
+    const amount = ethers.constants.MaxUint256.sub(1);
+    const data = encodeTestCall(amount, 0, "0x", []);
+
+    const decoded = await program.methods.testDecodeUint256(data, 0).view();
+    assertSE(Buffer.from(decoded).toString("hex"), amount.toHexString().slice(2), "uint256 should match");
+  });
+
+  it("Decodes int64 arguments", async () => {
+    for (const delta of ["-9223372036854775808", "-1", "0", "1", "9223372036854775807"]) {
+      const decoded = await program.methods.testDecodeInt64(encodeTestCall(0, delta, "0x", []), 1).view();
+      assertSE(decoded.toString(), delta, "int64 should match");
+    }
+  });
+
+  it("Rejects int64 arguments out of range", async () => {
+    for (const delta of ["-9223372036854775809", "9223372036854775808"]) {
+      const data = encodeCallData("testInt256", [delta]);
+      await assertCallDataError(program.methods.testDecodeInt64(data, 0).rpc(), "InvalidInt64");
+    }
+  });
+
+  it("Decodes dynamic bytes and arrays", async () => {
+    const message = ethers.utils.hexlify(ethers.utils.randomBytes(100));
+    const selectors = ["0x12345678", "0xdeadbeef"];
+    const data = encodeTestCall(0, 0, message, selectors);
+
+    const decodedMessage = await program.methods.testDecodeBytes(data, 2).view();
+    assertSE(ethers.utils.hexlify(decodedMessage), message, "bytes should match");
+
+    const decodedSelectors = await program.methods.testDecodeBytes4Array(data, 3).view();
+    assert.deepEqual(
+      decodedSelectors.map((selector: number[]) => ethers.utils.hexlify(selector)),
+      selectors,
+      "bytes4[] should match"
+    );
+
+    const emptyData = encodeTestCall(0, 0, "0x", []);
+    assertSE((await program.methods.testDecodeBytes(emptyData, 2).view()).length, 0, "bytes should be empty");
+    assertSE((await program.methods.testDecodeBytes4Array(emptyData, 3).view()).length, 0, "bytes4[] should be empty");
+  });
+
+  it("Rejects truncated dynamic bytes", async () => {
+    const data = encodeTestCall(0, 0, ethers.utils.hexlify(ethers.utils.randomBytes(100)), []);
+
+    // Drop the padded tail of the last dynamic argument so that the bytes contents are out of bounds.
+    const truncatedData = data.subarray(0, data.length - 64);
+    await assertCallDataError(program.methods.testDecodeBytes(truncatedData, 2).rpc(), "InvalidArgument");
+  });
+});
@@ -135,4 +135,96 @@ describe("utils.merkle", () => {
       )
       .rpc();
   });
+
+  describe("Multiproof verification", () => {
+    const hashFn = (leaf: string) => ethers.utils.keccak256(leaf);
+    const treeLeaves = Array.from({ length: 16 }, () => ethers.utils.hexlify(ethers.utils.randomBytes(32)));
+    const merkleTree = new MerkleTree<string>(treeLeaves, hashFn);
+    const root = Array.from(merkleTree.getRoot());
+
+    const getMultiProof = (leaves: string[]) => {
+      const multiProof = merkleTree.getMultiProof(leaves);
+      return {
+        leaves: multiProof.leaves.map((leaf) => Array.from(ethers.utils.arrayify(hashFn(leaf)))),
+        proof: multiProof.proof.map((p) => Array.from(p)),
+        proofFlags: multiProof.proofFlags,
+      };
+    };
+    type MultiProof = ReturnType<typeof getMultiProof>;
+
+    const verifyMulti = ({ leaves, proof, proofFlags }: MultiProof) =>
+      program.methods.verifyMulti(root, leaves, proof, proofFlags).rpc();
+
+    const assertInvalidMultiProof = async (multiProof: MultiProof, message: string) => {
+      try {
+        await verifyMulti(multiProof);
+        assert.fail(message);
+      } catch (err: any) {
+        assert.instanceOf(err, anchor.AnchorError);
+        assertSE(err.error.errorCode.code, "InvalidMerkleProof", "Expected error code InvalidMerkleProof");
+      }
+    };
+
+    const scatteredLeaves = [treeLeaves[0], treeLeaves[7], treeLeaves[9], treeLeaves[14]];
+
+    it("Verifies multiproofs for subsets of leaves", async () => {
+      await verifyMulti(getMultiProof([treeLeaves[3]]));
+      await verifyMulti(getMultiProof([treeLeaves[4], treeLeaves[5]]));
+      await verifyMulti(getMultiProof(scatteredLeaves));
+
+      const allLeavesMultiProof = getMultiProof(treeLeaves);
+      assert.isEmpty(allLeavesMultiProof.proof, "Multiproof of all leaves should not need proof elements");
+      await verifyMulti(allLeavesMultiProof);
+    });
+
+    it("Rejects multiproofs with proof flags not matching leaves and proof elements", async () => {
+      const multiProof = getMultiProof(scatteredLeaves);
+
+      await assertInvalidMultiProof(
+        { ...multiProof, proofFlags: multiProof.proofFlags.slice(0, -1) },
+        "Should not verify with missing proof flag"
+      );
+      await assertInvalidMultiProof(
+        { ...multiProof, proofFlags: [...multiProof.proofFlags, false] },
+        "Should not verify with extra proof flag"
+      );
+      await assertInvalidMultiProof(
+        { ...multiProof, proof: [...multiProof.proof, Array.from(ethers.utils.randomBytes(32))] },
+        "Should not verify with extra proof element"
+      );
+    });
+
+    it("Rejects multiproofs with unused proof elements", async () => {
+      const multiProof = getMultiProof(scatteredLeaves);
+
+      // Extra proof element is balanced by an extra flag, but that hashes computed hashes instead of the proof element.
+      await assertInvalidMultiProof(
+        {
+          ...multiProof,
+          proof: [...multiProof.proof, Array.from(ethers.utils.randomBytes(32))],
+          proofFlags: [...multiProof.proofFlags, true],
+        },
+        "Should not verify with unused proof element"
+      );
+    });
+
+    it("Rejects multiproofs with tampered, duplicate or reordered leaves", async () => {
+      const multiProof = getMultiProof(scatteredLeaves);
+
+      const tamperedLeaf = [...multiProof.leaves[1]];
+      tamperedLeaf[0] ^= 1;
+      await assertInvalidMultiProof(
+        { ...multiProof, leaves: [multiProof.leaves[0], tamperedLeaf, ...multiProof.leaves.slice(2)] },
+        "Should not verify tampered leaf"
+      );
+      await assertInvalidMultiProof(
+        { ...multiProof, leaves: [multiProof.leaves[0], multiProof.leaves[0], ...multiProof.leaves.slice(2)] },
+        "Should not verify duplicate leaf"
+      );
+      await assertInvalidMultiProof(
+        { ...multiProof, leaves: [...multiProof.leaves].reverse() },
+        "Should not verify leaves out of multiproof order"
+      );
+    });
+  });
 });
@@ -73,6 +73,56 @@ export class MerkleTree<T> {
     return this.getHexProofRawBuf(this.leafToBuf(leaf));
   }
 
+  // Generates multiproof for the leaves in the format of OpenZeppelin MerkleProof.multiProofVerify. Returned leaves are
+  // ordered as they are consumed when rebuilding the tree. Nodes without a pair in odd sized layers are carried up
+  // without hashing, so some combinations of leaves cannot be proven together and these throw.
+  getMultiProof(leaves: T[]): { leaves: T[]; proof: Buffer[]; proofFlags: boolean[] } {
+    const indexedLeaves = leaves.map((leaf) => {
+      const idx = this.bufferElementPositionIndex[bufferToHex(this.leafToBuf(leaf))];
+      if (typeof idx !== "number") throw new Error("Element does not exist in Merkle tree");
+      return { leaf, idx };
+    });
+    indexedLeaves.sort((a, b) => a.idx - b.idx);
+    if (indexedLeaves.some((el, i) => i > 0 && el.idx === indexedLeaves[i - 1].idx)) {
+      throw new Error("Duplicate elements in multiproof");
+    }
+
+    const topLayer = this.layers.length - 1;
+    const carryUp = (node: { layer: number; idx: number }) => {
+      while (node.layer < topLayer && (node.idx ^ 1) >= this.layers[node.layer].length) {
+        node = { layer: node.layer + 1, idx: node.idx >> 1 };
+      }
+      return node;
+    };
+    const isDescendant = (node: { layer: number; idx: number }, layer: number, idx: number) =>
+      node.layer <= layer && node.idx >> (layer - node.layer) === idx;
+
+    // Simulate the multiproof verification queue where all leaves are consumed before any computed hashes.
+    const queue = indexedLeaves.map(({ idx }) => ({ layer: 0, idx }));
+    const proof: Buffer[] = [];
+    const proofFlags: boolean[] = [];
+    for (let pos = 0; ; ) {
+      const node = carryUp(queue[pos]);
+      if (node.layer === topLayer) break;
+      const pairIdx = node.idx ^ 1;
+      const next = pos + 1 < queue.length ? carryUp(queue[pos + 1]) : undefined;
+      if (next && next.layer === node.layer && next.idx === pairIdx) {
+        proofFlags.push(true);
+        pos += 2;
+      } else {
+        if (queue.slice(pos + 1).some((pending) => isDescendant(pending, node.layer, pairIdx))) {
+          throw new Error("Elements cannot be proven together in multiproof");
+        }
+        proof.push(this.layers[node.layer][pairIdx]);
+        proofFlags.push(false);
+        pos += 1;
+      }
+      queue.push({ layer: node.layer + 1, idx: node.idx >> 1 });
+    }
+
+    return { leaves: indexedLeaves.map(({ leaf }) => leaf), proof, proofFlags };
+  }
+
   leafToBuf(element: T): Buffer {
     const hash = this.hashFn(element);
     const hexString = hash.startsWith("0x") ? hash.substring(2) : hash;